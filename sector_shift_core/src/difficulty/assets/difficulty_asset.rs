@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::maps::DifficultyLevel;
+
+/// This gets loaded by the AssetManager
+/// Describes how a difficulty level scales the game
+#[derive(Serialize, Deserialize, Asset, TypePath)]
+pub struct DifficultyAsset {
+    /// The difficulty level these values apply to, used as the key in the "DifficultyLibrary" resource
+    pub level: DifficultyLevel,
+    /// Multiplier applied to damage taken by the player
+    pub damage_multiplier: f32,
+    /// Multiplier applied to ammo given by pickups
+    pub ammo_multiplier: f32,
+}
+
+impl DifficultyAsset {
+    /// The file extension for these assets
+    pub const EXTENSION: &'static str = "difficulty";
+}
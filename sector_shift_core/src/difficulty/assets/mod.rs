@@ -0,0 +1,2 @@
+mod difficulty_asset;
+pub use self::difficulty_asset::*;
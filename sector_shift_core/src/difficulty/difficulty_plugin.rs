@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::{
+    difficulty::{assets::DifficultyAsset, resources::DifficultyLibrary, systems::process_difficulties},
+    utils::{generic_asset_loader::GenericAssetLoader, generic_directory_loader::DirectoryLoaderPlugin},
+};
+
+#[derive(Clone)]
+pub struct DifficultyPlugin<T: States + Copy> {
+    asset_load_state: T,
+}
+
+impl<T: States + Copy> DifficultyPlugin<T> {
+    pub fn new(asset_load_state: T) -> Self {
+        Self { asset_load_state }
+    }
+}
+
+impl<T: States + Copy> Plugin for DifficultyPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<DifficultyAsset>();
+        app.register_asset_loader(GenericAssetLoader::<DifficultyAsset>::new(&[
+            DifficultyAsset::EXTENSION,
+        ]));
+
+        app.init_resource::<DifficultyLibrary>();
+        app.add_plugins(DirectoryLoaderPlugin::<DifficultyLibrary, _>::new(
+            self.asset_load_state,
+        ));
+
+        app.add_systems(
+            Update,
+            process_difficulties.run_if(in_state(self.asset_load_state)),
+        );
+    }
+}
@@ -0,0 +1,6 @@
+pub mod assets;
+pub mod resources;
+pub mod systems;
+
+mod difficulty_plugin;
+pub use self::difficulty_plugin::*;
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+use crate::{difficulty::assets::DifficultyAsset, maps::DifficultyLevel};
+
+/// The scaling values for the difficulty a game is being played on
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct Difficulty {
+    /// The difficulty level used to filter map objects
+    pub level: DifficultyLevel,
+    /// Multiplier applied to damage taken by the player
+    pub damage_multiplier: f32,
+    /// Multiplier applied to ammo given by pickups
+    pub ammo_multiplier: f32,
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Self {
+            level: DifficultyLevel::default(),
+            damage_multiplier: 1.0,
+            ammo_multiplier: 1.0,
+        }
+    }
+}
+
+impl Difficulty {
+    /// Helper to convert from DifficultyAsset to Difficulty
+    pub fn from_asset(asset: &DifficultyAsset) -> Self {
+        Self {
+            level: asset.level,
+            damage_multiplier: asset.damage_multiplier,
+            ammo_multiplier: asset.ammo_multiplier,
+        }
+    }
+
+    /// Scales damage taken by the player, never reducing a hit to nothing
+    pub fn scale_damage(&self, amount: i32) -> i32 {
+        if amount <= 0 {
+            return amount;
+        }
+        ((amount as f32 * self.damage_multiplier).round() as i32).max(1)
+    }
+
+    /// Scales the ammo given by a pickup, always giving at least one round
+    pub fn scale_ammo(&self, amount: u32) -> u32 {
+        if amount == 0 {
+            return 0;
+        }
+        ((amount as f32 * self.ammo_multiplier).round() as u32).max(1)
+    }
+}
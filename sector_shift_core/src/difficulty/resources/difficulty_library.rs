@@ -0,0 +1,40 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::{
+    difficulty::{assets::DifficultyAsset, resources::Difficulty},
+    maps::DifficultyLevel,
+};
+
+/// A library of the scaling values for each difficulty level
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
+pub struct DifficultyLibrary {
+    /// A map of loaded difficulties sorted by their level
+    pub map: HashMap<DifficultyLevel, Difficulty>,
+    /// A list of difficulty assets currently being loaded
+    pub loading: Vec<Handle<DifficultyAsset>>,
+    /// Indicates that all handles in `Self.loading` are fully loaded
+    pub loading_finished: bool,
+}
+
+impl DifficultyLibrary {
+    /// Get the difficulty for a level, falling back to unscaled values if it has no asset
+    pub fn get(&self, level: DifficultyLevel) -> Difficulty {
+        self.map.get(&level).cloned().unwrap_or_else(|| {
+            warn!("No difficulty asset for {}, using defaults.", level.name());
+            Difficulty {
+                level,
+                ..Default::default()
+            }
+        })
+    }
+
+    /// Add a new difficulty to the library
+    pub fn add(&mut self, difficulty: Difficulty) {
+        self.map.insert(difficulty.level, difficulty);
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.loading_finished && self.loading.is_empty()
+    }
+}
@@ -0,0 +1,5 @@
+mod difficulty;
+pub use self::difficulty::*;
+
+mod difficulty_library;
+pub use self::difficulty_library::*;
@@ -0,0 +1,2 @@
+mod process_difficulties;
+pub use self::process_difficulties::*;
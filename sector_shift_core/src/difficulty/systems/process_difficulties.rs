@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::difficulty::{
+    assets::DifficultyAsset,
+    resources::{Difficulty, DifficultyLibrary},
+};
+
+/// A system that processes loaded difficulty assets and adds them to the DifficultyLibrary
+pub fn process_difficulties(
+    a_difficulties: Res<Assets<DifficultyAsset>>,
+    mut difficulty_library: ResMut<DifficultyLibrary>,
+) {
+    if difficulty_library.loading_finished {
+        for handle in std::mem::take(&mut difficulty_library.loading) {
+            if let Some(difficulty_asset) = a_difficulties.get(&handle) {
+                info!(
+                    "Loaded difficulty asset for {}",
+                    difficulty_asset.level.name()
+                );
+                difficulty_library.add(Difficulty::from_asset(difficulty_asset));
+            }
+        }
+    }
+}
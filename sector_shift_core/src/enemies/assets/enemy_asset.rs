@@ -1,17 +1,41 @@
-use bevy::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    enemies::data::{EnemyAnimation, EnemySpriteSheet, EnemyStats},
+    sounds::data::SoundEvent,
+    utils::{deserialize_optional_path, serialize_optional_path},
+};
+
 /// This gets loaded by the AssetManager
 /// Represents an enemy type that can be spawned in levels
-/// Need to add more data later for AI, stats, etc.
 #[derive(Serialize, Deserialize, Asset, TypePath)]
 pub struct EnemyAsset {
     /// This must be unique as it is used as the key when looking up from the [`EnemyLibrary`] resource or MapObject::Enemy
     pub id: String,
     /// This is the path to the image to be loaded
     pub sprite: String,
-    /// This is the path to the gltf model to be loaded
-    pub gltf: String,
+    /// This is the path to the gltf model to be loaded, left out for sprite sheet enemies
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_path",
+        serialize_with = "serialize_optional_path",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub gltf: Option<String>,
+    /// Draws the enemy as an 8-direction billboard instead of a model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sprite_sheet: Option<EnemySpriteSheet>,
+    /// Health, speed, attack and reward of the enemy
+    #[serde(default)]
+    pub stats: EnemyStats,
+    /// The clip of the model played for each state, `Idle` is required and states left out fall
+    /// back to another one, see [`EnemyAnimation::fallback`]. Unused by sprite sheet enemies
+    #[serde(default = "EnemyAnimation::default_clips")]
+    pub animations: HashMap<EnemyAnimation, String>,
+    /// The ids of the sounds this enemy makes instead of the defaults, keyed by event
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub sounds: HashMap<SoundEvent, String>,
     #[serde(skip)]
     #[dependency]
     /// Asset dependency, the gltf has to be loaded before we can convert [`EnemyAsset`] to
@@ -23,3 +47,74 @@ impl EnemyAsset {
     /// The file extension for these assets
     pub const EXTENSION: &'static str = "enemy";
 }
+
+#[cfg(test)]
+mod tests {
+    use super::EnemyAsset;
+    use crate::{
+        enemies::data::{EnemyAnimation, EnemyStats},
+        utils::billboard::data::SpritePlayback,
+    };
+
+    #[test]
+    fn test_enemy_asset_defaults() {
+        // Files from before stats and animations existed keep their old clip names
+        let asset: EnemyAsset =
+            ron::de::from_str(r#"(id: "alien", sprite: "alien.png", gltf: "Alien.glb")"#).unwrap();
+        assert_eq!(asset.stats, EnemyStats::default());
+        assert_eq!(asset.animations, EnemyAnimation::default_clips());
+        assert_eq!(asset.gltf.as_deref(), Some("Alien.glb"));
+        assert_eq!(asset.sprite_sheet, None);
+
+        let asset: EnemyAsset = ron::de::from_str(
+            r#"(
+                id: "slime",
+                sprite: "slime.png",
+                gltf: "Slime.glb",
+                stats: (health: 20, drop_item: Some("ammo_small")),
+                animations: {Idle: "Wobble", Death: "Splat"},
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(asset.stats.health, 20);
+        assert_eq!(asset.stats.drop_item.as_deref(), Some("ammo_small"));
+        assert_eq!(asset.stats.speed, EnemyStats::default().speed);
+        assert_eq!(asset.animations.len(), 2);
+        assert_eq!(asset.animations[&EnemyAnimation::Death], "Splat");
+    }
+
+    #[test]
+    fn test_enemy_asset_sprite_sheet() {
+        let asset: EnemyAsset = ron::de::from_str(
+            r#"(
+                id: "guard",
+                sprite: "guard_icon.png",
+                sprite_sheet: Some((
+                    image: "guard.png",
+                    frame_size: (64, 64),
+                    animations: {
+                        Idle: (),
+                        Walk: (row: 1, frames: 4, fps: 8.0, playback: PingPong),
+                    },
+                )),
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(asset.gltf, None);
+
+        // Without a model the path is left out rather than written empty
+        let reloaded: EnemyAsset = ron::de::from_str(&ron::ser::to_string(&asset).unwrap()).unwrap();
+        assert_eq!(reloaded.gltf, None);
+        assert_eq!(reloaded.sprite_sheet, asset.sprite_sheet);
+
+        let sheet = asset.sprite_sheet.unwrap();
+        assert_eq!(sheet.animation(EnemyAnimation::Walk).unwrap().frames, 4);
+        assert_eq!(
+            sheet.animation(EnemyAnimation::Walk).unwrap().playback,
+            SpritePlayback::PingPong
+        );
+        // No attack frames, the idle ones are shown instead
+        assert_eq!(sheet.animation(EnemyAnimation::Attack).unwrap().row, 0);
+        assert!(sheet.animation(EnemyAnimation::Death).is_none());
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
+
+/// The logical states an enemy model can have an animation clip for
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnemyAnimation {
+    /// Standing still, every enemy needs a clip for it
+    Idle,
+    Walk,
+    Attack,
+    /// Flinching after taking damage
+    Pain,
+    Death,
+}
+
+impl EnemyAnimation {
+    pub const ALL: [Self; 5] = [
+        Self::Idle,
+        Self::Walk,
+        Self::Attack,
+        Self::Pain,
+        Self::Death,
+    ];
+
+    /// The state whose clip is played instead when the model has none for this one.
+    /// `None` means nothing is played, the enemy keeps its current animation.
+    pub fn fallback(self) -> Option<Self> {
+        match self {
+            EnemyAnimation::Idle => None,
+            EnemyAnimation::Walk | EnemyAnimation::Attack => Some(EnemyAnimation::Idle),
+            EnemyAnimation::Pain | EnemyAnimation::Death => None,
+        }
+    }
+
+    /// Whether the state plays once and then gives way to `Idle`, other states last until another one
+    /// is played
+    pub fn is_reaction(self) -> bool {
+        matches!(self, EnemyAnimation::Attack | EnemyAnimation::Pain)
+    }
+
+    /// Looks up the value for this state, following the fallbacks for states that have none
+    pub fn resolve<T>(self, values: &HashMap<Self, T>) -> Option<&T> {
+        let mut state = Some(self);
+        while let Some(current) = state {
+            if let Some(value) = values.get(&current) {
+                return Some(value);
+            }
+            state = current.fallback();
+        }
+        None
+    }
+
+    /// The clip names used by enemy files written before the animation map existed
+    pub fn default_clips() -> HashMap<Self, String> {
+        HashMap::from_iter([
+            (EnemyAnimation::Idle, "Idle".to_string()),
+            (EnemyAnimation::Walk, "RunForward".to_string()),
+        ])
+    }
+}
+
+impl fmt::Display for EnemyAnimation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
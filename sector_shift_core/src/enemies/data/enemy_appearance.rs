@@ -0,0 +1,21 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::enemies::data::{EnemyAnimation, EnemySpriteSheet};
+
+/// How a loaded enemy is drawn
+#[derive(Reflect)]
+pub enum EnemyAppearance {
+    /// A glTF scene with its clips in an animation graph
+    Model {
+        gltf: Handle<Gltf>,
+        scene: Handle<Scene>,
+        graph: Handle<AnimationGraph>,
+        /// The node of the graph for each state that has a clip, always contains `Idle`
+        animations: HashMap<EnemyAnimation, AnimationNodeIndex>,
+    },
+    /// A billboard showing the frame of the sheet for the angle it is seen from
+    SpriteSheet {
+        image: Handle<Image>,
+        sheet: EnemySpriteSheet,
+    },
+}
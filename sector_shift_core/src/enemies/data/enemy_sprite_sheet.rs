@@ -0,0 +1,153 @@
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enemies::data::{EnemyAnimation, SpriteAnimation},
+    utils::direction::Direction,
+};
+
+/// A flat enemy drawn from a sprite sheet instead of a model, like in Wolfenstein 3D or Doom.
+///
+/// Every row of the sheet is one animation frame seen from the 8 directions, in the columns
+/// listed by [`EnemySpriteSheet::VIEWS`]: the front first and then clockwise around the enemy.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+pub struct EnemySpriteSheet {
+    /// This is the path to the sheet image to be loaded
+    pub image: String,
+    /// The size of a single frame in pixels
+    pub frame_size: (u32, u32),
+    /// The frames of each state, `Idle` is required and states left out fall back to another one,
+    /// see [`EnemyAnimation::fallback`]
+    pub animations: HashMap<EnemyAnimation, SpriteAnimation>,
+}
+
+impl EnemySpriteSheet {
+    /// The side of the enemy each column shows, as the direction of the viewer where `NORTH` is
+    /// the way the enemy faces
+    pub const VIEWS: [Direction; 8] = [
+        Direction::NORTH,
+        Direction::NORTH_EAST,
+        Direction::EAST,
+        Direction::SOUTH_EAST,
+        Direction::SOUTH,
+        Direction::SOUTH_WEST,
+        Direction::WEST,
+        Direction::NORTH_WEST,
+    ];
+
+    /// The direction of the viewer as seen by the enemy, rounded to the nearest of the 8 views.
+    ///
+    /// `viewer` is the viewer's position relative to the enemy, with `y` pointing the way the enemy
+    /// faces and `x` to its right.
+    pub fn view_direction(viewer: Vec2) -> Direction {
+        if viewer == Vec2::ZERO {
+            return Direction::NORTH;
+        }
+        // Clockwise from the front
+        let angle = viewer.x.atan2(viewer.y);
+        let view = (angle / FRAC_PI_4).round() as i32;
+        Self::VIEWS[view.rem_euclid(8) as usize]
+    }
+
+    /// The column of the sheet showing the enemy seen from `view`
+    pub fn column(view: Direction) -> u32 {
+        Self::VIEWS.iter().position(|direction| *direction == view).unwrap_or_default() as u32
+    }
+
+    /// The frames to play for a state, following the fallbacks for states without frames
+    pub fn animation(&self, state: EnemyAnimation) -> Option<&SpriteAnimation> {
+        state.resolve(&self.animations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::EnemySpriteSheet;
+    use crate::{
+        enemies::data::SpriteAnimation,
+        utils::{billboard::data::SpritePlayback, direction::Direction},
+    };
+
+    #[test]
+    fn test_view_direction() {
+        assert_eq!(
+            EnemySpriteSheet::view_direction(Vec2::new(0.0, 5.0)),
+            Direction::NORTH
+        );
+        assert_eq!(
+            EnemySpriteSheet::view_direction(Vec2::new(0.3, 5.0)),
+            Direction::NORTH
+        );
+        assert_eq!(
+            EnemySpriteSheet::view_direction(Vec2::new(2.0, 0.0)),
+            Direction::EAST
+        );
+        assert_eq!(
+            EnemySpriteSheet::view_direction(Vec2::new(0.0, -1.0)),
+            Direction::SOUTH
+        );
+        assert_eq!(
+            EnemySpriteSheet::view_direction(Vec2::new(-1.0, -1.0)),
+            Direction::SOUTH_WEST
+        );
+        assert_eq!(
+            EnemySpriteSheet::view_direction(Vec2::new(-1.0, 1.1)),
+            Direction::NORTH_WEST
+        );
+        assert_eq!(
+            EnemySpriteSheet::view_direction(Vec2::ZERO),
+            Direction::NORTH
+        );
+
+        assert_eq!(EnemySpriteSheet::column(Direction::NORTH), 0);
+        assert_eq!(EnemySpriteSheet::column(Direction::SOUTH), 4);
+        assert_eq!(EnemySpriteSheet::column(Direction::NORTH_WEST), 7);
+    }
+
+    #[test]
+    fn test_sprite_animation_frames() {
+        let walk = SpriteAnimation {
+            row: 1,
+            frames: 4,
+            fps: 4.0,
+            playback: SpritePlayback::Loop,
+        };
+        assert_eq!(walk.frame_at(0.0), 0);
+        assert_eq!(walk.frame_at(0.6), 2);
+        assert_eq!(walk.frame_at(1.1), 0);
+
+        let death = SpriteAnimation {
+            playback: SpritePlayback::Once,
+            ..walk.clone()
+        };
+        assert_eq!(death.frame_at(10.0), 3);
+
+        let idle = SpriteAnimation {
+            playback: SpritePlayback::PingPong,
+            ..walk
+        };
+        assert_eq!(idle.frame_at(1.1), 2);
+    }
+
+    #[test]
+    fn test_sprite_animation_duration() {
+        let attack = SpriteAnimation {
+            row: 2,
+            frames: 3,
+            fps: 6.0,
+            playback: SpritePlayback::Once,
+        };
+        assert_eq!(attack.duration(), 0.5);
+        let ping_pong = SpriteAnimation {
+            playback: SpritePlayback::PingPong,
+            ..attack.clone()
+        };
+        assert_eq!(ping_pong.duration(), 5.0 / 6.0);
+        let still = SpriteAnimation { fps: 0.0, ..attack };
+        assert_eq!(still.duration(), 0.0);
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How an enemy type plays, every value can be left out of the `.enemy` file to use its default.
+/// Also added to spawned enemies so behaviour code can read them.
+#[derive(Component, Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+#[serde(default)]
+pub struct EnemyStats {
+    pub health: i32,
+    /// Movement speed in units per second
+    pub speed: f32,
+    /// How far the enemy can see the player, in units
+    pub sight_range: f32,
+    /// How wide the enemy's sight cone is, in degrees
+    pub field_of_view: f32,
+    /// Seconds between noticing the player and reacting
+    pub reaction_time: f32,
+    /// Seconds the enemy keeps hunting after losing sight of the player, and stays suspicious after
+    /// that
+    pub give_up_time: f32,
+    /// Health taken from the player per attack that hits instantly
+    pub damage: i32,
+    /// The id of the projectile its attacks fire, `None` for attacks that hit instantly
+    pub projectile: Option<String>,
+    /// How close the enemy has to be to attack, in units
+    pub attack_range: f32,
+    /// Seconds between two attacks
+    pub attack_interval: f32,
+    /// Added to the player's score when killed
+    pub score_value: u32,
+    /// The id of the item dropped when killed
+    pub drop_item: Option<String>,
+}
+
+impl Default for EnemyStats {
+    fn default() -> Self {
+        Self {
+            health: 50,
+            speed: 4.0,
+            sight_range: 30.0,
+            field_of_view: 120.0,
+            reaction_time: 0.5,
+            give_up_time: 5.0,
+            damage: 10,
+            projectile: None,
+            attack_range: 15.0,
+            attack_interval: 1.5,
+            score_value: 100,
+            drop_item: None,
+        }
+    }
+}
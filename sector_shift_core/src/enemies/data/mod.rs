@@ -0,0 +1,14 @@
+mod enemy_animation;
+pub use self::enemy_animation::*;
+
+mod enemy_appearance;
+pub use self::enemy_appearance::*;
+
+mod enemy_sprite_sheet;
+pub use self::enemy_sprite_sheet::*;
+
+mod enemy_stats;
+pub use self::enemy_stats::*;
+
+mod sprite_animation;
+pub use self::sprite_animation::*;
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::billboard::data::SpritePlayback;
+
+/// Where the frames of one animation state are on a sprite sheet and how they play, timed like a
+/// [`SpriteSheet`](crate::utils::billboard::data::SpriteSheet)
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct SpriteAnimation {
+    /// The row of the first frame, the following frames are on the rows below it
+    pub row: u32,
+    pub frames: u32,
+    /// Frames shown per second
+    pub fps: f32,
+    pub playback: SpritePlayback,
+}
+
+impl Default for SpriteAnimation {
+    fn default() -> Self {
+        Self {
+            row: 0,
+            frames: 1,
+            fps: 10.0,
+            playback: SpritePlayback::Loop,
+        }
+    }
+}
+
+impl SpriteAnimation {
+    /// The frame shown after playing for `elapsed` seconds
+    pub fn frame_at(&self, elapsed: f32) -> u32 {
+        self.playback.frame_at(elapsed, self.fps, self.frames)
+    }
+
+    /// Seconds one play of the frames takes, including the way back to the first frame for
+    /// `PingPong`. Still frames without `fps` take none
+    pub fn duration(&self) -> f32 {
+        self.playback.duration(self.fps, self.frames)
+    }
+}
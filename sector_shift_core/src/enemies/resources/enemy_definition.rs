@@ -1,7 +1,13 @@
-use bevy::{platform::collections::HashMap, prelude::*};
+use bevy::prelude::*;
 use thiserror::Error;
 
-use crate::enemies::assets::EnemyAsset;
+use crate::{
+    enemies::{
+        assets::EnemyAsset,
+        data::{EnemyAnimation, EnemyAppearance, EnemyStats},
+    },
+    sounds::components::SoundOverrides,
+};
 
 /// An intermediate step between an enemy asset and a spawned enemy
 #[derive(Reflect)]
@@ -10,11 +16,10 @@ pub struct EnemyDefinition {
     pub id: String,
     /// The sprite handle for the enemy
     pub sprite: Handle<Image>,
-    pub gltf: Handle<Gltf>,
-    pub scene: Handle<Scene>,
-    pub graph: Handle<AnimationGraph>,
-    pub idle: AnimationNodeIndex,
-    pub walking: AnimationNodeIndex,
+    pub appearance: EnemyAppearance,
+    pub stats: EnemyStats,
+    /// The sounds this enemy makes instead of the defaults
+    pub sounds: SoundOverrides,
 }
 
 impl EnemyDefinition {
@@ -25,24 +30,67 @@ impl EnemyDefinition {
         graphs: &mut Assets<AnimationGraph>,
         asset: &EnemyAsset,
     ) -> Result<Self, EnemyDefinitionLoadError> {
-        let gltf_handle = asset.gltf_handle.clone().ok_or(EnemyDefinitionLoadError::MissingGltfHandle)?;
-        let gltf = gltfs.get(&gltf_handle).ok_or(EnemyDefinitionLoadError::BrokenGltfHandle)?;
-        let (names, clips): (Vec<_>, Vec<_>) = gltf.named_animations.iter().unzip();
-        let (graph, indices) = AnimationGraph::from_clips(clips.iter().map(|h| (*h).clone()));
-        let named_indices: HashMap<_, AnimationNodeIndex> =
-            names.into_iter().map(|s| s.as_ref()).zip(indices).collect();
-        let get = |s: &'static str| {
-            named_indices.get(s).ok_or(EnemyDefinitionLoadError::MissingAnimation(s)).copied()
+        let appearance = match (&asset.gltf, &asset.sprite_sheet) {
+            (Some(path), None) => Self::model_from_asset(gltfs, graphs, asset, path)?,
+            (None, Some(sheet)) => {
+                if !sheet.animations.contains_key(&EnemyAnimation::Idle) {
+                    return Err(EnemyDefinitionLoadError::NoIdleAnimation);
+                }
+                EnemyAppearance::SpriteSheet {
+                    image: asset_server.load(&sheet.image),
+                    sheet: sheet.clone(),
+                }
+            },
+            (None, None) => return Err(EnemyDefinitionLoadError::NoAppearance),
+            (Some(_), Some(_)) => return Err(EnemyDefinitionLoadError::AmbiguousAppearance),
         };
 
         Ok(Self {
             id: asset.id.clone(),
             sprite: asset_server.load(&asset.sprite),
-            gltf: asset_server.load(&asset.gltf),
-            scene: gltf.scenes.first().ok_or(EnemyDefinitionLoadError::NoDefaultScene)?.clone(),
+            appearance,
+            stats: asset.stats.clone(),
+            sounds: SoundOverrides(asset.sounds.clone()),
+        })
+    }
+
+    fn model_from_asset(
+        gltfs: &Assets<Gltf>,
+        graphs: &mut Assets<AnimationGraph>,
+        asset: &EnemyAsset,
+        path: &str,
+    ) -> Result<EnemyAppearance, EnemyDefinitionLoadError> {
+        let gltf_handle = asset.gltf_handle.clone().ok_or(EnemyDefinitionLoadError::MissingGltfHandle)?;
+        let gltf = gltfs.get(&gltf_handle).ok_or(EnemyDefinitionLoadError::BrokenGltfHandle)?;
+
+        if !asset.animations.contains_key(&EnemyAnimation::Idle) {
+            return Err(EnemyDefinitionLoadError::NoIdleAnimation);
+        }
+        // Only the clips that are used go into the graph
+        let mut states = Vec::new();
+        let mut clips = Vec::new();
+        for (state, clip) in &asset.animations {
+            let handle = gltf.named_animations.get(clip.as_str()).ok_or_else(|| {
+                EnemyDefinitionLoadError::MissingAnimation {
+                    gltf: path.to_string(),
+                    state: *state,
+                    clip: clip.clone(),
+                }
+            })?;
+            states.push(*state);
+            clips.push(handle.clone());
+        }
+        let (graph, indices) = AnimationGraph::from_clips(clips);
+
+        Ok(EnemyAppearance::Model {
+            scene: gltf
+                .scenes
+                .first()
+                .ok_or_else(|| EnemyDefinitionLoadError::NoDefaultScene(path.to_string()))?
+                .clone(),
+            gltf: gltf_handle,
             graph: graphs.add(graph),
-            idle: get("Idle")?,
-            walking: get("RunForward")?,
+            animations: states.into_iter().zip(indices).collect(),
         })
     }
 }
@@ -53,8 +101,18 @@ pub enum EnemyDefinitionLoadError {
     MissingGltfHandle,
     #[error("Unexpected internal error: broken gltf handle")]
     BrokenGltfHandle,
-    #[error("The model does not contain any scenes")]
-    NoDefaultScene,
-    #[error("The model was expected to contain an animation with name {0}")]
-    MissingAnimation(&'static str),
+    #[error("Either a gltf model or a sprite sheet is needed to draw the enemy")]
+    NoAppearance,
+    #[error("Only one of a gltf model and a sprite sheet can be given")]
+    AmbiguousAppearance,
+    #[error("The model {0} does not contain any scenes")]
+    NoDefaultScene(String),
+    #[error("The animations have nothing to show for the Idle state")]
+    NoIdleAnimation,
+    #[error("The model {gltf} has no animation named \"{clip}\" for the {state} state")]
+    MissingAnimation {
+        gltf: String,
+        state: EnemyAnimation,
+        clip: String,
+    },
 }
@@ -22,7 +22,9 @@ pub fn process_enemies(
                         enemy_library.add(definition);
                     },
                     Err(e) => {
-                        warn!("Failed to load enemy asset: {e}")
+                        let path =
+                            asset_server.get_path(&handle).map(|path| path.to_string()).unwrap_or_default();
+                        warn!("Failed to load enemy asset {path}: {e}")
                     },
                 }
             }
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::utils::{billboard::data::SpriteSheet, deserialize_optional_path, serialize_optional_path};
+
 /// This gets loaded by the AssetManager
 /// Represents a generic environment object model
 #[derive(Serialize, Deserialize, Asset, TypePath)]
@@ -8,8 +10,26 @@ pub struct EnvObjAsset {
     /// This must be unique as it is used as the key when looking up from the [`EnvObjLibrary`]
     /// resource
     pub id: String,
-    /// This is the path to the gltf model to be loaded
-    pub gltf: String,
+    /// This is the path to the gltf model to be loaded, left out for sprite objects
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_path",
+        serialize_with = "serialize_optional_path",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub gltf: Option<String>,
+    /// This is the path to the image of a flat object drawn as an upright billboard instead of a
+    /// model, like a torch
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_path",
+        serialize_with = "serialize_optional_path",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sprite: Option<String>,
+    /// Plays the sprite as an animation, the image then holds all of its frames
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animation: Option<SpriteSheet>,
     #[serde(default = "f_true")]
     /// Whether this object can be set on the map in the editor
     /// This should be set to `false` for global environment like the skybox
@@ -19,6 +39,10 @@ pub struct EnvObjAsset {
     /// Asset dependency, the gltf has to be loaded before we can convert [`EnvObjAsset`] to
     /// [`EnvObjDefinition`]
     pub gltf_handle: Option<Handle<Gltf>>,
+    #[serde(skip)]
+    #[dependency]
+    /// Asset dependency, the size of the sprite is needed to spawn it
+    pub sprite_handle: Option<Handle<Image>>,
 }
 
 impl EnvObjAsset {
@@ -31,3 +55,29 @@ impl EnvObjAsset {
 fn f_true() -> bool {
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::EnvObjAsset;
+    use crate::utils::billboard::data::SpritePlayback;
+
+    #[test]
+    fn test_env_asset_sprite() {
+        let asset: EnvObjAsset =
+            ron::de::from_str(r#"(id: "skybox", gltf: "Skybox.glb", placeable: false)"#).unwrap();
+        assert_eq!(asset.gltf.as_deref(), Some("Skybox.glb"));
+        assert_eq!(asset.sprite, None);
+
+        let asset: EnvObjAsset = ron::de::from_str(
+            r#"(
+                id: "torch",
+                sprite: "torch.png",
+                animation: Some((frame_size: (16, 48), frames: 3, fps: 8.0, playback: PingPong)),
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(asset.gltf, None);
+        assert!(asset.placeable);
+        assert_eq!(asset.animation.unwrap().playback, SpritePlayback::PingPong);
+    }
+}
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use crate::utils::billboard::data::SpriteSheet;
+
+/// How a loaded environment object is drawn
+pub enum EnvObjAppearance {
+    /// The default scene of a glTF model
+    Model {
+        gltf: Handle<Gltf>,
+        scene: Handle<Scene>,
+    },
+    /// An upright billboard, animated when the image is a sprite sheet
+    Sprite {
+        image: Handle<Image>,
+        /// The size of the image, or of one frame of the animation, in pixels
+        size: UVec2,
+        animation: Option<SpriteSheet>,
+    },
+}
+
+impl EnvObjAppearance {
+    /// The scene of a model, `None` for sprites
+    pub fn scene(&self) -> Option<&Handle<Scene>> {
+        match self {
+            EnvObjAppearance::Model { scene, .. } => Some(scene),
+            EnvObjAppearance::Sprite { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,2 @@
+mod env_obj_appearance;
+pub use self::env_obj_appearance::*;
@@ -1,16 +1,14 @@
 use bevy::prelude::*;
 use thiserror::Error;
 
-use crate::environment::assets::EnvObjAsset;
+use crate::environment::{assets::EnvObjAsset, data::EnvObjAppearance};
 
 /// An intermediate step between an environment asset and a spawned environment object
 pub struct EnvObjDefinition {
     /// The unique ID of the environment element
     pub id: String,
-    /// The model of the environment element
-    pub gltf: Handle<Gltf>,
-    /// The default scene of the environment element
-    pub scene: Handle<Scene>,
+    /// The model or sprite of the environment element
+    pub appearance: EnvObjAppearance,
     /// Whether this object can be set on the map in the editor
     /// This should be set to `false` for global environment like the skybox
     pub placeable: bool,
@@ -18,13 +16,42 @@ pub struct EnvObjDefinition {
 
 impl EnvObjDefinition {
     /// Helper to convert from [`EnvObjAsset`] to [`EnvObjDefinition`]
-    pub fn from_asset(gltfs: &Assets<Gltf>, asset: &EnvObjAsset) -> Result<Self, EnvObjDefinitionLoadError> {
-        let gltf_handle = asset.gltf_handle.clone().ok_or(EnvObjDefinitionLoadError::MissingGltfHandle)?;
-        let gltf = gltfs.get(&gltf_handle).ok_or(EnvObjDefinitionLoadError::BrokenGltfHandle)?;
+    pub fn from_asset(
+        gltfs: &Assets<Gltf>,
+        images: &Assets<Image>,
+        asset: &EnvObjAsset,
+    ) -> Result<Self, EnvObjDefinitionLoadError> {
+        let appearance = match (&asset.gltf, &asset.sprite) {
+            (Some(_), None) => {
+                let gltf_handle =
+                    asset.gltf_handle.clone().ok_or(EnvObjDefinitionLoadError::MissingGltfHandle)?;
+                let gltf = gltfs.get(&gltf_handle).ok_or(EnvObjDefinitionLoadError::BrokenGltfHandle)?;
+                EnvObjAppearance::Model {
+                    scene: gltf.scenes.first().ok_or(EnvObjDefinitionLoadError::NoDefaultScene)?.clone(),
+                    gltf: gltf_handle,
+                }
+            },
+            (None, Some(_)) => {
+                let image_handle =
+                    asset.sprite_handle.clone().ok_or(EnvObjDefinitionLoadError::MissingSpriteHandle)?;
+                let image = images.get(&image_handle).ok_or(EnvObjDefinitionLoadError::BrokenSpriteHandle)?;
+                let size = match &asset.animation {
+                    Some(animation) => UVec2::new(animation.frame_size.0, animation.frame_size.1),
+                    None => image.size(),
+                };
+                EnvObjAppearance::Sprite {
+                    image: image_handle,
+                    size,
+                    animation: asset.animation.clone(),
+                }
+            },
+            (None, None) => return Err(EnvObjDefinitionLoadError::NoAppearance),
+            (Some(_), Some(_)) => return Err(EnvObjDefinitionLoadError::AmbiguousAppearance),
+        };
+
         Ok(Self {
             id: asset.id.clone(),
-            gltf: gltf_handle,
-            scene: gltf.scenes.first().ok_or(EnvObjDefinitionLoadError::NoDefaultScene)?.clone(),
+            appearance,
             placeable: asset.placeable,
         })
     }
@@ -36,6 +63,14 @@ pub enum EnvObjDefinitionLoadError {
     MissingGltfHandle,
     #[error("Unexpected internal error: broken gltf handle")]
     BrokenGltfHandle,
+    #[error("Unexpected internal error: there is no sprite handle")]
+    MissingSpriteHandle,
+    #[error("Unexpected internal error: broken sprite handle")]
+    BrokenSpriteHandle,
+    #[error("Either a gltf model or a sprite is needed to draw the object")]
+    NoAppearance,
+    #[error("Only one of a gltf model and a sprite can be given")]
+    AmbiguousAppearance,
     #[error("The model does not contain any scenes")]
     NoDefaultScene,
 }
@@ -0,0 +1,30 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::{episodes::data::SecretLevel, items::data::ItemEffect};
+
+/// This gets loaded by the AssetManager
+/// Represents an episode, an ordered run of levels played from New Game
+#[derive(Serialize, Deserialize, Asset, TypePath)]
+pub struct EpisodeAsset {
+    /// This must be unique as it is used as the key when looking up from the "EpisodeLibrary" resource
+    pub id: String,
+    /// The name shown when choosing an episode
+    pub name: String,
+    /// The level ids in the order they are played, starting with the first
+    pub levels: Vec<String>,
+    /// Levels reached through secret exits, which are not part of the main order
+    #[serde(default)]
+    pub secret_levels: Vec<SecretLevel>,
+    /// What the player is given when starting the episode
+    #[serde(default)]
+    pub starting_inventory: Vec<ItemEffect>,
+    /// The music played in each level, keyed by level id
+    #[serde(default)]
+    pub music: HashMap<String, String>,
+}
+
+impl EpisodeAsset {
+    /// The file extension for these assets
+    pub const EXTENSION: &'static str = "episode";
+}
@@ -0,0 +1,2 @@
+mod episode_asset;
+pub use self::episode_asset::*;
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+/// A problem found when checking an episode against its levels
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum EpisodeValidationError {
+    #[error("Episode '{episode_id}' has no levels")]
+    NoLevels { episode_id: String },
+    #[error("Level '{level_id}' of episode '{episode_id}' could not be loaded: {reason}")]
+    MissingLevel {
+        episode_id: String,
+        level_id: String,
+        reason: String,
+    },
+    #[error(
+        "Exit at {position:?} in level '{level_id}' leads to '{target}' which is not part of episode '{episode_id}'"
+    )]
+    UnknownExit {
+        episode_id: String,
+        level_id: String,
+        position: (i32, i32),
+        target: String,
+    },
+    #[error(
+        "Secret level '{level_id}' of episode '{episode_id}' refers to '{target}' which is not part of the episode"
+    )]
+    UnknownSecretBranch {
+        episode_id: String,
+        level_id: String,
+        target: String,
+    },
+}
@@ -0,0 +1,5 @@
+mod episode_validation_error;
+pub use self::episode_validation_error::*;
+
+mod secret_level;
+pub use self::secret_level::*;
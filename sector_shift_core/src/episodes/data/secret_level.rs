@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A level branching off the main order of an episode
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+pub struct SecretLevel {
+    /// The id of the secret level
+    pub level: String,
+    /// The level containing the secret exit
+    pub from: String,
+    /// The level the secret level's exit returns to
+    pub return_to: String,
+}
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::{
+    episodes::{assets::EpisodeAsset, resources::EpisodeLibrary, systems::process_episodes},
+    utils::{generic_asset_loader::GenericAssetLoader, generic_directory_loader::DirectoryLoaderPlugin},
+};
+
+#[derive(Clone)]
+pub struct EpisodesPlugin<T: States + Copy> {
+    asset_load_state: T,
+}
+
+impl<T: States + Copy> EpisodesPlugin<T> {
+    pub fn new(asset_load_state: T) -> Self {
+        Self { asset_load_state }
+    }
+}
+
+impl<T: States + Copy> Plugin for EpisodesPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<EpisodeAsset>();
+        app.register_asset_loader(GenericAssetLoader::<EpisodeAsset>::new(&[
+            EpisodeAsset::EXTENSION,
+        ]));
+
+        app.init_resource::<EpisodeLibrary>();
+        app.add_plugins(DirectoryLoaderPlugin::<EpisodeLibrary, _>::new(
+            self.asset_load_state,
+        ));
+
+        app.add_systems(
+            Update,
+            process_episodes.run_if(in_state(self.asset_load_state)),
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod assets;
+pub mod data;
+pub mod resources;
+pub mod systems;
+
+mod episodes_plugin;
+pub use self::episodes_plugin::*;
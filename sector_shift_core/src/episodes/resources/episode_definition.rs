@@ -0,0 +1,237 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::{
+    episodes::{
+        assets::EpisodeAsset,
+        data::{EpisodeValidationError, SecretLevel},
+    },
+    items::data::ItemEffect,
+    maps::{Level, LevelError, MapObject},
+};
+
+/// An intermediate step between an episode asset and a started game
+#[derive(Reflect, Debug, Clone)]
+pub struct EpisodeDefinition {
+    /// The unique ID of the episode
+    pub id: String,
+    /// The name shown when choosing an episode
+    pub name: String,
+    /// The level ids in the order they are played
+    pub levels: Vec<String>,
+    /// Levels reached through secret exits
+    pub secret_levels: Vec<SecretLevel>,
+    /// What the player is given when starting the episode
+    pub starting_inventory: Vec<ItemEffect>,
+    /// The music played in each level, keyed by level id
+    pub music: HashMap<String, String>,
+}
+
+impl EpisodeDefinition {
+    /// Helper to convert from EpisodeAsset to EpisodeDefinition
+    pub fn from_asset(asset: &EpisodeAsset) -> Self {
+        Self {
+            id: asset.id.clone(),
+            name: asset.name.clone(),
+            levels: asset.levels.clone(),
+            secret_levels: asset.secret_levels.clone(),
+            starting_inventory: asset.starting_inventory.clone(),
+            music: asset.music.clone(),
+        }
+    }
+
+    /// The level a new game of this episode starts on
+    pub fn first_level(&self) -> Option<&str> {
+        self.levels.first().map(String::as_str)
+    }
+
+    /// Whether leaving the level finishes the episode, which is the case for the last level of the main
+    /// order whatever its exits lead to
+    pub fn is_last_level(&self, level_id: &str) -> bool {
+        self.levels.last().is_some_and(|id| id == level_id)
+    }
+
+    /// The music for a level, if the episode sets any
+    pub fn music_for(&self, level_id: &str) -> Option<&str> {
+        self.music.get(level_id).map(String::as_str)
+    }
+
+    /// Whether the level is part of this episode, including secret levels
+    pub fn contains(&self, level_id: &str) -> bool {
+        self.levels.iter().any(|id| id == level_id)
+            || self.secret_levels.iter().any(|secret| secret.level == level_id)
+    }
+
+    /// Every level of the episode, main order first and secret levels after
+    pub fn all_levels(&self) -> impl Iterator<Item = &str> {
+        self.levels.iter().map(String::as_str).chain(self.secret_levels.iter().map(|s| s.level.as_str()))
+    }
+
+    /// Checks that every level of the episode loads and that every exit leads to a level of the episode.
+    /// The exits of the last level are left out, they finish the episode.
+    ///
+    /// `load_level` is usually [`Level::try_load`], see [`Self::validate_files`].
+    pub fn validate(
+        &self,
+        mut load_level: impl FnMut(&str) -> Result<Level, LevelError>,
+    ) -> Vec<EpisodeValidationError> {
+        let mut errors = Vec::new();
+
+        if self.levels.is_empty() {
+            errors.push(EpisodeValidationError::NoLevels {
+                episode_id: self.id.clone(),
+            });
+        }
+
+        for secret in &self.secret_levels {
+            for target in [&secret.from, &secret.return_to] {
+                if !self.contains(target) {
+                    errors.push(EpisodeValidationError::UnknownSecretBranch {
+                        episode_id: self.id.clone(),
+                        level_id: secret.level.clone(),
+                        target: target.clone(),
+                    });
+                }
+            }
+        }
+
+        for level_id in self.all_levels() {
+            let level = match load_level(level_id) {
+                Ok(level) => level,
+                Err(e) => {
+                    errors.push(EpisodeValidationError::MissingLevel {
+                        episode_id: self.id.clone(),
+                        level_id: level_id.to_string(),
+                        reason: e.to_string(),
+                    });
+                    continue;
+                },
+            };
+
+            if self.is_last_level(level_id) {
+                continue;
+            }
+            let mut exits: Vec<_> = level
+                .objects
+                .iter()
+                .filter_map(|(position, object)| match object {
+                    MapObject::Exit(target) if !self.contains(target) => Some((*position, target)),
+                    _ => None,
+                })
+                .collect();
+            // Report in a stable order
+            exits.sort_by_key(|(position, _)| *position);
+
+            for (position, target) in exits {
+                errors.push(EpisodeValidationError::UnknownExit {
+                    episode_id: self.id.clone(),
+                    level_id: level_id.to_string(),
+                    position,
+                    target: target.clone(),
+                });
+            }
+        }
+
+        errors
+    }
+
+    /// Validates the episode against the level files on disk
+    pub fn validate_files(&self) -> Vec<EpisodeValidationError> {
+        self.validate(|level_id| Level::try_load(level_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::platform::collections::HashMap;
+
+    use super::EpisodeDefinition;
+    use crate::{
+        episodes::data::{EpisodeValidationError, SecretLevel},
+        maps::{Level, LevelError, ObjectProperties},
+    };
+
+    fn episode() -> EpisodeDefinition {
+        EpisodeDefinition {
+            id: "episode_1".to_string(),
+            name: "Episode 1".to_string(),
+            levels: vec!["level_1".to_string(), "level_2".to_string()],
+            secret_levels: vec![SecretLevel {
+                level: "secret_1".to_string(),
+                from: "level_1".to_string(),
+                return_to: "level_2".to_string(),
+            }],
+            starting_inventory: Vec::new(),
+            music: HashMap::new(),
+        }
+    }
+
+    fn levels() -> HashMap<String, Level> {
+        let mut level_1 = Level::new("level_1", (8, 8));
+        level_1.add_exit((1, 1), "level_2");
+        level_1.add_exit((2, 1), "secret_1");
+        let mut level_2 = Level::new("level_2", (8, 8));
+        level_2.add_enemy((3, 3), "slime", ObjectProperties::default());
+        let mut secret_1 = Level::new("secret_1", (8, 8));
+        secret_1.add_exit((1, 1), "level_2");
+
+        [level_1, level_2, secret_1].into_iter().map(|level| (level.id.clone(), level)).collect()
+    }
+
+    fn load_from(levels: &HashMap<String, Level>) -> impl FnMut(&str) -> Result<Level, LevelError> {
+        |id| levels.get(id).cloned().ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound).into())
+    }
+
+    #[test]
+    fn test_episode_valid() {
+        let levels = levels();
+        assert_eq!(episode().validate(load_from(&levels)), Vec::new());
+    }
+
+    #[test]
+    fn test_episode_unknown_exit_and_missing_level() {
+        let mut levels = levels();
+        levels.get_mut("level_1").unwrap().add_exit((4, 4), "level_3");
+        levels.remove("secret_1");
+
+        let errors = episode().validate(load_from(&levels));
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(
+            &errors[0],
+            EpisodeValidationError::UnknownExit { level_id, position: (4, 4), target, .. }
+                if level_id == "level_1" && target == "level_3"
+        ));
+        assert!(matches!(
+            &errors[1],
+            EpisodeValidationError::MissingLevel { level_id, .. } if level_id == "secret_1"
+        ));
+    }
+
+    #[test]
+    fn test_episode_last_level_exit() {
+        let mut levels = levels();
+        levels.get_mut("level_2").unwrap().add_exit((4, 4), "the_end");
+
+        let episode = episode();
+        assert_eq!(episode.validate(load_from(&levels)), Vec::new());
+        assert!(episode.is_last_level("level_2"));
+        assert!(!episode.is_last_level("level_1"));
+        assert!(!episode.is_last_level("secret_1"));
+    }
+
+    #[test]
+    fn test_episode_secret_branch() {
+        let mut episode = episode();
+        episode.secret_levels[0].return_to = "level_9".to_string();
+        let levels = levels();
+
+        let errors = episode.validate(load_from(&levels));
+        assert_eq!(
+            errors,
+            vec![EpisodeValidationError::UnknownSecretBranch {
+                episode_id: "episode_1".to_string(),
+                level_id: "secret_1".to_string(),
+                target: "level_9".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::episodes::{assets::EpisodeAsset, resources::EpisodeDefinition};
+
+/// A library of episodes which can be started from New Game
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
+pub struct EpisodeLibrary {
+    /// A map of loaded episode definitions sorted by their id
+    pub map: HashMap<String, EpisodeDefinition>,
+    /// A list of episode assets currently being loaded
+    pub loading: Vec<Handle<EpisodeAsset>>,
+    /// Indicates that all handles in `Self.loading` are fully loaded
+    pub loading_finished: bool,
+}
+
+impl EpisodeLibrary {
+    /// Get an episode definition by its unique ID
+    pub fn get(&self, id: &str) -> Option<&EpisodeDefinition> {
+        self.map.get(id)
+    }
+
+    /// Add a new episode definition to the library
+    pub fn add(&mut self, definition: EpisodeDefinition) {
+        self.map.insert(definition.id.clone(), definition);
+    }
+
+    /// All episodes sorted by name
+    pub fn sorted(&self) -> Vec<&EpisodeDefinition> {
+        let mut episodes: Vec<_> = self.map.values().collect();
+        episodes.sort_by(|a, b| a.name.cmp(&b.name));
+        episodes
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.loading_finished && self.loading.is_empty()
+    }
+}
@@ -0,0 +1,5 @@
+mod episode_definition;
+pub use self::episode_definition::*;
+
+mod episode_library;
+pub use self::episode_library::*;
@@ -0,0 +1,2 @@
+mod process_episodes;
+pub use self::process_episodes::*;
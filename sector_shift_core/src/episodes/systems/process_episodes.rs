@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+use crate::episodes::{
+    assets::EpisodeAsset,
+    resources::{EpisodeDefinition, EpisodeLibrary},
+};
+
+/// A system that processes loaded episode assets, validates them and adds them to the EpisodeLibrary
+pub fn process_episodes(a_episodes: Res<Assets<EpisodeAsset>>, mut episode_library: ResMut<EpisodeLibrary>) {
+    if episode_library.loading_finished {
+        for handle in std::mem::take(&mut episode_library.loading) {
+            if let Some(episode_asset) = a_episodes.get(&handle) {
+                let definition = EpisodeDefinition::from_asset(episode_asset);
+                for error in definition.validate_files() {
+                    warn!("{error}");
+                }
+                info!("Loaded episode asset with id: {}", definition.id);
+                episode_library.add(definition);
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::items::data::ItemEffect;
+use crate::{
+    items::data::{ItemCondition, ItemEffect},
+    utils::billboard::data::SpriteSheet,
+};
 
 /// This gets loaded by the AssetManager
 /// Represents an item type that can be picked up in levels
@@ -11,8 +14,14 @@ pub struct ItemAsset {
     pub id: String,
     /// The effect this item has when picked up
     pub effect: ItemEffect,
+    /// All of these have to be met for the item to be picked up, otherwise it stays where it is
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<ItemCondition>,
     /// This is the path to the image to be loaded
     pub sprite: String,
+    /// Plays the sprite as an animation, the image then holds all of its frames
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animation: Option<SpriteSheet>,
 }
 
 impl ItemAsset {
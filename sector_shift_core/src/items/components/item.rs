@@ -1,12 +1,14 @@
 use bevy::prelude::*;
 
-use crate::items::data::ItemEffect;
+use crate::items::data::{ItemCondition, ItemEffect};
 
 /// Item component
-/// This holds the item's pickup effect
+/// This holds the item's pickup effect and when it can be picked up
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
 pub struct Item {
     /// The effect this item has when picked up
     pub effect: ItemEffect,
+    /// All of these have to be met for the item to be picked up
+    pub conditions: Vec<ItemCondition>,
 }
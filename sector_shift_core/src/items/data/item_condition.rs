@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::items::data::ItemTarget;
+
+/// Something that has to be true for an item to be picked up, otherwise it stays on the floor
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+pub enum ItemCondition {
+    /// Only while health is below a value
+    /// (Health)
+    HealthBelow(i32),
+    /// Only while armor is below a value
+    /// (Armor)
+    ArmorBelow(i32),
+    /// Only if the weapon is owned, e.g. for ammo of a weapon that has not been found yet
+    /// (WeaponId)
+    HasWeapon(String),
+    /// Only if the weapon isn't owned yet
+    /// (WeaponId)
+    LacksWeapon(String),
+}
+
+impl ItemCondition {
+    pub fn is_met(&self, target: &(impl ItemTarget + ?Sized)) -> bool {
+        match self {
+            ItemCondition::HealthBelow(health) => target.health() < *health,
+            ItemCondition::ArmorBelow(armor) => target.armor() < *armor,
+            ItemCondition::HasWeapon(weapon_id) => target.has_weapon(weapon_id),
+            ItemCondition::LacksWeapon(weapon_id) => !target.has_weapon(weapon_id),
+        }
+    }
+
+    /// Whether an item with these conditions can be picked up
+    pub fn all_met(conditions: &[ItemCondition], target: &(impl ItemTarget + ?Sized)) -> bool {
+        conditions.iter().all(|condition| condition.is_met(target))
+    }
+}
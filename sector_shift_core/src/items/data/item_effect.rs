@@ -1,12 +1,23 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    difficulty::resources::Difficulty,
+    items::data::{ItemTarget, PowerUp},
+};
+
 /// The effect an item has when picked up
-#[derive(Serialize, Deserialize, Reflect, Debug, Clone)]
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
 pub enum ItemEffect {
-    /// Restore a certain amount of health
+    /// Restore a certain amount of health, up to the maximum
     /// (Amount)
     Health(i32),
+    /// Raise health above the maximum, it decays back down to the maximum over time
+    /// (Amount, Limit)
+    Overheal(i32, i32),
+    /// Add armor, which absorbs part of the damage taken
+    /// (Amount, Limit)
+    Armor(i32, i32),
     /// Add ammo for a specific weapon
     /// (WeaponId, Amount)
     Ammo(String, u32),
@@ -16,6 +27,14 @@ pub enum ItemEffect {
     /// Increase the player's score
     /// (Amount)
     Score(u32),
+    /// Give a temporary power-up, picking up another one restarts the longer of the two timers
+    /// (PowerUp, Seconds)
+    PowerUp(PowerUp, f32),
+    /// Give the player an extra life
+    ExtraLife,
+    /// Apply several effects in order
+    /// (Effects)
+    List(Vec<ItemEffect>),
 }
 
 impl Default for ItemEffect {
@@ -23,3 +42,215 @@ impl Default for ItemEffect {
         ItemEffect::Score(100)
     }
 }
+
+impl ItemEffect {
+    /// Applies the effect to whatever picked up the item
+    pub fn apply(&self, target: &mut (impl ItemTarget + ?Sized)) {
+        match self {
+            ItemEffect::Health(amount) => {
+                // Healing never takes away overheal
+                if target.health() < target.max_health() {
+                    target.set_health((target.health() + amount).min(target.max_health()));
+                }
+            },
+            ItemEffect::Overheal(amount, limit) => {
+                let health = (target.health() + amount).min(*limit);
+                target.set_health(health.max(target.health()));
+            },
+            ItemEffect::Armor(amount, limit) => {
+                let armor = (target.armor() + amount).min(*limit);
+                target.set_armor(armor.max(target.armor()));
+            },
+            ItemEffect::Ammo(weapon_id, amount) => target.give_ammo(weapon_id, *amount),
+            ItemEffect::Weapon(weapon_id) => target.give_weapon(weapon_id),
+            ItemEffect::Score(amount) => target.add_score(*amount),
+            ItemEffect::PowerUp(power_up, seconds) => {
+                let remaining = target.power_up_remaining(*power_up).max(*seconds);
+                target.set_power_up_remaining(*power_up, remaining);
+            },
+            ItemEffect::ExtraLife => target.add_life(),
+            ItemEffect::List(effects) => {
+                for effect in effects {
+                    effect.apply(target);
+                }
+            },
+        }
+    }
+
+    /// The effect with its ammo scaled by the difficulty, including ammo inside lists
+    pub fn with_ammo_scaled(&self, difficulty: &Difficulty) -> Self {
+        match self {
+            ItemEffect::Ammo(weapon_id, amount) => {
+                ItemEffect::Ammo(weapon_id.clone(), difficulty.scale_ammo(*amount))
+            },
+            ItemEffect::List(effects) => {
+                ItemEffect::List(effects.iter().map(|effect| effect.with_ammo_scaled(difficulty)).collect())
+            },
+            effect => effect.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::platform::collections::HashMap;
+
+    use super::ItemEffect;
+    use crate::items::data::{ItemCondition, ItemTarget, PowerUp};
+
+    #[derive(Default)]
+    struct TestTarget {
+        health: i32,
+        armor: i32,
+        weapons: Vec<String>,
+        ammo: HashMap<String, u32>,
+        score: u32,
+        lives: u32,
+        power_ups: HashMap<PowerUp, f32>,
+    }
+
+    impl ItemTarget for TestTarget {
+        fn health(&self) -> i32 {
+            self.health
+        }
+        fn max_health(&self) -> i32 {
+            100
+        }
+        fn set_health(&mut self, health: i32) {
+            self.health = health;
+        }
+        fn armor(&self) -> i32 {
+            self.armor
+        }
+        fn set_armor(&mut self, armor: i32) {
+            self.armor = armor;
+        }
+        fn has_weapon(&self, weapon_id: &str) -> bool {
+            self.weapons.iter().any(|weapon| weapon == weapon_id)
+        }
+        fn give_weapon(&mut self, weapon_id: &str) {
+            self.weapons.push(weapon_id.to_string());
+        }
+        fn give_ammo(&mut self, weapon_id: &str, amount: u32) {
+            *self.ammo.entry(weapon_id.to_string()).or_default() += amount;
+        }
+        fn add_score(&mut self, amount: u32) {
+            self.score += amount;
+        }
+        fn add_life(&mut self) {
+            self.lives += 1;
+        }
+        fn power_up_remaining(&self, power_up: PowerUp) -> f32 {
+            self.power_ups.get(&power_up).copied().unwrap_or_default()
+        }
+        fn set_power_up_remaining(&mut self, power_up: PowerUp, seconds: f32) {
+            self.power_ups.insert(power_up, seconds);
+        }
+    }
+
+    fn target(health: i32) -> TestTarget {
+        TestTarget {
+            health,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_health_effect() {
+        let mut player = target(80);
+        ItemEffect::Health(50).apply(&mut player);
+        assert_eq!(player.health, 100);
+
+        // Overheal isn't lost by picking up a medkit
+        let mut player = target(150);
+        ItemEffect::Health(50).apply(&mut player);
+        assert_eq!(player.health, 150);
+    }
+
+    #[test]
+    fn test_overheal_effect() {
+        let mut player = target(90);
+        ItemEffect::Overheal(100, 200).apply(&mut player);
+        assert_eq!(player.health, 190);
+        ItemEffect::Overheal(100, 200).apply(&mut player);
+        assert_eq!(player.health, 200);
+
+        // A smaller limit never lowers health
+        ItemEffect::Overheal(10, 150).apply(&mut player);
+        assert_eq!(player.health, 200);
+    }
+
+    #[test]
+    fn test_armor_effect() {
+        let mut player = target(100);
+        ItemEffect::Armor(100, 100).apply(&mut player);
+        assert_eq!(player.armor, 100);
+        ItemEffect::Armor(5, 100).apply(&mut player);
+        assert_eq!(player.armor, 100);
+        ItemEffect::Armor(200, 200).apply(&mut player);
+        assert_eq!(player.armor, 200);
+        ItemEffect::Armor(100, 100).apply(&mut player);
+        assert_eq!(player.armor, 200);
+    }
+
+    #[test]
+    fn test_power_up_effect() {
+        let mut player = target(100);
+        ItemEffect::PowerUp(PowerUp::QuadDamage, 30.0).apply(&mut player);
+        assert_eq!(player.power_up_remaining(PowerUp::QuadDamage), 30.0);
+
+        player.set_power_up_remaining(PowerUp::QuadDamage, 10.0);
+        ItemEffect::PowerUp(PowerUp::QuadDamage, 30.0).apply(&mut player);
+        assert_eq!(player.power_up_remaining(PowerUp::QuadDamage), 30.0);
+        ItemEffect::PowerUp(PowerUp::QuadDamage, 5.0).apply(&mut player);
+        assert_eq!(player.power_up_remaining(PowerUp::QuadDamage), 30.0);
+        assert_eq!(player.power_up_remaining(PowerUp::Invulnerability), 0.0);
+    }
+
+    #[test]
+    fn test_inventory_effects() {
+        let mut player = target(100);
+        let effect: ItemEffect = ron::de::from_str(
+            r#"List([Weapon("shotgun"), Ammo("shotgun", 8), Score(500), ExtraLife, ExtraLife])"#,
+        )
+        .unwrap();
+        effect.apply(&mut player);
+        assert!(player.has_weapon("shotgun"));
+        assert_eq!(player.ammo["shotgun"], 8);
+        assert_eq!(player.score, 500);
+        assert_eq!(player.lives, 2);
+    }
+
+    #[test]
+    fn test_effect_syntax() {
+        let effect: ItemEffect = ron::de::from_str(
+            "List([Overheal(100, 200), Armor(200, 200), PowerUp(Invulnerability, 30.0), PowerUp(MapReveal, 600.0)])",
+        )
+        .unwrap();
+        assert_eq!(
+            effect,
+            ItemEffect::List(vec![
+                ItemEffect::Overheal(100, 200),
+                ItemEffect::Armor(200, 200),
+                ItemEffect::PowerUp(PowerUp::Invulnerability, 30.0),
+                ItemEffect::PowerUp(PowerUp::MapReveal, 600.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_item_conditions() {
+        let conditions: Vec<ItemCondition> =
+            ron::de::from_str(r#"[HealthBelow(100), HasWeapon("shotgun")]"#).unwrap();
+        let mut player = target(60);
+        assert!(!ItemCondition::all_met(&conditions, &player));
+        player.give_weapon("shotgun");
+        assert!(ItemCondition::all_met(&conditions, &player));
+        player.health = 100;
+        assert!(!ItemCondition::all_met(&conditions, &player));
+
+        assert!(ItemCondition::ArmorBelow(100).is_met(&player));
+        assert!(!ItemCondition::LacksWeapon("shotgun".to_string()).is_met(&player));
+        assert!(ItemCondition::all_met(&[], &player));
+    }
+}
@@ -0,0 +1,22 @@
+use crate::items::data::PowerUp;
+
+/// Whatever picks up items, usually the player. [`ItemEffect::apply`](crate::items::data::ItemEffect::apply)
+/// and [`ItemCondition::is_met`](crate::items::data::ItemCondition::is_met) work through this so the
+/// rules of each effect don't depend on where the game keeps health or the inventory.
+pub trait ItemTarget {
+    fn health(&self) -> i32;
+    /// The health [`ItemEffect::Health`](crate::items::data::ItemEffect::Health) heals up to,
+    /// overheal decays back down to it
+    fn max_health(&self) -> i32;
+    fn set_health(&mut self, health: i32);
+    fn armor(&self) -> i32;
+    fn set_armor(&mut self, armor: i32);
+    fn has_weapon(&self, weapon_id: &str) -> bool;
+    fn give_weapon(&mut self, weapon_id: &str);
+    fn give_ammo(&mut self, weapon_id: &str, amount: u32);
+    fn add_score(&mut self, amount: u32);
+    fn add_life(&mut self);
+    /// Seconds left on a power-up, zero when it isn't active
+    fn power_up_remaining(&self, power_up: PowerUp) -> f32;
+    fn set_power_up_remaining(&mut self, power_up: PowerUp, seconds: f32);
+}
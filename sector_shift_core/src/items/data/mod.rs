@@ -1,2 +1,11 @@
+mod item_condition;
+pub use self::item_condition::*;
+
 mod item_effect;
 pub use self::item_effect::*;
+
+mod item_target;
+pub use self::item_target::*;
+
+mod power_up;
+pub use self::power_up::*;
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A temporary ability given by an item
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerUp {
+    /// The player takes no damage
+    Invulnerability,
+    /// The player deals four times the damage
+    QuadDamage,
+    /// The whole level is shown on the map
+    MapReveal,
+}
+
+impl PowerUp {
+    pub const ALL: [PowerUp; 3] = [
+        PowerUp::Invulnerability,
+        PowerUp::QuadDamage,
+        PowerUp::MapReveal,
+    ];
+}
@@ -1,6 +1,12 @@
 use bevy::prelude::*;
 
-use crate::items::{assets::ItemAsset, data::ItemEffect};
+use crate::{
+    items::{
+        assets::ItemAsset,
+        data::{ItemCondition, ItemEffect},
+    },
+    utils::billboard::data::SpriteSheet,
+};
 
 /// An intermediate step between an item asset and a spawned item
 #[derive(Reflect)]
@@ -9,8 +15,12 @@ pub struct ItemDefinition {
     pub id: String,
     /// The effect this item has when picked up
     pub effect: ItemEffect,
+    /// All of these have to be met for the item to be picked up
+    pub conditions: Vec<ItemCondition>,
     /// The sprite handle for the item
     pub sprite: Handle<Image>,
+    /// The frames of the sprite when it is animated
+    pub animation: Option<SpriteSheet>,
 }
 
 impl ItemDefinition {
@@ -19,7 +29,9 @@ impl ItemDefinition {
         Self {
             id: asset.id.clone(),
             effect: asset.effect.clone(),
+            conditions: asset.conditions.clone(),
             sprite: asset_server.load(&asset.sprite),
+            animation: asset.animation.clone(),
         }
     }
 }
@@ -1,34 +1,68 @@
+pub mod difficulty;
 pub mod enemies;
 pub mod environment;
+pub mod episodes;
 pub mod items;
 pub mod maps;
+pub mod projectiles;
+pub mod sounds;
 pub mod utils;
 
 mod sector_shift_core_plugin;
 pub use self::sector_shift_core_plugin::*;
 
 pub mod prelude {
+    // Difficulty
+    pub use crate::difficulty::resources::Difficulty;
+    pub use crate::difficulty::resources::DifficultyLibrary;
+
     // Enemies
     pub use crate::enemies::components::Enemy;
+    pub use crate::enemies::data::EnemyAnimation;
+    pub use crate::enemies::data::EnemyStats;
     pub use crate::enemies::resources::EnemyLibrary;
 
+    // Episodes
+    pub use crate::episodes::resources::EpisodeDefinition;
+    pub use crate::episodes::resources::EpisodeLibrary;
+
     // Items
     pub use crate::items::components::Item;
+    pub use crate::items::data::ItemCondition;
     pub use crate::items::data::ItemEffect;
+    pub use crate::items::data::ItemTarget;
+    pub use crate::items::data::PowerUp;
     pub use crate::items::resources::ItemLibrary;
 
     // Environment objects
     pub use crate::environment::resources::EnvObjLibrary;
 
     // Maps
+    pub use crate::maps::DifficultyLevel;
     pub use crate::maps::DoorAxis;
     pub use crate::maps::Level;
+    pub use crate::maps::LevelProblem;
     pub use crate::maps::MapObject;
+    pub use crate::maps::ObjectFlag;
+    pub use crate::maps::ObjectProperties;
+    pub use crate::maps::PatrolRoute;
+    pub use crate::maps::ProblemSeverity;
     pub use crate::maps::TileType;
 
+    // Projectiles
+    pub use crate::projectiles::resources::ProjectileLibrary;
+
+    // Sounds
+    pub use crate::sounds::components::SoundOverrides;
+    pub use crate::sounds::data::SoundEvent;
+    pub use crate::sounds::resources::SoundLibrary;
+
     // Utils
     pub use crate::utils::all_assets_loaded;
+    pub use crate::utils::billboard::components::AnimatedBillboard;
     pub use crate::utils::billboard::components::Billboard;
+    pub use crate::utils::billboard::data::SpritePlayback;
+    pub use crate::utils::billboard::data::SpriteSheet;
     pub use crate::utils::direction::CardinalDirection;
     pub use crate::utils::direction::Direction;
     pub use crate::utils::direction::OrdinalDirection;
@@ -42,6 +76,12 @@ pub mod prelude {
     pub use crate::utils::grid::shapes::circle::Circle;
     pub use crate::utils::grid::shapes::line::Line;
     pub use crate::utils::grid::shapes::rectangle::Rectangle;
+    pub use crate::utils::input_map::ActionState;
+    pub use crate::utils::input_map::AxisDirection;
+    pub use crate::utils::input_map::InputAction;
+    pub use crate::utils::input_map::InputBinding;
+    pub use crate::utils::input_map::InputMap;
+    pub use crate::utils::input_map::InputMapPlugin;
 
     // Plugin
     pub use crate::SectorShiftCorePlugin;
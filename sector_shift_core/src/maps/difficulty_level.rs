@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The skill level chosen by the player when starting a game.
+#[derive(
+    Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
+pub enum DifficultyLevel {
+    /// Fewer enemies, less incoming damage.
+    Easy,
+    /// The intended experience.
+    #[default]
+    Normal,
+    /// More enemies, more incoming damage.
+    Hard,
+    /// Everything the map has to offer.
+    Nightmare,
+}
+
+impl DifficultyLevel {
+    /// All difficulty levels ordered from easiest to hardest.
+    pub const ALL: [Self; 4] = [Self::Easy, Self::Normal, Self::Hard, Self::Nightmare];
+
+    /// Returns a human readable name for menus and the editor.
+    pub fn name(self) -> &'static str {
+        match self {
+            DifficultyLevel::Easy => "Easy",
+            DifficultyLevel::Normal => "Normal",
+            DifficultyLevel::Hard => "Hard",
+            DifficultyLevel::Nightmare => "Nightmare",
+        }
+    }
+
+    /// Returns the next harder difficulty, staying at the hardest.
+    pub fn harder(self) -> Self {
+        match self {
+            DifficultyLevel::Easy => DifficultyLevel::Normal,
+            DifficultyLevel::Normal => DifficultyLevel::Hard,
+            DifficultyLevel::Hard | DifficultyLevel::Nightmare => DifficultyLevel::Nightmare,
+        }
+    }
+
+    /// Returns the next easier difficulty, staying at the easiest.
+    pub fn easier(self) -> Self {
+        match self {
+            DifficultyLevel::Easy | DifficultyLevel::Normal => DifficultyLevel::Easy,
+            DifficultyLevel::Hard => DifficultyLevel::Normal,
+            DifficultyLevel::Nightmare => DifficultyLevel::Hard,
+        }
+    }
+}
@@ -1,12 +1,18 @@
 use std::path::PathBuf;
 
-use bevy::{platform::collections::HashMap, prelude::*};
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::{
-    maps::{MapObject, TileType},
-    utils::{direction::Direction, grid::Grid},
+    maps::{LevelProblem, MapObject, ObjectProperties, TileType},
+    utils::{
+        direction::{CardinalDirection, Direction},
+        grid::Grid,
+    },
 };
 
 #[derive(Debug, Error)]
@@ -20,7 +26,7 @@ pub enum LevelError {
 }
 
 /// A Level represents a game level with its tiles, player start position, and objects.
-#[derive(Serialize, Deserialize, Asset, TypePath)]
+#[derive(Serialize, Deserialize, Asset, TypePath, Clone)]
 pub struct Level {
     /// The unique identifier for the level. Used in MapObject::Exit.
     pub id: String,
@@ -30,6 +36,12 @@ pub struct Level {
     pub player_start: ((i32, i32), Direction),
     /// Map objects placed in the level, keyed by their (x, y) position.
     pub objects: HashMap<(i32, i32), MapObject>,
+    /// The time in seconds a good player should finish the level in, shown on the intermission screen.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub par_time: Option<f32>,
+    /// The id of the sound looped as music while the level is played, an episode can replace it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub music: Option<String>,
 }
 
 impl Default for Level {
@@ -40,8 +52,28 @@ impl Default for Level {
 
 // Constructor + Serialization methods
 impl Level {
+    const DIRECTORY: &'static str = "./assets/levels";
+    /// The level the editor saves to when playtesting, hidden from [`Self::list_ids`]
+    pub const PLAYTEST_ID: &'static str = "_playtest";
+
     fn get_path_for_id(id: &str) -> PathBuf {
-        format!("./assets/levels/{}.ron", id).into()
+        format!("{}/{}.ron", Self::DIRECTORY, id).into()
+    }
+
+    /// Lists the ids of all levels saved in the levels directory, sorted alphabetically.
+    pub fn list_ids() -> Result<Vec<String>, LevelError> {
+        let mut ids = Vec::new();
+        for entry in std::fs::read_dir(Self::DIRECTORY)? {
+            let path = entry?.path();
+            if path.extension().and_then(|s| s.to_str()) == Some("ron")
+                && let Some(id) = path.file_stem().and_then(|s| s.to_str())
+                && id != Self::PLAYTEST_ID
+            {
+                ids.push(id.to_string());
+            }
+        }
+        ids.sort();
+        Ok(ids)
     }
 
     pub fn load(id: impl ToString) -> Self {
@@ -70,6 +102,8 @@ impl Level {
             tiles: Grid::new_default(size),
             player_start: ((0, 0), Direction::NORTH),
             objects: HashMap::new(),
+            par_time: None,
+            music: None,
         }
     }
 }
@@ -119,18 +153,170 @@ impl Level {
     }
 
     /// Adds an enemy at the given position with the specified enemy ID.
-    pub fn add_enemy(&mut self, position: (i32, i32), enemy_id: impl ToString) {
+    pub fn add_enemy(&mut self, position: (i32, i32), enemy_id: impl ToString, properties: ObjectProperties) {
         // All objects go on Floor tiles
         if self.set_tile(position, TileType::Floor) {
-            self.objects.insert(position, MapObject::Enemy(enemy_id.to_string()));
+            self.objects.insert(position, MapObject::Enemy(enemy_id.to_string(), properties));
         }
     }
 
     /// Adds an item at the given position with the specified item ID.
-    pub fn add_item(&mut self, position: (i32, i32), item_id: impl ToString) {
+    pub fn add_item(&mut self, position: (i32, i32), item_id: impl ToString, properties: ObjectProperties) {
+        // All objects go on Floor tiles
+        if self.set_tile(position, TileType::Floor) {
+            self.objects.insert(position, MapObject::Item(item_id.to_string(), properties));
+        }
+    }
+
+    /// Adds a decoration at the given position with the specified environment object ID.
+    pub fn add_env(&mut self, position: (i32, i32), env_id: impl ToString, properties: ObjectProperties) {
         // All objects go on Floor tiles
         if self.set_tile(position, TileType::Floor) {
-            self.objects.insert(position, MapObject::Item(item_id.to_string()));
+            self.objects.insert(position, MapObject::Env(env_id.to_string(), properties));
         }
     }
+
+    /// Marks the given position as a secret area.
+    pub fn add_secret(&mut self, position: (i32, i32)) {
+        // All objects go on Floor tiles
+        if self.set_tile(position, TileType::Floor) {
+            self.objects.insert(position, MapObject::Secret);
+        }
+    }
+
+    /// Places a marker turning patrolling enemies towards the given direction.
+    pub fn add_patrol_turn(&mut self, position: (i32, i32), direction: Direction) {
+        // All objects go on Floor tiles
+        if self.set_tile(position, TileType::Floor) {
+            self.objects.insert(position, MapObject::PatrolTurn(direction));
+        }
+    }
+}
+
+// Validation
+impl Level {
+    /// Finds mistakes that would only show up when playing the level, sorted by position.
+    ///
+    /// `is_known` tells whether an enemy, item, decoration or exit refers to something that exists.
+    pub fn validate(&self, is_known: impl Fn(&MapObject) -> bool) -> Vec<LevelProblem> {
+        let mut problems = Vec::new();
+
+        let (start, direction) = self.player_start;
+        match self.tiles.get(start) {
+            None => problems.push(LevelProblem::PlayerStartOutOfBounds { position: start }),
+            Some(TileType::Floor) => {},
+            Some(tile) => problems.push(LevelProblem::PlayerStartNotOnFloor {
+                position: start,
+                tile: *tile,
+            }),
+        }
+        if !CardinalDirection::iter().any(|cardinal| cardinal == direction) {
+            problems.push(LevelProblem::PlayerStartNotCardinal { position: start });
+        }
+
+        let mut objects: Vec<_> = self.objects.iter().collect();
+        objects.sort_by_key(|(position, _)| **position);
+        for (position, object) in objects {
+            let position = *position;
+            match self.tiles.get(position) {
+                None => problems.push(LevelProblem::ObjectOutOfBounds { position }),
+                Some(TileType::Floor) => {},
+                Some(tile) => problems.push(LevelProblem::ObjectNotOnFloor {
+                    position,
+                    tile: *tile,
+                }),
+            }
+            if !is_known(object) {
+                let id = match object {
+                    MapObject::Exit(id)
+                    | MapObject::Enemy(id, _)
+                    | MapObject::Item(id, _)
+                    | MapObject::Env(id, _) => id.clone(),
+                    MapObject::Secret => "Secret".to_string(),
+                    MapObject::PatrolTurn(_) => "PatrolTurn".to_string(),
+                };
+                problems.push(LevelProblem::UnknownObject { position, id });
+            }
+        }
+
+        if !self.objects.values().any(|object| matches!(object, MapObject::Exit(_))) {
+            problems.push(LevelProblem::NoExit);
+        }
+
+        // Every walkable cell not connected to the player start, grouped into areas
+        let walkable = |tile: &TileType| *tile != TileType::Wall;
+        let mut visited = HashSet::new();
+        self.tiles.flood(start, &mut visited, walkable);
+        for (position, _) in self.tiles.enumerate() {
+            let cells = self.tiles.flood(position, &mut visited, walkable);
+            if !cells.is_empty() {
+                problems.push(LevelProblem::UnreachableArea { cells });
+            }
+        }
+
+        problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Level;
+    use crate::{
+        maps::{LevelProblem, MapObject, ObjectProperties, TileType},
+        utils::direction::Direction,
+    };
+
+    #[test]
+    fn test_level_validate_unreachable_area() {
+        // Two rooms split by a wall column at x = 2
+        let mut level = Level::new("test", (5, 3));
+        for position in [(0, 1), (1, 1), (3, 1), (4, 1)] {
+            level.set_tile(position, TileType::Floor);
+        }
+        level.set_player_start((0, 1), Direction::EAST);
+        level.add_exit((1, 1), "level_2");
+        assert_eq!(
+            level.validate(|_| true),
+            vec![LevelProblem::UnreachableArea {
+                cells: vec![(3, 1), (4, 1)]
+            }]
+        );
+
+        level.set_tile((2, 1), TileType::Door(Default::default()));
+        assert_eq!(level.validate(|_| true), Vec::new());
+    }
+
+    #[test]
+    fn test_level_validate_objects_and_start() {
+        let mut level = Level::new("test", (5, 3));
+        for position in [(0, 1), (1, 1), (3, 1), (4, 1)] {
+            level.set_tile(position, TileType::Floor);
+        }
+        level.set_player_start((0, 1), Direction::NORTH_EAST);
+        level.add_exit((1, 1), "level_2");
+        level.set_tile((1, 1), TileType::Wall);
+        level.add_item((4, 1), "medkit", ObjectProperties::default());
+
+        let problems = level.validate(|object| !matches!(object, MapObject::Item(..)));
+        assert_eq!(
+            problems,
+            vec![
+                LevelProblem::PlayerStartNotCardinal { position: (0, 1) },
+                LevelProblem::ObjectNotOnFloor {
+                    position: (1, 1),
+                    tile: TileType::Wall
+                },
+                LevelProblem::UnknownObject {
+                    position: (4, 1),
+                    id: "medkit".to_string()
+                },
+                LevelProblem::UnreachableArea {
+                    cells: vec![(3, 1), (4, 1)]
+                },
+            ]
+        );
+
+        level.objects.clear();
+        assert!(level.validate(|_| true).contains(&LevelProblem::NoExit));
+    }
 }
@@ -0,0 +1,62 @@
+use thiserror::Error;
+
+use crate::maps::TileType;
+
+/// How bad a [`LevelProblem`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ProblemSeverity {
+    /// The level works but probably not as intended
+    Warning,
+    /// The level will not play correctly
+    Error,
+}
+
+/// A mistake found when checking a level, see [`Level::validate`](crate::maps::Level::validate)
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum LevelProblem {
+    #[error("The player start {position:?} is outside the map")]
+    PlayerStartOutOfBounds { position: (i32, i32) },
+    #[error("The player start {position:?} is on a {tile:?} tile")]
+    PlayerStartNotOnFloor {
+        position: (i32, i32),
+        tile: TileType,
+    },
+    #[error("The player start {position:?} faces a diagonal direction and will face north")]
+    PlayerStartNotCardinal { position: (i32, i32) },
+    #[error("The object at {position:?} is outside the map")]
+    ObjectOutOfBounds { position: (i32, i32) },
+    #[error("The object at {position:?} is on a {tile:?} tile")]
+    ObjectNotOnFloor {
+        position: (i32, i32),
+        tile: TileType,
+    },
+    #[error("The object at {position:?} refers to unknown '{id}'")]
+    UnknownObject { position: (i32, i32), id: String },
+    #[error("The level has no exit")]
+    NoExit,
+    #[error("{} cells starting at {:?} can't be reached from the player start", cells.len(), cells.first())]
+    UnreachableArea { cells: Vec<(i32, i32)> },
+}
+
+impl LevelProblem {
+    pub fn severity(&self) -> ProblemSeverity {
+        match self {
+            LevelProblem::NoExit | LevelProblem::UnreachableArea { .. } => ProblemSeverity::Warning,
+            _ => ProblemSeverity::Error,
+        }
+    }
+
+    /// The cells the problem is about, empty for problems of the whole level
+    pub fn cells(&self) -> &[(i32, i32)] {
+        match self {
+            LevelProblem::PlayerStartOutOfBounds { position }
+            | LevelProblem::PlayerStartNotOnFloor { position, .. }
+            | LevelProblem::PlayerStartNotCardinal { position }
+            | LevelProblem::ObjectOutOfBounds { position }
+            | LevelProblem::ObjectNotOnFloor { position, .. }
+            | LevelProblem::UnknownObject { position, .. } => std::slice::from_ref(position),
+            LevelProblem::UnreachableArea { cells } => cells,
+            LevelProblem::NoExit => &[],
+        }
+    }
+}
@@ -1,15 +1,35 @@
+use std::fmt;
+
 use bevy::prelude::*;
-use serde::{Deserialize, Serialize};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, EnumAccess, SeqAccess, VariantAccess, Visitor},
+    ser::SerializeTupleVariant,
+};
+
+use crate::{
+    maps::{DifficultyLevel, ObjectFlag, ObjectProperties},
+    utils::direction::Direction,
+};
 
 /// Represents any object that can be placed on the map.
-#[derive(Serialize, Deserialize, Reflect, Debug, Clone)]
+///
+/// Objects with [`ObjectProperties`] are written as `Enemy("slime")` while their properties are
+/// default and as `Enemy("slime", (min_difficulty: Hard))` otherwise, so older levels still load.
+#[derive(Reflect, Debug, Clone, PartialEq)]
 pub enum MapObject {
     /// An exit leading to another level.
     Exit(String),
     /// An enemy with a specific ID.
-    Enemy(String),
+    Enemy(String, ObjectProperties),
     /// An item with a specific ID.
-    Item(String),
+    Item(String, ObjectProperties),
+    /// A secret area, counted as found when the player steps on it.
+    Secret,
+    /// A placeable decoration from the [`EnvObjLibrary`](crate::prelude::EnvObjLibrary).
+    Env(String, ObjectProperties),
+    /// Patrolling enemies stepping on this cell turn to face the direction.
+    PatrolTurn(Direction),
 }
 
 impl MapObject {
@@ -17,8 +37,232 @@ impl MapObject {
     pub fn color(&self) -> Color {
         match self {
             MapObject::Exit(_) => Color::srgb(0.0, 0.0, 1.0),
-            MapObject::Enemy(_) => Color::srgb(1.0, 0.0, 0.0),
-            MapObject::Item(_) => Color::srgb(0.0, 1.0, 0.0),
+            MapObject::Enemy(..) => Color::srgb(1.0, 0.0, 0.0),
+            MapObject::Item(..) => Color::srgb(0.0, 1.0, 0.0),
+            MapObject::Secret => Color::srgb(1.0, 0.0, 1.0),
+            MapObject::Env(..) => Color::srgb(0.8, 0.6, 0.2),
+            MapObject::PatrolTurn(_) => Color::srgb(0.0, 1.0, 1.0),
+        }
+    }
+
+    /// Returns the object's properties, if this kind of object has any.
+    pub fn properties(&self) -> Option<&ObjectProperties> {
+        match self {
+            MapObject::Exit(_) | MapObject::Secret | MapObject::PatrolTurn(_) => None,
+            MapObject::Enemy(_, properties)
+            | MapObject::Item(_, properties)
+            | MapObject::Env(_, properties) => Some(properties),
+        }
+    }
+
+    /// Returns the object's properties mutably, if this kind of object has any.
+    pub fn properties_mut(&mut self) -> Option<&mut ObjectProperties> {
+        match self {
+            MapObject::Exit(_) | MapObject::Secret | MapObject::PatrolTurn(_) => None,
+            MapObject::Enemy(_, properties)
+            | MapObject::Item(_, properties)
+            | MapObject::Env(_, properties) => Some(properties),
+        }
+    }
+
+    /// The flags that mean something for this kind of object.
+    pub fn available_flags(&self) -> &'static [ObjectFlag] {
+        match self {
+            MapObject::Enemy(..) => &[ObjectFlag::Ambush, ObjectFlag::Patrol],
+            MapObject::Env(..) => &[ObjectFlag::NonBlocking],
+            MapObject::Exit(_) | MapObject::Item(..) | MapObject::Secret | MapObject::PatrolTurn(_) => &[],
+        }
+    }
+
+    /// Whether the object takes part in a game played on the given difficulty.
+    pub fn spawns_on(&self, difficulty: DifficultyLevel) -> bool {
+        self.properties().is_none_or(|properties| properties.spawns_on(difficulty))
+    }
+}
+
+// Serialization
+impl MapObject {
+    const NAME: &'static str = "MapObject";
+    const VARIANTS: &'static [&'static str] = &["Exit", "Enemy", "Item", "Secret", "Env", "PatrolTurn"];
+
+    fn serialize_with_properties<S: Serializer>(
+        serializer: S,
+        index: u32,
+        id: &String,
+        properties: &ObjectProperties,
+    ) -> Result<S::Ok, S::Error> {
+        let variant = Self::VARIANTS[index as usize];
+        if properties.is_default() {
+            serializer.serialize_newtype_variant(Self::NAME, index, variant, id)
+        } else {
+            let mut state = serializer.serialize_tuple_variant(Self::NAME, index, variant, 2)?;
+            state.serialize_field(id)?;
+            state.serialize_field(properties)?;
+            state.end()
         }
     }
 }
+
+impl Serialize for MapObject {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            MapObject::Exit(level_id) => {
+                serializer.serialize_newtype_variant(Self::NAME, 0, "Exit", level_id)
+            },
+            MapObject::Enemy(id, properties) => {
+                Self::serialize_with_properties(serializer, 1, id, properties)
+            },
+            MapObject::Item(id, properties) => Self::serialize_with_properties(serializer, 2, id, properties),
+            MapObject::Secret => serializer.serialize_unit_variant(Self::NAME, 3, "Secret"),
+            MapObject::Env(id, properties) => Self::serialize_with_properties(serializer, 4, id, properties),
+            MapObject::PatrolTurn(direction) => {
+                serializer.serialize_newtype_variant(Self::NAME, 5, "PatrolTurn", direction)
+            },
+        }
+    }
+}
+
+#[derive(Deserialize)]
+enum MapObjectTag {
+    Exit,
+    Enemy,
+    Item,
+    Secret,
+    Env,
+    PatrolTurn,
+}
+
+/// Reads `(id)` or `(id, properties)`
+struct IdWithPropertiesVisitor;
+
+impl<'de> Visitor<'de> for IdWithPropertiesVisitor {
+    type Value = (String, ObjectProperties);
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an id optionally followed by object properties")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let id = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let properties = seq.next_element()?.unwrap_or_default();
+        Ok((id, properties))
+    }
+}
+
+struct MapObjectVisitor;
+
+impl<'de> Visitor<'de> for MapObjectVisitor {
+    type Value = MapObject;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a MapObject")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (tag, variant) = data.variant()?;
+        Ok(match tag {
+            MapObjectTag::Exit => MapObject::Exit(variant.newtype_variant()?),
+            MapObjectTag::Enemy => {
+                let (id, properties) = variant.tuple_variant(2, IdWithPropertiesVisitor)?;
+                MapObject::Enemy(id, properties)
+            },
+            MapObjectTag::Item => {
+                let (id, properties) = variant.tuple_variant(2, IdWithPropertiesVisitor)?;
+                MapObject::Item(id, properties)
+            },
+            MapObjectTag::Secret => {
+                variant.unit_variant()?;
+                MapObject::Secret
+            },
+            MapObjectTag::Env => {
+                let (id, properties) = variant.tuple_variant(2, IdWithPropertiesVisitor)?;
+                MapObject::Env(id, properties)
+            },
+            MapObjectTag::PatrolTurn => MapObject::PatrolTurn(variant.newtype_variant()?),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for MapObject {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_enum(Self::NAME, Self::VARIANTS, MapObjectVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MapObject;
+    use crate::{
+        maps::{DifficultyLevel, ObjectFlag, ObjectProperties},
+        utils::direction::Direction,
+    };
+
+    #[test]
+    fn test_map_object_legacy_format() {
+        let object: MapObject = ron::de::from_str(r#"Enemy("slime")"#).unwrap();
+        assert_eq!(
+            object,
+            MapObject::Enemy("slime".to_string(), ObjectProperties::default())
+        );
+
+        let object: MapObject = ron::de::from_str(r#"Exit("level_2")"#).unwrap();
+        assert_eq!(object, MapObject::Exit("level_2".to_string()));
+
+        let object: MapObject = ron::de::from_str("Secret").unwrap();
+        assert_eq!(object, MapObject::Secret);
+
+        let object: MapObject = ron::de::from_str(r#"Env("barrel")"#).unwrap();
+        assert_eq!(
+            object,
+            MapObject::Env("barrel".to_string(), ObjectProperties::default())
+        );
+    }
+
+    #[test]
+    fn test_map_object_round_trip() {
+        let plain = MapObject::Item("medkit".to_string(), ObjectProperties::default());
+        let data = ron::ser::to_string(&plain).unwrap();
+        assert_eq!(data, r#"Item("medkit")"#);
+        assert_eq!(ron::de::from_str::<MapObject>(&data).unwrap(), plain);
+
+        let hard = MapObject::Enemy(
+            "alien".to_string(),
+            ObjectProperties {
+                min_difficulty: DifficultyLevel::Hard,
+                ..Default::default()
+            },
+        );
+        let data = ron::ser::to_string(&hard).unwrap();
+        assert_eq!(ron::de::from_str::<MapObject>(&data).unwrap(), hard);
+        assert!(!hard.spawns_on(DifficultyLevel::Normal));
+        assert!(hard.spawns_on(DifficultyLevel::Nightmare));
+
+        let turn = MapObject::PatrolTurn(Direction::WEST);
+        let data = ron::ser::to_string(&turn).unwrap();
+        assert_eq!(ron::de::from_str::<MapObject>(&data).unwrap(), turn);
+        assert!(turn.spawns_on(DifficultyLevel::Easy));
+    }
+
+    #[test]
+    fn test_map_object_properties_format() {
+        // Only the settings that differ from their default are written
+        let mut properties = ObjectProperties {
+            facing: Some(Direction::EAST),
+            ..Default::default()
+        };
+        properties.set_flag(ObjectFlag::Ambush, true);
+        properties.values.insert("tag".to_string(), "door_guard".to_string());
+        let object = MapObject::Enemy("slime".to_string(), properties);
+        let data = ron::ser::to_string(&object).unwrap();
+        assert!(!data.contains("min_difficulty"));
+        assert_eq!(ron::de::from_str::<MapObject>(&data).unwrap(), object);
+
+        // Levels written before facing, flags and values existed still load
+        let object: MapObject = ron::de::from_str(r#"Item("medkit", (min_difficulty: Hard))"#).unwrap();
+        let properties = object.properties().unwrap();
+        assert_eq!(properties.min_difficulty, DifficultyLevel::Hard);
+        assert_eq!(properties.facing, None);
+        assert!(!properties.has_flag(ObjectFlag::Ambush));
+        assert!(properties.values.is_empty());
+    }
+}
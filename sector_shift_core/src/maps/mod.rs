@@ -1,11 +1,26 @@
+mod difficulty_level;
+pub use self::difficulty_level::*;
+
 mod door_axis;
 pub use self::door_axis::*;
 
 mod level;
 pub use self::level::*;
 
+mod level_problem;
+pub use self::level_problem::*;
+
 mod map_object;
 pub use self::map_object::*;
 
+mod object_flag;
+pub use self::object_flag::*;
+
+mod object_properties;
+pub use self::object_properties::*;
+
+mod patrol_route;
+pub use self::patrol_route::*;
+
 mod tile_type;
 pub use self::tile_type::*;
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// An on/off switch set on a placed object in the editor.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ObjectFlag {
+    /// The enemy ignores noise and only reacts once it sees the player.
+    Ambush,
+    /// The decoration doesn't get a collider, so actors walk through it.
+    NonBlocking,
+    /// The enemy walks its patrol route instead of standing guard, see [`PatrolRoute`](crate::maps::PatrolRoute).
+    Patrol,
+}
+
+impl ObjectFlag {
+    /// All flags, in the order they are listed in the editor.
+    pub const ALL: [Self; 3] = [Self::Ambush, Self::NonBlocking, Self::Patrol];
+
+    /// Returns a human readable name for the editor.
+    pub fn name(self) -> &'static str {
+        match self {
+            ObjectFlag::Ambush => "Ambush",
+            ObjectFlag::NonBlocking => "Non Blocking",
+            ObjectFlag::Patrol => "Patrol",
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    maps::{DifficultyLevel, ObjectFlag},
+    utils::direction::Direction,
+};
+
+/// Optional per-object settings stored alongside a [`MapObject`](crate::maps::MapObject).
+/// Left out of the level file entirely when everything is at its default, and each setting is
+/// only written when it differs from its default.
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ObjectProperties {
+    /// The object is only spawned on this difficulty and harder ones.
+    #[serde(skip_serializing_if = "is_default")]
+    pub min_difficulty: DifficultyLevel,
+    /// The way the object faces when spawned, `None` keeps its default orientation.
+    #[serde(skip_serializing_if = "is_default")]
+    pub facing: Option<Direction>,
+    /// Switches changing how the object behaves.
+    #[serde(skip_serializing_if = "is_default")]
+    pub flags: BTreeSet<ObjectFlag>,
+    /// Free-form key/value pairs, e.g. to tag objects for scripts.
+    #[serde(skip_serializing_if = "is_default")]
+    pub values: BTreeMap<String, String>,
+}
+
+impl ObjectProperties {
+    /// Whether the object takes part in a game played on the given difficulty.
+    pub fn spawns_on(&self, difficulty: DifficultyLevel) -> bool {
+        difficulty >= self.min_difficulty
+    }
+
+    /// Whether all properties are at their default values.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether the given flag is set.
+    pub fn has_flag(&self, flag: ObjectFlag) -> bool {
+        self.flags.contains(&flag)
+    }
+
+    /// Sets or clears a flag.
+    pub fn set_flag(&mut self, flag: ObjectFlag, enabled: bool) {
+        if enabled {
+            self.flags.insert(flag);
+        } else {
+            self.flags.remove(&flag);
+        }
+    }
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
@@ -0,0 +1,191 @@
+use bevy::platform::collections::HashMap;
+
+use crate::{
+    maps::{Level, MapObject, ObjectFlag, TileType},
+    utils::direction::Direction,
+};
+
+/// The cells a patrolling enemy walks through, turning on every [`MapObject::PatrolTurn`] it steps
+/// on. Walls and blocking decorations end the route, coming back to a cell while facing the same
+/// way closes it into a loop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatrolRoute {
+    /// The cells in walking order, starting with the enemy's own
+    pub cells: Vec<(i32, i32)>,
+    /// The index of the cell walked to after the last one, `None` when the route ends at a dead end.
+    /// Can be past the start when the enemy has to walk a bit before joining its loop
+    pub loop_start: Option<usize>,
+}
+
+impl PatrolRoute {
+    /// Follows the route of an enemy placed on `start`, walking towards `facing` until the first
+    /// turn marker
+    pub fn new(level: &Level, start: (i32, i32), facing: Direction) -> Self {
+        let mut cells = vec![start];
+        let mut direction = facing;
+        let mut visited = HashMap::from([((start, direction), 0)]);
+
+        let mut position = start;
+        loop {
+            let (dx, dy) = direction.coord();
+            let next = (position.0 + dx, position.1 + dy);
+            if next == position || !Self::is_walkable(level, next) {
+                return Self {
+                    cells,
+                    loop_start: None,
+                };
+            }
+            if let Some(turn) = Self::turn_at(level, next) {
+                direction = turn;
+            }
+            if let Some(&index) = visited.get(&(next, direction)) {
+                return Self {
+                    cells,
+                    loop_start: Some(index),
+                };
+            }
+            visited.insert((next, direction), cells.len());
+            cells.push(next);
+            position = next;
+        }
+    }
+
+    /// The route of the enemy placed on `position`, `None` unless it is flagged with
+    /// [`ObjectFlag::Patrol`]. Enemies without a facing start walking south, the way they face when
+    /// spawned
+    pub fn for_enemy(level: &Level, position: (i32, i32)) -> Option<Self> {
+        let Some(MapObject::Enemy(_, properties)) = level.objects.get(&position) else {
+            return None;
+        };
+        if !properties.has_flag(ObjectFlag::Patrol) {
+            return None;
+        }
+        Some(Self::new(
+            level,
+            position,
+            properties.facing.unwrap_or(Direction::SOUTH),
+        ))
+    }
+
+    /// The index of the cell after `index`, `None` at the end of a route that isn't a loop
+    pub fn next(&self, index: usize) -> Option<usize> {
+        if index + 1 < self.cells.len() { Some(index + 1) } else { self.loop_start }
+    }
+
+    fn turn_at(level: &Level, position: (i32, i32)) -> Option<Direction> {
+        match level.objects.get(&position) {
+            Some(MapObject::PatrolTurn(direction)) => Some(*direction),
+            _ => None,
+        }
+    }
+
+    /// Floors and doors, unless a blocking decoration stands on them
+    fn is_walkable(level: &Level, position: (i32, i32)) -> bool {
+        let blocked = match level.objects.get(&position) {
+            Some(MapObject::Env(_, properties)) => !properties.has_flag(ObjectFlag::NonBlocking),
+            _ => false,
+        };
+        !blocked && level.tiles.get(position).is_some_and(|tile| *tile != TileType::Wall)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PatrolRoute;
+    use crate::{
+        maps::{Level, ObjectFlag, ObjectProperties, TileType},
+        utils::direction::Direction,
+    };
+
+    #[test]
+    fn test_patrol_route_loop() {
+        let mut level = Level::new("test", (7, 6));
+        for x in 1..=5 {
+            for y in 1..=4 {
+                level.set_tile((x, y), TileType::Floor);
+            }
+        }
+        // Counter clockwise around the square from (1, 1) to (4, 4)
+        for (position, direction) in [
+            ((1, 1), Direction::EAST),
+            ((4, 1), Direction::NORTH),
+            ((4, 4), Direction::WEST),
+            ((1, 4), Direction::SOUTH),
+        ] {
+            level.add_patrol_turn(position, direction);
+        }
+        let route = PatrolRoute::new(&level, (2, 1), Direction::EAST);
+        assert_eq!(
+            route.cells,
+            vec![
+                (2, 1),
+                (3, 1),
+                (4, 1),
+                (4, 2),
+                (4, 3),
+                (4, 4),
+                (3, 4),
+                (2, 4),
+                (1, 4),
+                (1, 3),
+                (1, 2),
+                (1, 1)
+            ]
+        );
+        assert_eq!(route.loop_start, Some(0));
+        assert_eq!(route.next(11), Some(0));
+
+        // Only enemies flagged to patrol have a route, facing the way they were placed
+        let mut properties = ObjectProperties {
+            facing: Some(Direction::EAST),
+            ..Default::default()
+        };
+        level.add_enemy((2, 1), "slime", properties.clone());
+        assert_eq!(PatrolRoute::for_enemy(&level, (2, 1)), None);
+        properties.set_flag(ObjectFlag::Patrol, true);
+        level.add_enemy((2, 1), "slime", properties);
+        assert_eq!(PatrolRoute::for_enemy(&level, (2, 1)), Some(route));
+
+        // Walking into the loop from outside of it
+        let route = PatrolRoute::new(&level, (5, 1), Direction::WEST);
+        assert_eq!(route.cells.len(), 13);
+        assert_eq!(route.cells[1], (4, 1));
+        assert_eq!(route.loop_start, Some(1));
+        assert_eq!(route.next(12), Some(1));
+    }
+
+    #[test]
+    fn test_patrol_route_dead_end() {
+        let mut level = Level::new("test", (7, 6));
+        for x in 1..=5 {
+            for y in 1..=4 {
+                level.set_tile((x, y), TileType::Floor);
+            }
+        }
+        for (position, direction) in [
+            ((1, 1), Direction::EAST),
+            ((4, 1), Direction::NORTH),
+            ((4, 4), Direction::WEST),
+            ((1, 4), Direction::SOUTH),
+        ] {
+            level.add_patrol_turn(position, direction);
+        }
+        let route = PatrolRoute::new(&level, (2, 2), Direction::EAST);
+        assert_eq!(route.cells, vec![(2, 2), (3, 2), (4, 2), (5, 2)]);
+        assert_eq!(route.loop_start, None);
+        assert_eq!(route.next(2), Some(3));
+        assert_eq!(route.next(3), None);
+
+        // Blocking decorations end the route like walls
+        level.add_env((4, 2), "barrel", ObjectProperties::default());
+        let route = PatrolRoute::new(&level, (2, 2), Direction::EAST);
+        assert_eq!(route.cells, vec![(2, 2), (3, 2)]);
+
+        // Enemies placed without a facing walk south
+        let mut properties = ObjectProperties::default();
+        properties.set_flag(ObjectFlag::Patrol, true);
+        level.add_enemy((2, 3), "slime", properties);
+        let route = PatrolRoute::for_enemy(&level, (2, 3)).unwrap();
+        assert_eq!(route.cells, vec![(2, 3), (2, 2), (2, 1)]);
+    }
+}
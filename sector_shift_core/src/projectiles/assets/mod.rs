@@ -0,0 +1,2 @@
+mod projectile_asset;
+pub use self::projectile_asset::*;
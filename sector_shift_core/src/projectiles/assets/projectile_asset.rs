@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    projectiles::data::{ProjectileImpact, Splash},
+    utils::{billboard::data::SpriteSheet, deserialize_optional_path, serialize_optional_path},
+};
+
+/// This gets loaded by the AssetManager
+/// Represents a travelling projectile fired by weapons and enemies, like a rocket or slime spit
+#[derive(Serialize, Deserialize, Asset, TypePath)]
+pub struct ProjectileAsset {
+    /// This must be unique as it is used as the key when looking up from the [`ProjectileLibrary`]
+    /// resource
+    pub id: String,
+    /// Speed when fired, in units per second
+    pub speed: f32,
+    /// Downward acceleration in units per second squared, projectiles without it fly straight
+    #[serde(default)]
+    pub gravity: f32,
+    /// Health taken from an actor that is hit directly
+    pub damage: i32,
+    /// Damage dealt around the point of impact
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub splash: Option<Splash>,
+    /// Radius of the sphere that collides with the level and actors
+    #[serde(default = "f_radius")]
+    pub radius: f32,
+    /// Seconds before it disappears without hitting anything
+    #[serde(default = "f_lifetime")]
+    pub lifetime: f32,
+    /// Height of the sprite in units, its width follows the sprite's aspect ratio
+    #[serde(default = "f_size")]
+    pub size: f32,
+    /// This is the path to the gltf model to be loaded, left out for sprite projectiles
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_path",
+        serialize_with = "serialize_optional_path",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub gltf: Option<String>,
+    /// This is the path to the image of a projectile drawn as a billboard instead of a model
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_path",
+        serialize_with = "serialize_optional_path",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sprite: Option<String>,
+    /// Plays the sprite as an animation, the image then holds all of its frames
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animation: Option<SpriteSheet>,
+    /// Shown where the projectile hits something
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impact: Option<ProjectileImpact>,
+    #[serde(skip)]
+    #[dependency]
+    /// Asset dependency, the gltf has to be loaded before we can convert [`ProjectileAsset`] to
+    /// [`ProjectileDefinition`]
+    pub gltf_handle: Option<Handle<Gltf>>,
+    #[serde(skip)]
+    #[dependency]
+    /// Asset dependency, the size of the sprite is needed to spawn it
+    pub sprite_handle: Option<Handle<Image>>,
+}
+
+impl ProjectileAsset {
+    /// The file extension for these assets
+    pub const EXTENSION: &'static str = "projectile";
+}
+
+fn f_radius() -> f32 {
+    0.2
+}
+
+fn f_lifetime() -> f32 {
+    10.0
+}
+
+fn f_size() -> f32 {
+    0.5
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectileAsset;
+
+    #[test]
+    fn test_projectile_asset_format() {
+        let asset: ProjectileAsset = ron::de::from_str(
+            r#"(
+                id: "rocket",
+                speed: 20.0,
+                damage: 20,
+                splash: Some((damage: 120, radius: 6.0)),
+                gltf: "models/projectiles/Rocket.glb",
+                impact: Some((sprite: "sprites/effects/explosion.png")),
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(asset.gravity, 0.0);
+        assert_eq!(asset.lifetime, 10.0);
+        assert_eq!(asset.gltf.as_deref(), Some("models/projectiles/Rocket.glb"));
+        assert_eq!(asset.sprite, None);
+        assert_eq!(asset.splash.unwrap().radius, 6.0);
+        assert_eq!(asset.impact.unwrap().size, 1.0);
+    }
+}
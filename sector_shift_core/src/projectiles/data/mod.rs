@@ -0,0 +1,8 @@
+mod projectile_appearance;
+pub use self::projectile_appearance::*;
+
+mod projectile_impact;
+pub use self::projectile_impact::*;
+
+mod splash;
+pub use self::splash::*;
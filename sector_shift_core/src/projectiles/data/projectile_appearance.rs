@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use crate::utils::billboard::data::SpriteSheet;
+
+/// How a loaded projectile is drawn
+pub enum ProjectileAppearance {
+    /// The default scene of a glTF model, turned to face the way it flies
+    Model {
+        gltf: Handle<Gltf>,
+        scene: Handle<Scene>,
+    },
+    /// A billboard, animated when the image is a sprite sheet
+    Sprite {
+        image: Handle<Image>,
+        /// The size of the image, or of one frame of the animation, in pixels
+        size: UVec2,
+        animation: Option<SpriteSheet>,
+    },
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::billboard::data::SpriteSheet;
+
+/// A sprite shown for a moment where a projectile hits, like an explosion or a splat
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+pub struct ProjectileImpact {
+    /// This is the path to the image to be loaded
+    pub sprite: String,
+    /// Plays the sprite as an animation, the image then holds all of its frames
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animation: Option<SpriteSheet>,
+    /// Height of the sprite in units
+    #[serde(default = "f_size")]
+    pub size: f32,
+}
+
+impl ProjectileImpact {
+    /// How long a still sprite is shown
+    const STILL_DURATION: f32 = 0.25;
+
+    /// Seconds the impact is shown, a single play of its animation
+    pub fn duration(&self) -> f32 {
+        match &self.animation {
+            Some(animation) if animation.fps > 0.0 => animation.duration(),
+            _ => Self::STILL_DURATION,
+        }
+    }
+}
+
+fn f_size() -> f32 {
+    1.0
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Damage dealt to every actor around the point of impact, falling off with the distance
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct Splash {
+    /// Health taken at the point of impact
+    pub damage: i32,
+    /// Distance at which the damage has fallen off to nothing, in units
+    pub radius: f32,
+}
+
+impl Splash {
+    /// The damage taken at `distance` from the point of impact, falling off linearly
+    pub fn damage_at(&self, distance: f32) -> i32 {
+        if self.radius <= 0.0 || distance >= self.radius {
+            return 0;
+        }
+        (self.damage as f32 * (1.0 - distance.max(0.0) / self.radius)).round() as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Splash;
+
+    #[test]
+    fn test_splash_falloff() {
+        let splash = Splash {
+            damage: 100,
+            radius: 5.0,
+        };
+        assert_eq!(splash.damage_at(0.0), 100);
+        assert_eq!(splash.damage_at(2.5), 50);
+        assert_eq!(splash.damage_at(4.0), 20);
+        assert_eq!(splash.damage_at(5.0), 0);
+        assert_eq!(splash.damage_at(12.0), 0);
+
+        let none = Splash {
+            damage: 100,
+            radius: 0.0,
+        };
+        assert_eq!(none.damage_at(0.0), 0);
+    }
+}
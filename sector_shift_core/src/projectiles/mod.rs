@@ -0,0 +1,7 @@
+pub mod assets;
+pub mod data;
+pub mod resources;
+pub mod systems;
+
+mod projectiles_plugin;
+pub use self::projectiles_plugin::*;
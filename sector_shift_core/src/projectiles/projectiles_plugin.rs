@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::{
+    projectiles::{assets::ProjectileAsset, resources::ProjectileLibrary, systems::process_projectiles},
+    utils::{generic_asset_loader::GenericAssetLoader, generic_directory_loader::DirectoryLoaderPlugin},
+};
+
+#[derive(Clone)]
+pub struct ProjectilesPlugin<T: States + Copy> {
+    asset_load_state: T,
+}
+
+impl<T: States + Copy> ProjectilesPlugin<T> {
+    pub fn new(asset_load_state: T) -> Self {
+        Self { asset_load_state }
+    }
+}
+
+impl<T: States + Copy> Plugin for ProjectilesPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<ProjectileAsset>();
+        app.register_asset_loader(GenericAssetLoader::<ProjectileAsset>::new(&[
+            ProjectileAsset::EXTENSION,
+        ]));
+
+        app.init_resource::<ProjectileLibrary>();
+        app.add_plugins(DirectoryLoaderPlugin::<ProjectileLibrary, _>::new(
+            self.asset_load_state,
+        ));
+
+        app.add_systems(
+            Update,
+            process_projectiles.run_if(in_state(self.asset_load_state)),
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod projectile_definition;
+pub use self::projectile_definition::*;
+
+mod projectile_library;
+pub use self::projectile_library::*;
@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+use thiserror::Error;
+
+use crate::projectiles::{
+    assets::ProjectileAsset,
+    data::{ProjectileAppearance, ProjectileImpact, Splash},
+};
+
+/// An intermediate step between a projectile asset and a fired projectile
+pub struct ProjectileDefinition {
+    /// The unique ID of the projectile
+    pub id: String,
+    /// Speed when fired, in units per second
+    pub speed: f32,
+    /// Downward acceleration in units per second squared
+    pub gravity: f32,
+    /// Health taken from an actor that is hit directly
+    pub damage: i32,
+    /// Damage dealt around the point of impact
+    pub splash: Option<Splash>,
+    /// Radius of the sphere that collides with the level and actors
+    pub radius: f32,
+    /// Seconds before it disappears without hitting anything
+    pub lifetime: f32,
+    /// Height of the sprite in units
+    pub size: f32,
+    /// The model or sprite of the projectile
+    pub appearance: ProjectileAppearance,
+    /// Shown where the projectile hits something
+    pub impact: Option<ProjectileImpact>,
+    /// The image of the impact
+    pub impact_sprite: Option<Handle<Image>>,
+}
+
+impl ProjectileDefinition {
+    /// Helper to convert from [`ProjectileAsset`] to [`ProjectileDefinition`]
+    pub fn from_asset(
+        asset_server: &AssetServer,
+        gltfs: &Assets<Gltf>,
+        images: &Assets<Image>,
+        asset: &ProjectileAsset,
+    ) -> Result<Self, ProjectileDefinitionLoadError> {
+        let appearance = match (&asset.gltf, &asset.sprite) {
+            (Some(_), None) => {
+                let gltf_handle =
+                    asset.gltf_handle.clone().ok_or(ProjectileDefinitionLoadError::MissingGltfHandle)?;
+                let gltf = gltfs.get(&gltf_handle).ok_or(ProjectileDefinitionLoadError::BrokenGltfHandle)?;
+                ProjectileAppearance::Model {
+                    scene: gltf.scenes.first().ok_or(ProjectileDefinitionLoadError::NoDefaultScene)?.clone(),
+                    gltf: gltf_handle,
+                }
+            },
+            (None, Some(_)) => {
+                let image_handle =
+                    asset.sprite_handle.clone().ok_or(ProjectileDefinitionLoadError::MissingSpriteHandle)?;
+                let image =
+                    images.get(&image_handle).ok_or(ProjectileDefinitionLoadError::BrokenSpriteHandle)?;
+                let size = match &asset.animation {
+                    Some(animation) => UVec2::new(animation.frame_size.0, animation.frame_size.1),
+                    None => image.size(),
+                };
+                ProjectileAppearance::Sprite {
+                    image: image_handle,
+                    size,
+                    animation: asset.animation.clone(),
+                }
+            },
+            (None, None) => return Err(ProjectileDefinitionLoadError::NoAppearance),
+            (Some(_), Some(_)) => return Err(ProjectileDefinitionLoadError::AmbiguousAppearance),
+        };
+        if asset.speed <= 0.0 {
+            return Err(ProjectileDefinitionLoadError::NoSpeed);
+        }
+
+        Ok(Self {
+            id: asset.id.clone(),
+            speed: asset.speed,
+            gravity: asset.gravity,
+            damage: asset.damage,
+            splash: asset.splash,
+            radius: asset.radius,
+            lifetime: asset.lifetime,
+            size: asset.size,
+            appearance,
+            impact: asset.impact.clone(),
+            impact_sprite: asset.impact.as_ref().map(|impact| asset_server.load(&impact.sprite)),
+        })
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ProjectileDefinitionLoadError {
+    #[error("Unexpected internal error: there is no gltf handle")]
+    MissingGltfHandle,
+    #[error("Unexpected internal error: broken gltf handle")]
+    BrokenGltfHandle,
+    #[error("Unexpected internal error: there is no sprite handle")]
+    MissingSpriteHandle,
+    #[error("Unexpected internal error: broken sprite handle")]
+    BrokenSpriteHandle,
+    #[error("Either a gltf model or a sprite is needed to draw the projectile")]
+    NoAppearance,
+    #[error("Only one of a gltf model and a sprite can be given")]
+    AmbiguousAppearance,
+    #[error("The model does not contain any scenes")]
+    NoDefaultScene,
+    #[error("The speed has to be above zero")]
+    NoSpeed,
+}
@@ -0,0 +1,30 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::projectiles::{assets::ProjectileAsset, resources::ProjectileDefinition};
+
+/// A library of projectiles which can be fired
+#[derive(Resource, Default)]
+pub struct ProjectileLibrary {
+    /// A map of loaded projectile definitions sorted by their id
+    pub map: HashMap<String, ProjectileDefinition>,
+    /// A list of projectile assets currently being loaded
+    pub loading: Vec<Handle<ProjectileAsset>>,
+    /// Indicates that all handles in `Self.loading` are fully loaded
+    pub loading_finished: bool,
+}
+
+impl ProjectileLibrary {
+    /// Get a projectile definition by its unique ID
+    pub fn get(&self, id: &str) -> Option<&ProjectileDefinition> {
+        self.map.get(id)
+    }
+
+    /// Add a new projectile definition to the library
+    pub fn add(&mut self, definition: ProjectileDefinition) {
+        self.map.insert(definition.id.clone(), definition);
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.loading_finished && self.loading.is_empty()
+    }
+}
@@ -0,0 +1,2 @@
+mod process_projectiles;
+pub use self::process_projectiles::*;
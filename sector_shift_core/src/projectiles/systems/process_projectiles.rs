@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::projectiles::{
+    assets::ProjectileAsset,
+    resources::{ProjectileDefinition, ProjectileLibrary},
+};
+
+/// A system that processes loaded projectile assets and adds them to the [`ProjectileLibrary`]
+pub fn process_projectiles(
+    asset_server: Res<AssetServer>,
+    gltfs: Res<Assets<Gltf>>,
+    images: Res<Assets<Image>>,
+    a_projectiles: Res<Assets<ProjectileAsset>>,
+    mut projectile_library: ResMut<ProjectileLibrary>,
+) {
+    if projectile_library.loading_finished {
+        for handle in std::mem::take(&mut projectile_library.loading) {
+            if let Some(projectile_asset) = a_projectiles.get(&handle) {
+                match ProjectileDefinition::from_asset(&asset_server, &gltfs, &images, projectile_asset) {
+                    Ok(definition) => {
+                        info!("Loaded projectile asset with id {}", definition.id);
+                        projectile_library.add(definition);
+                    },
+                    Err(e) => {
+                        warn!(
+                            "Failed to load projectile asset {}: {e}",
+                            projectile_asset.id
+                        )
+                    },
+                }
+            }
+        }
+    }
+}
@@ -1,28 +1,44 @@
 use bevy::prelude::*;
 
-use crate::{enemies::EnemiesPlugin, environment::EnvObjsPlugin, items::ItemsPlugin, utils::UtilsPlugin};
+use crate::{
+    difficulty::DifficultyPlugin, enemies::EnemiesPlugin, environment::EnvObjsPlugin,
+    episodes::EpisodesPlugin, items::ItemsPlugin, projectiles::ProjectilesPlugin, sounds::SoundsPlugin,
+    utils::UtilsPlugin,
+};
 
 pub struct SectorShiftCorePlugin<T: States + Copy> {
+    difficulty_plugin: DifficultyPlugin<T>,
     enemies_plugin: EnemiesPlugin<T>,
+    episodes_plugin: EpisodesPlugin<T>,
     items_plugin: ItemsPlugin<T>,
     env_objs_plugin: EnvObjsPlugin<T>,
+    projectiles_plugin: ProjectilesPlugin<T>,
+    sounds_plugin: SoundsPlugin<T>,
 }
 
 impl<T: States + Copy> SectorShiftCorePlugin<T> {
     pub fn new(asset_load_state: T) -> Self {
         Self {
+            difficulty_plugin: DifficultyPlugin::new(asset_load_state),
             enemies_plugin: EnemiesPlugin::new(asset_load_state),
+            episodes_plugin: EpisodesPlugin::new(asset_load_state),
             items_plugin: ItemsPlugin::new(asset_load_state),
             env_objs_plugin: EnvObjsPlugin::new(asset_load_state),
+            projectiles_plugin: ProjectilesPlugin::new(asset_load_state),
+            sounds_plugin: SoundsPlugin::new(asset_load_state),
         }
     }
 }
 
 impl<T: States + Copy> Plugin for SectorShiftCorePlugin<T> {
     fn build(&self, app: &mut App) {
+        app.add_plugins(self.difficulty_plugin.clone());
         app.add_plugins(self.enemies_plugin.clone());
+        app.add_plugins(self.episodes_plugin.clone());
         app.add_plugins(self.items_plugin.clone());
         app.add_plugins(self.env_objs_plugin.clone());
+        app.add_plugins(self.projectiles_plugin.clone());
+        app.add_plugins(self.sounds_plugin.clone());
 
         app.add_plugins(UtilsPlugin);
     }
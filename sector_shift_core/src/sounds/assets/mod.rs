@@ -0,0 +1,2 @@
+mod sound_asset;
+pub use self::sound_asset::*;
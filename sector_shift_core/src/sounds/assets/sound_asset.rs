@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This gets loaded by the AssetManager
+/// Represents a sound effect or music track, one of its clips is picked every time it plays
+#[derive(Serialize, Deserialize, Asset, TypePath)]
+pub struct SoundAsset {
+    /// This must be unique as it is used as the key when looking up from the [`SoundLibrary`]
+    /// resource, by [`SoundEvent::default_sound`] and by the music of levels and episodes
+    pub id: String,
+    /// The paths to the audio files to be loaded, a random one plays each time
+    pub clips: Vec<String>,
+    /// Volume between `0.0` and `1.0`, on top of the global volume
+    #[serde(default = "f_volume")]
+    pub volume: f32,
+    /// How far the playback speed, and with it the pitch, randomly strays from normal, e.g. `0.1`
+    /// plays at 90% to 110%
+    #[serde(default)]
+    pub pitch_variance: f32,
+    /// How many instances can play at once, further ones are dropped until one finishes
+    #[serde(default = "f_max_instances")]
+    pub max_instances: u32,
+}
+
+impl SoundAsset {
+    /// The file extension for these assets
+    pub const EXTENSION: &'static str = "sound";
+}
+
+fn f_volume() -> f32 {
+    1.0
+}
+
+fn f_max_instances() -> u32 {
+    4
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::SoundAsset;
+    use crate::sounds::data::SoundEvent;
+
+    #[test]
+    fn test_default_sounds_ship() {
+        let assets = Path::new(env!("CARGO_MANIFEST_DIR")).join("../assets");
+        for event in SoundEvent::ALL {
+            let id = event.default_sound();
+            let path = assets.join(format!("sounds/{id}.{}", SoundAsset::EXTENSION));
+            let sound: SoundAsset = ron::de::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+
+            assert_eq!(sound.id, id);
+            assert!(!sound.clips.is_empty());
+            for clip in &sound.clips {
+                assert!(assets.join(clip).is_file(), "{id} is missing {clip}");
+            }
+        }
+    }
+}
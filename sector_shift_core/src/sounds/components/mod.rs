@@ -0,0 +1,2 @@
+mod sound_overrides;
+pub use self::sound_overrides::*;
@@ -0,0 +1,8 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::sounds::data::SoundEvent;
+
+/// Sounds an entity makes instead of the defaults, keyed by event, e.g. the death cry of an enemy type
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component)]
+pub struct SoundOverrides(pub HashMap<SoundEvent, String>);
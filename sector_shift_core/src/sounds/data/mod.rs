@@ -0,0 +1,8 @@
+mod sound_cue;
+pub use self::sound_cue::*;
+
+mod sound_event;
+pub use self::sound_event::*;
+
+mod sound_output;
+pub use self::sound_output::*;
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+/// A single playback of a sound, handed to a [`SoundOutput`](crate::sounds::data::SoundOutput)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoundCue {
+    /// The id of the sound the cue was made from
+    pub sound: String,
+    pub clip: Handle<AudioSource>,
+    pub volume: f32,
+    /// Playback speed, which also changes the pitch
+    pub speed: f32,
+    /// Where the sound comes from, `None` plays it at the listener like the player's own sounds
+    pub position: Option<Vec3>,
+    /// Starts over when it ends, for music
+    pub looping: bool,
+}
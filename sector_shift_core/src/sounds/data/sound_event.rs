@@ -0,0 +1,53 @@
+use std::fmt::Display;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Something happening in the game that makes a noise
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEvent {
+    DoorOpen,
+    DoorClose,
+    WeaponFire,
+    Pickup,
+    EnemyAlert,
+    EnemyPain,
+    EnemyDeath,
+    Footstep,
+    ProjectileImpact,
+}
+
+impl SoundEvent {
+    pub const ALL: [SoundEvent; 9] = [
+        SoundEvent::DoorOpen,
+        SoundEvent::DoorClose,
+        SoundEvent::WeaponFire,
+        SoundEvent::Pickup,
+        SoundEvent::EnemyAlert,
+        SoundEvent::EnemyPain,
+        SoundEvent::EnemyDeath,
+        SoundEvent::Footstep,
+        SoundEvent::ProjectileImpact,
+    ];
+
+    /// The id of the sound played when nothing overrides it, see [`SoundOverrides`](crate::sounds::components::SoundOverrides)
+    pub fn default_sound(self) -> &'static str {
+        match self {
+            SoundEvent::DoorOpen => "door_open",
+            SoundEvent::DoorClose => "door_close",
+            SoundEvent::WeaponFire => "weapon_fire",
+            SoundEvent::Pickup => "pickup",
+            SoundEvent::EnemyAlert => "enemy_alert",
+            SoundEvent::EnemyPain => "enemy_pain",
+            SoundEvent::EnemyDeath => "enemy_death",
+            SoundEvent::Footstep => "footstep",
+            SoundEvent::ProjectileImpact => "projectile_impact",
+        }
+    }
+}
+
+impl Display for SoundEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
@@ -0,0 +1,9 @@
+use crate::sounds::data::SoundCue;
+
+/// Where sounds end up. The game plays them through bevy's audio, tests record them instead so
+/// which sounds gameplay makes can be checked without an audio device.
+pub trait SoundOutput {
+    /// How many instances of a sound are playing right now
+    fn playing(&self, sound: &str) -> usize;
+    fn play(&mut self, cue: SoundCue);
+}
@@ -0,0 +1,8 @@
+pub mod assets;
+pub mod components;
+pub mod data;
+pub mod resources;
+pub mod systems;
+
+mod sounds_plugin;
+pub use self::sounds_plugin::*;
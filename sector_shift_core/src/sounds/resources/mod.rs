@@ -0,0 +1,5 @@
+mod sound_definition;
+pub use self::sound_definition::*;
+
+mod sound_library;
+pub use self::sound_library::*;
@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+
+use crate::sounds::{assets::SoundAsset, data::SoundCue};
+
+/// An intermediate step between a sound asset and a playing sound
+pub struct SoundDefinition {
+    /// The unique ID of the sound
+    pub id: String,
+    /// The clips one is picked from each time the sound plays
+    pub clips: Vec<Handle<AudioSource>>,
+    pub volume: f32,
+    /// How far the playback speed randomly strays from normal
+    pub pitch_variance: f32,
+    /// How many instances can play at once
+    pub max_instances: u32,
+}
+
+impl SoundDefinition {
+    /// Helper to convert from [`SoundAsset`] to [`SoundDefinition`]
+    pub fn from_asset(asset_server: &AssetServer, asset: &SoundAsset) -> Self {
+        Self {
+            id: asset.id.clone(),
+            clips: asset.clips.iter().map(|clip| asset_server.load(clip)).collect(),
+            volume: asset.volume,
+            pitch_variance: asset.pitch_variance,
+            max_instances: asset.max_instances,
+        }
+    }
+
+    /// A playback of the sound, picking its clip and speed with `roll`, which returns random
+    /// numbers between `0.0` and `1.0`. `None` if the sound has no clips
+    pub fn cue(
+        &self,
+        position: Option<Vec3>,
+        looping: bool,
+        mut roll: impl FnMut() -> f32,
+    ) -> Option<SoundCue> {
+        if self.clips.is_empty() {
+            return None;
+        }
+        let index = ((roll() * self.clips.len() as f32) as usize).min(self.clips.len() - 1);
+        Some(SoundCue {
+            sound: self.id.clone(),
+            clip: self.clips[index].clone(),
+            volume: self.volume,
+            speed: 1.0 + self.pitch_variance * (roll() * 2.0 - 1.0),
+            position,
+            looping,
+        })
+    }
+}
@@ -0,0 +1,199 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::sounds::{
+    assets::SoundAsset,
+    components::SoundOverrides,
+    data::{SoundEvent, SoundOutput},
+    resources::SoundDefinition,
+};
+
+/// A library of sounds which can be played
+#[derive(Resource, Default)]
+pub struct SoundLibrary {
+    /// A map of loaded sound definitions sorted by their id
+    pub map: HashMap<String, SoundDefinition>,
+    /// A list of sound assets currently being loaded
+    pub loading: Vec<Handle<SoundAsset>>,
+    /// Indicates that all handles in `Self.loading` are fully loaded
+    pub loading_finished: bool,
+}
+
+impl SoundLibrary {
+    /// Get a sound definition by its unique ID
+    pub fn get(&self, id: &str) -> Option<&SoundDefinition> {
+        self.map.get(id)
+    }
+
+    /// Add a new sound definition to the library
+    pub fn add(&mut self, definition: SoundDefinition) {
+        self.map.insert(definition.id.clone(), definition);
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.loading_finished && self.loading.is_empty()
+    }
+
+    /// The sound made by an event, the override if there is one and it exists, otherwise the
+    /// event's default sound
+    pub fn sound_for(
+        &self,
+        event: SoundEvent,
+        overrides: Option<&SoundOverrides>,
+    ) -> Option<&SoundDefinition> {
+        overrides
+            .and_then(|overrides| overrides.0.get(&event))
+            .and_then(|id| self.get(id))
+            .or_else(|| self.get(event.default_sound()))
+    }
+
+    /// Plays a sound unless it is unknown or already playing as often as it may, returns whether it
+    /// played. `roll` returns random numbers between `0.0` and `1.0`
+    pub fn play(
+        &self,
+        output: &mut impl SoundOutput,
+        sound: &str,
+        position: Option<Vec3>,
+        looping: bool,
+        roll: impl FnMut() -> f32,
+    ) -> bool {
+        let Some(definition) = self.get(sound) else {
+            return false;
+        };
+        if output.playing(sound) >= definition.max_instances as usize {
+            return false;
+        }
+        let Some(cue) = definition.cue(position, looping, roll) else {
+            return false;
+        };
+        output.play(cue);
+        true
+    }
+
+    /// Plays the sound made by an event, see [`Self::sound_for`] and [`Self::play`]
+    pub fn play_event(
+        &self,
+        output: &mut impl SoundOutput,
+        event: SoundEvent,
+        overrides: Option<&SoundOverrides>,
+        position: Option<Vec3>,
+        roll: impl FnMut() -> f32,
+    ) -> bool {
+        let Some(definition) = self.sound_for(event, overrides) else {
+            return false;
+        };
+        let sound = definition.id.clone();
+        self.play(output, &sound, position, false, roll)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{platform::collections::HashMap, prelude::*};
+
+    use super::SoundLibrary;
+    use crate::sounds::{
+        components::SoundOverrides,
+        data::{SoundCue, SoundEvent, SoundOutput},
+        resources::SoundDefinition,
+    };
+
+    /// Records what would have been played
+    #[derive(Default)]
+    struct RecordingOutput {
+        played: Vec<SoundCue>,
+    }
+
+    impl SoundOutput for RecordingOutput {
+        fn playing(&self, sound: &str) -> usize {
+            self.played.iter().filter(|cue| cue.sound == sound).count()
+        }
+
+        fn play(&mut self, cue: SoundCue) {
+            self.played.push(cue);
+        }
+    }
+
+    fn sound(id: &str, clips: usize) -> SoundDefinition {
+        SoundDefinition {
+            id: id.to_string(),
+            clips: vec![Handle::default(); clips],
+            volume: 0.5,
+            pitch_variance: 0.2,
+            max_instances: 2,
+        }
+    }
+
+    fn library() -> SoundLibrary {
+        let mut library = SoundLibrary::default();
+        library.add(sound("pickup", 1));
+        library.add(sound("enemy_death", 2));
+        library.add(sound("enemy_pain", 1));
+        library.add(sound("alien_death", 1));
+        library.add(sound("silence", 0));
+        library
+    }
+
+    #[test]
+    fn test_event_sounds() {
+        let library = library();
+        let mut output = RecordingOutput::default();
+
+        assert!(library.play_event(&mut output, SoundEvent::Pickup, None, None, || 0.5));
+        let position = Some(Vec3::new(1.0, 2.0, 3.0));
+        assert!(library.play_event(&mut output, SoundEvent::EnemyDeath, None, position, || 0.5));
+        // No sound is loaded for doors
+        assert!(!library.play_event(&mut output, SoundEvent::DoorOpen, None, None, || 0.5));
+
+        let sounds: Vec<_> = output.played.iter().map(|cue| cue.sound.as_str()).collect();
+        assert_eq!(sounds, ["pickup", "enemy_death"]);
+        assert_eq!(output.played[1].position, position);
+        assert_eq!(output.played[1].volume, 0.5);
+        assert!(!output.played[1].looping);
+    }
+
+    #[test]
+    fn test_sound_overrides() {
+        let library = library();
+        let alien = SoundOverrides(HashMap::from_iter([
+            (SoundEvent::EnemyDeath, "alien_death".to_string()),
+            (SoundEvent::EnemyPain, "missing".to_string()),
+        ]));
+
+        assert_eq!(
+            library.sound_for(SoundEvent::EnemyDeath, Some(&alien)).unwrap().id,
+            "alien_death"
+        );
+        // Unknown overrides and events without one use the default sound
+        assert_eq!(
+            library.sound_for(SoundEvent::EnemyPain, Some(&alien)).unwrap().id,
+            "enemy_pain"
+        );
+        assert_eq!(
+            library.sound_for(SoundEvent::Pickup, Some(&alien)).unwrap().id,
+            "pickup"
+        );
+    }
+
+    #[test]
+    fn test_sound_limits() {
+        let library = library();
+        let mut output = RecordingOutput::default();
+        for _ in 0..5 {
+            library.play(&mut output, "pickup", None, false, || 0.5);
+        }
+        assert_eq!(output.playing("pickup"), 2);
+        assert!(!library.play(&mut output, "silence", None, false, || 0.5));
+        assert!(library.play(&mut output, "enemy_death", None, true, || 0.99));
+        assert!(output.played[2].looping);
+    }
+
+    #[test]
+    fn test_sound_pitch_variance() {
+        let definition = sound("enemy_death", 2);
+        let low = definition.cue(None, false, || 0.0).unwrap();
+        let high = definition.cue(None, false, || 1.0).unwrap();
+        assert!((low.speed - 0.8).abs() < 1e-5);
+        assert!((high.speed - 1.2).abs() < 1e-5);
+        assert!(definition.cue(None, false, || 0.5).unwrap().speed == 1.0);
+    }
+}
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::{
+    sounds::{assets::SoundAsset, resources::SoundLibrary, systems::process_sounds},
+    utils::{generic_asset_loader::GenericAssetLoader, generic_directory_loader::DirectoryLoaderPlugin},
+};
+
+#[derive(Clone)]
+pub struct SoundsPlugin<T: States + Copy> {
+    asset_load_state: T,
+}
+
+impl<T: States + Copy> SoundsPlugin<T> {
+    pub fn new(asset_load_state: T) -> Self {
+        Self { asset_load_state }
+    }
+}
+
+impl<T: States + Copy> Plugin for SoundsPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<SoundAsset>();
+        app.register_asset_loader(GenericAssetLoader::<SoundAsset>::new(&[
+            SoundAsset::EXTENSION,
+        ]));
+
+        app.init_resource::<SoundLibrary>();
+        app.add_plugins(DirectoryLoaderPlugin::<SoundLibrary, _>::new(
+            self.asset_load_state,
+        ));
+
+        app.add_systems(
+            Update,
+            process_sounds.run_if(in_state(self.asset_load_state)),
+        );
+    }
+}
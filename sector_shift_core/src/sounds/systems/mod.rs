@@ -0,0 +1,2 @@
+mod process_sounds;
+pub use self::process_sounds::*;
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+use crate::sounds::{
+    assets::SoundAsset,
+    resources::{SoundDefinition, SoundLibrary},
+};
+
+/// A system that processes loaded sound assets and adds them to the [`SoundLibrary`]
+pub fn process_sounds(
+    asset_server: Res<AssetServer>,
+    a_sounds: Res<Assets<SoundAsset>>,
+    mut sound_library: ResMut<SoundLibrary>,
+) {
+    if sound_library.loading_finished {
+        for handle in std::mem::take(&mut sound_library.loading) {
+            if let Some(sound_asset) = a_sounds.get(&handle) {
+                info!("Loaded sound asset with id: {}", sound_asset.id);
+                sound_library.add(SoundDefinition::from_asset(&asset_server, sound_asset));
+            }
+        }
+    }
+}
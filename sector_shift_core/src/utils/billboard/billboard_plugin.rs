@@ -1,10 +1,10 @@
 use bevy::prelude::*;
 
-use crate::utils::billboard::systems::rotate_billboards;
+use crate::utils::billboard::systems::{animate_billboards, rotate_billboards};
 
 pub struct BillboardPlugin;
 impl Plugin for BillboardPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, rotate_billboards);
+        app.add_systems(Update, (rotate_billboards, animate_billboards));
     }
 }
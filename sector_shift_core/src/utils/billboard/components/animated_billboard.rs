@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::utils::billboard::data::SpriteSheet;
+
+/// Plays a sprite sheet on the material of a billboard by moving its UVs from frame to frame
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct AnimatedBillboard {
+    pub sheet: SpriteSheet,
+    /// Seconds since the animation started
+    pub elapsed: f32,
+    /// The frame currently shown, the material is only touched when it changes
+    pub shown: Option<u32>,
+}
+
+impl AnimatedBillboard {
+    pub fn new(sheet: SpriteSheet) -> Self {
+        Self {
+            sheet,
+            elapsed: 0.0,
+            shown: None,
+        }
+    }
+}
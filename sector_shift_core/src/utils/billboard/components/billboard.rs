@@ -1,5 +1,12 @@
 use bevy::prelude::*;
 
-#[derive(Component, Reflect, Default)]
+/// Turns an entity to show its front (+Z) to the camera
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
 #[reflect(Component)]
-pub struct Billboard;
+pub enum Billboard {
+    /// Faces the camera on every axis
+    #[default]
+    Full,
+    /// Only turns around the Y axis and stays upright, like the sprites of Doom
+    YAxis,
+}
@@ -1,2 +1,5 @@
+mod animated_billboard;
+pub use self::animated_billboard::*;
+
 mod billboard;
 pub use self::billboard::*;
@@ -0,0 +1,5 @@
+mod sprite_playback;
+pub use self::sprite_playback::*;
+
+mod sprite_sheet;
+pub use self::sprite_sheet::*;
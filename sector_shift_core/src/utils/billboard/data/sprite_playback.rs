@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// What a sprite animation does after its last frame
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SpritePlayback {
+    /// Plays once and holds the last frame
+    Once,
+    /// Starts over from the first frame
+    #[default]
+    Loop,
+    /// Plays backwards to the first frame and then forwards again
+    PingPong,
+}
+
+impl SpritePlayback {
+    /// The frame shown after playing `frames` frames at `fps` for `elapsed` seconds, still sprites
+    /// without `fps` stay on the first frame
+    pub fn frame_at(self, elapsed: f32, fps: f32, frames: u32) -> u32 {
+        if fps <= 0.0 {
+            return 0;
+        }
+        self.frame((elapsed * fps) as u32, frames)
+    }
+
+    /// Seconds one play of `frames` frames at `fps` takes, including the way back to the first
+    /// frame for `PingPong`. Still sprites without `fps` take none
+    pub fn duration(self, fps: f32, frames: u32) -> f32 {
+        if fps <= 0.0 {
+            return 0.0;
+        }
+        let frames = frames.max(1);
+        let ticks = match self {
+            SpritePlayback::PingPong => 2 * frames - 1,
+            SpritePlayback::Once | SpritePlayback::Loop => frames,
+        };
+        ticks as f32 / fps
+    }
+
+    /// The frame shown on the given tick of an animation with `frames` frames
+    pub fn frame(self, tick: u32, frames: u32) -> u32 {
+        let frames = frames.max(1);
+        match self {
+            SpritePlayback::Once => tick.min(frames - 1),
+            SpritePlayback::Loop => tick % frames,
+            SpritePlayback::PingPong => {
+                if frames == 1 {
+                    return 0;
+                }
+                let period = 2 * (frames - 1);
+                let tick = tick % period;
+                if tick < frames { tick } else { period - tick }
+            },
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use bevy::{math::Affine2, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::billboard::data::SpritePlayback;
+
+/// The frames of an animated sprite, laid out left to right and then top to bottom on one image
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+pub struct SpriteSheet {
+    /// The size of a single frame in pixels
+    pub frame_size: (u32, u32),
+    /// The number of frames on the sheet
+    #[serde(default = "f_frames")]
+    pub frames: u32,
+    /// Frames shown per second
+    #[serde(default = "f_fps")]
+    pub fps: f32,
+    #[serde(default)]
+    pub playback: SpritePlayback,
+}
+
+impl SpriteSheet {
+    /// The frame shown after playing for `elapsed` seconds
+    pub fn frame_at(&self, elapsed: f32) -> u32 {
+        self.playback.frame_at(elapsed, self.fps, self.frames)
+    }
+
+    /// Seconds one play of the frames takes, including the way back to the first frame for
+    /// `PingPong`. Still sprites without `fps` take none
+    pub fn duration(&self) -> f32 {
+        self.playback.duration(self.fps, self.frames)
+    }
+
+    /// The pixels of the first frame, which is always in the top left corner
+    pub fn first_frame(&self) -> Rect {
+        Rect::new(0.0, 0.0, self.frame_size.0 as f32, self.frame_size.1 as f32)
+    }
+
+    /// The UV transform of a material showing `frame` of the sheet
+    pub fn uv_transform(&self, frame: u32, image_size: UVec2) -> Affine2 {
+        let columns = (image_size.x / self.frame_size.0.max(1)).max(1);
+        let cell = UVec2::new(frame % columns, frame / columns);
+        Self::frame_uv_transform(self.frame_size, cell, image_size)
+    }
+
+    /// The UV transform of a material showing the frame in the given column and row of a sheet
+    pub fn frame_uv_transform(frame_size: (u32, u32), cell: UVec2, image_size: UVec2) -> Affine2 {
+        let scale =
+            Vec2::new(frame_size.0 as f32, frame_size.1 as f32) / image_size.max(UVec2::ONE).as_vec2();
+        Affine2::from_scale_angle_translation(scale, 0.0, cell.as_vec2() * scale)
+    }
+}
+
+fn f_frames() -> u32 {
+    1
+}
+
+fn f_fps() -> f32 {
+    10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::SpriteSheet;
+    use crate::utils::billboard::data::SpritePlayback;
+
+    #[test]
+    fn test_sprite_sheet_playback() {
+        let sheet: SpriteSheet = ron::de::from_str("(frame_size: (32, 32), frames: 4, fps: 4.0)").unwrap();
+        assert_eq!(sheet.playback, SpritePlayback::Loop);
+        let frames: Vec<u32> = (0..9).map(|tick| sheet.frame_at(tick as f32 / 4.0 + 0.01)).collect();
+        assert_eq!(frames, [0, 1, 2, 3, 0, 1, 2, 3, 0]);
+
+        let once = SpriteSheet {
+            playback: SpritePlayback::Once,
+            ..sheet.clone()
+        };
+        assert_eq!(once.frame_at(100.0), 3);
+
+        let ping_pong = SpriteSheet {
+            playback: SpritePlayback::PingPong,
+            ..sheet
+        };
+        let frames: Vec<u32> = (0..9).map(|tick| ping_pong.frame_at(tick as f32 / 4.0 + 0.01)).collect();
+        assert_eq!(frames, [0, 1, 2, 3, 2, 1, 0, 1, 2]);
+        assert_eq!(SpritePlayback::PingPong.frame(7, 1), 0);
+    }
+
+    #[test]
+    fn test_sprite_sheet_uv_transform() {
+        let sheet: SpriteSheet = ron::de::from_str("(frame_size: (32, 16), frames: 6)").unwrap();
+        // Three frames per row, so the fifth frame is the middle of the second row
+        let transform = sheet.uv_transform(4, UVec2::new(96, 32));
+        assert_eq!(
+            transform.transform_point2(Vec2::ZERO),
+            Vec2::new(1.0 / 3.0, 0.5)
+        );
+        assert_eq!(
+            transform.transform_point2(Vec2::ONE),
+            Vec2::new(2.0 / 3.0, 1.0)
+        );
+        assert_eq!(sheet.first_frame(), Rect::new(0.0, 0.0, 32.0, 16.0));
+    }
+}
@@ -1,4 +1,5 @@
 pub mod components;
+pub mod data;
 pub mod systems;
 
 mod billboard_plugin;
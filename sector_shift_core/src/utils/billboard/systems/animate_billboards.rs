@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use crate::utils::billboard::components::AnimatedBillboard;
+
+/// Advances animated billboards and moves the UVs of their material to the current frame
+pub fn animate_billboards(
+    time: Res<Time>,
+    images: Res<Assets<Image>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut q_billboards: Query<(&mut AnimatedBillboard, &MeshMaterial3d<StandardMaterial>)>,
+) {
+    for (mut billboard, material_handle) in q_billboards.iter_mut() {
+        billboard.elapsed += time.delta_secs();
+        let frame = billboard.sheet.frame_at(billboard.elapsed);
+        if billboard.shown == Some(frame) {
+            continue;
+        }
+        let Some(image_size) = materials
+            .get(material_handle)
+            .and_then(|material| material.base_color_texture.as_ref())
+            .and_then(|image| images.get(image))
+            .map(|image| image.size())
+        else {
+            continue;
+        };
+        let Some(material) = materials.get_mut(material_handle) else {
+            continue;
+        };
+        material.uv_transform = billboard.sheet.uv_transform(frame, image_size);
+        billboard.shown = Some(frame);
+    }
+}
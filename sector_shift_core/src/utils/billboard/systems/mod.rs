@@ -1,2 +1,5 @@
+mod animate_billboards;
+pub use self::animate_billboards::*;
+
 mod rotate_billboards;
 pub use self::rotate_billboards::*;
@@ -4,12 +4,23 @@ use crate::utils::billboard::components::Billboard;
 
 pub fn rotate_billboards(
     q_camera: Single<&GlobalTransform, With<Camera>>,
-    mut q_billboards: Query<&mut Transform, With<Billboard>>,
+    mut q_billboards: Query<(&mut Transform, &GlobalTransform, &Billboard)>,
 ) {
-    let camera_transform = *q_camera;
+    let camera_position = q_camera.translation();
 
-    for mut transform in q_billboards.iter_mut() {
-        // TOOD: Should probably be xz plane only
-        transform.look_at(camera_transform.translation(), Vec3::Y);
+    for (mut transform, global_transform, billboard) in q_billboards.iter_mut() {
+        // Looking away from the camera turns the front of the billboard towards it, so its
+        // texture isn't mirrored
+        let mut away = global_transform.translation() - camera_position;
+        if *billboard == Billboard::YAxis {
+            away.y = 0.0;
+        }
+        if away.length_squared() <= f32::EPSILON {
+            continue;
+        }
+        let target = Transform::IDENTITY.looking_to(away, Vec3::Y);
+        // Billboards can be children of rotated entities, undo the rotation of the parent
+        let parent_rotation = global_transform.rotation() * transform.rotation.inverse();
+        transform.rotation = parent_rotation.inverse() * target.rotation;
     }
 }
@@ -1,6 +1,10 @@
 use bevy::asset::LoadContext;
 
-use crate::{enemies::assets::EnemyAsset, environment::assets::EnvObjAsset, items::assets::ItemAsset};
+use crate::{
+    difficulty::assets::DifficultyAsset, enemies::assets::EnemyAsset, environment::assets::EnvObjAsset,
+    episodes::assets::EpisodeAsset, items::assets::ItemAsset, projectiles::assets::ProjectileAsset,
+    sounds::assets::SoundAsset,
+};
 
 pub trait LoadAssetDependencies {
     fn load_dependencies(&mut self, _load_context: &mut LoadContext) {}
@@ -8,12 +12,22 @@ pub trait LoadAssetDependencies {
 
 impl LoadAssetDependencies for EnemyAsset {
     fn load_dependencies(&mut self, load_context: &mut LoadContext) {
-        self.gltf_handle = Some(load_context.load(&self.gltf));
+        self.gltf_handle = self.gltf.as_ref().map(|gltf| load_context.load(gltf));
     }
 }
 impl LoadAssetDependencies for ItemAsset {}
+impl LoadAssetDependencies for DifficultyAsset {}
+impl LoadAssetDependencies for SoundAsset {}
+impl LoadAssetDependencies for EpisodeAsset {}
+impl LoadAssetDependencies for ProjectileAsset {
+    fn load_dependencies(&mut self, load_context: &mut LoadContext) {
+        self.gltf_handle = self.gltf.as_ref().map(|gltf| load_context.load(gltf));
+        self.sprite_handle = self.sprite.as_ref().map(|sprite| load_context.load(sprite));
+    }
+}
 impl LoadAssetDependencies for EnvObjAsset {
     fn load_dependencies(&mut self, load_context: &mut LoadContext) {
-        self.gltf_handle = Some(load_context.load(&self.gltf));
+        self.gltf_handle = self.gltf.as_ref().map(|gltf| load_context.load(gltf));
+        self.sprite_handle = self.sprite.as_ref().map(|sprite| load_context.load(sprite));
     }
 }
@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 
 use crate::{
-    enemies::assets::EnemyAsset, environment::assets::EnvObjAsset, items::assets::ItemAsset, prelude::*,
+    difficulty::assets::DifficultyAsset, enemies::assets::EnemyAsset, environment::assets::EnvObjAsset,
+    episodes::assets::EpisodeAsset, items::assets::ItemAsset, prelude::*,
+    projectiles::assets::ProjectileAsset, sounds::assets::SoundAsset,
 };
 
 pub trait DataLibrary: Resource + Send + Sync + 'static {
@@ -16,6 +18,19 @@ pub trait DataLibrary: Resource + Send + Sync + 'static {
     fn mark_finished(&mut self);
 }
 
+impl DataLibrary for DifficultyLibrary {
+    type Asset = DifficultyAsset;
+    const ASSET_PATH: &'static str = "difficulty";
+    const EXTENSION: &'static str = Self::Asset::EXTENSION;
+
+    fn add_asset_handle(&mut self, handle: Handle<Self::Asset>) {
+        self.loading.push(handle);
+    }
+    fn mark_finished(&mut self) {
+        self.loading_finished = true;
+    }
+}
+
 impl DataLibrary for EnemyLibrary {
     type Asset = EnemyAsset;
     const ASSET_PATH: &'static str = "enemies";
@@ -29,6 +44,19 @@ impl DataLibrary for EnemyLibrary {
     }
 }
 
+impl DataLibrary for EpisodeLibrary {
+    type Asset = EpisodeAsset;
+    const ASSET_PATH: &'static str = "episodes";
+    const EXTENSION: &'static str = Self::Asset::EXTENSION;
+
+    fn add_asset_handle(&mut self, handle: Handle<Self::Asset>) {
+        self.loading.push(handle);
+    }
+    fn mark_finished(&mut self) {
+        self.loading_finished = true;
+    }
+}
+
 impl DataLibrary for ItemLibrary {
     type Asset = ItemAsset;
     const ASSET_PATH: &'static str = "items";
@@ -54,3 +82,29 @@ impl DataLibrary for EnvObjLibrary {
         self.loading_finished = true;
     }
 }
+
+impl DataLibrary for ProjectileLibrary {
+    type Asset = ProjectileAsset;
+    const ASSET_PATH: &'static str = "projectiles";
+    const EXTENSION: &'static str = Self::Asset::EXTENSION;
+
+    fn add_asset_handle(&mut self, handle: Handle<Self::Asset>) {
+        self.loading.push(handle);
+    }
+    fn mark_finished(&mut self) {
+        self.loading_finished = true;
+    }
+}
+
+impl DataLibrary for SoundLibrary {
+    type Asset = SoundAsset;
+    const ASSET_PATH: &'static str = "sounds";
+    const EXTENSION: &'static str = Self::Asset::EXTENSION;
+
+    fn add_asset_handle(&mut self, handle: Handle<Self::Asset>) {
+        self.loading.push(handle);
+    }
+    fn mark_finished(&mut self) {
+        self.loading_finished = true;
+    }
+}
@@ -1,7 +1,10 @@
 use core::slice;
-use std::ops::{Index, IndexMut};
+use std::{
+    collections::VecDeque,
+    ops::{Index, IndexMut},
+};
 
-use bevy::prelude::*;
+use bevy::{platform::collections::HashSet, prelude::*};
 use serde::{Deserialize, Serialize};
 
 use crate::utils::grid::PointIterRowMajor;
@@ -274,6 +277,36 @@ impl<T> Grid<T> {
             .map(move |(dx, dy)| (position.0 + dx, position.1 + dy))
             .filter(move |&pos| self.in_bounds(pos))
     }
+
+    /// Collects the positions connected to `start` through their four neighbours whose value passes
+    /// `include`, in the order they are reached, and adds them to `visited`.
+    ///
+    /// Returns nothing if `start` is outside of this `Grid`, not included or already visited, so
+    /// sharing `visited` between calls splits the included positions into separate regions.
+    pub fn flood(
+        &self,
+        start: (i32, i32),
+        visited: &mut HashSet<(i32, i32)>,
+        include: impl Fn(&T) -> bool,
+    ) -> Vec<(i32, i32)> {
+        let passes = |position: (i32, i32)| self.get(position).is_some_and(&include);
+        if !passes(start) || !visited.insert(start) {
+            return Vec::new();
+        }
+
+        let mut positions = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(position) = queue.pop_front() {
+            positions.push(position);
+            for (dx, dy) in [(0, 1), (1, 0), (0, -1), (-1, 0)] {
+                let next = (position.0 + dx, position.1 + dy);
+                if passes(next) && visited.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        positions
+    }
 }
 
 // Iterators
@@ -423,3 +456,43 @@ impl<T> IndexMut<(u32, u32)> for Grid<T> {
         self.get_mut(index).expect("Invalid index position")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::platform::collections::HashSet;
+
+    use super::Grid;
+
+    #[test]
+    fn test_flood() {
+        // 1 1 0
+        // 0 1 0
+        // 1 0 1
+        let grid = Grid::new((3, 3), vec![1, 1, 0, 0, 1, 0, 1, 0, 1]);
+        let mut visited = HashSet::new();
+
+        assert_eq!(
+            grid.flood((0, 0), &mut visited, |value| *value == 1),
+            vec![(0, 0), (1, 0), (1, 1)]
+        );
+        // Diagonal neighbours are not connected
+        assert_eq!(
+            grid.flood((2, 2), &mut visited, |value| *value == 1),
+            vec![(2, 2)]
+        );
+        // Visited, excluded and outside positions start nothing
+        assert_eq!(
+            grid.flood((1, 1), &mut visited, |value| *value == 1),
+            Vec::new()
+        );
+        assert_eq!(
+            grid.flood((2, 0), &mut visited, |value| *value == 1),
+            Vec::new()
+        );
+        assert_eq!(
+            grid.flood((3, 0), &mut visited, |value| *value == 1),
+            Vec::new()
+        );
+        assert_eq!(visited.len(), 4);
+    }
+}
@@ -1,12 +1,23 @@
 use crate::prelude::*;
 use bevy::{asset::UnapprovedPathMode, prelude::*};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 pub fn all_assets_loaded(
+    difficulty_library: Res<DifficultyLibrary>,
     enemy_library: Res<EnemyLibrary>,
+    episode_library: Res<EpisodeLibrary>,
     item_library: Res<ItemLibrary>,
     env_library: Res<EnvObjLibrary>,
+    projectile_library: Res<ProjectileLibrary>,
+    sound_library: Res<SoundLibrary>,
 ) -> bool {
-    enemy_library.is_ready() && item_library.is_ready() && env_library.is_ready()
+    difficulty_library.is_ready()
+        && enemy_library.is_ready()
+        && episode_library.is_ready()
+        && item_library.is_ready()
+        && env_library.is_ready()
+        && projectile_library.is_ready()
+        && sound_library.is_ready()
 }
 
 pub fn asset_plugin_with_fixed_path() -> AssetPlugin {
@@ -22,3 +33,18 @@ pub fn asset_plugin_with_fixed_path() -> AssetPlugin {
         ..default()
     }
 }
+
+/// Reads an optional asset path written as a plain string instead of `Some("...")`, so paths that
+/// used to be required keep their format
+pub(crate) fn deserialize_optional_path<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    String::deserialize(deserializer).map(Some)
+}
+
+pub(crate) fn serialize_optional_path<S: Serializer>(
+    path: &Option<String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    path.as_deref().unwrap_or_default().serialize(serializer)
+}
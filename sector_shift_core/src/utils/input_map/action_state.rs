@@ -0,0 +1,55 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::utils::input_map::InputAction;
+
+/// The state of every action this frame, updated from the [`InputMap`](super::InputMap) before `Update`
+#[derive(Resource)]
+pub struct ActionState<A: InputAction> {
+    current: HashMap<A, f32>,
+    previous: HashMap<A, f32>,
+}
+
+impl<A: InputAction> Default for ActionState<A> {
+    fn default() -> Self {
+        Self {
+            current: HashMap::new(),
+            previous: HashMap::new(),
+        }
+    }
+}
+
+impl<A: InputAction> ActionState<A> {
+    /// The value above which an analog input counts as pressed
+    pub const PRESS_THRESHOLD: f32 = 0.5;
+
+    /// Replaces the values of all actions, keeping the old ones to detect presses and releases
+    pub fn update(&mut self, values: impl IntoIterator<Item = (A, f32)>) {
+        self.previous = std::mem::take(&mut self.current);
+        self.current.extend(values);
+    }
+
+    /// Returns how far an action is pressed between `0.0` and `1.0`
+    pub fn value(&self, action: A) -> f32 {
+        self.current.get(&action).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the difference between two opposing actions between `-1.0` and `1.0`
+    pub fn axis(&self, negative: A, positive: A) -> f32 {
+        self.value(positive) - self.value(negative)
+    }
+
+    /// Returns whether an action is held down
+    pub fn pressed(&self, action: A) -> bool {
+        self.value(action) >= Self::PRESS_THRESHOLD
+    }
+
+    /// Returns whether an action started being pressed this frame
+    pub fn just_pressed(&self, action: A) -> bool {
+        self.pressed(action) && self.previous.get(&action).copied().unwrap_or(0.0) < Self::PRESS_THRESHOLD
+    }
+
+    /// Returns whether an action stopped being pressed this frame
+    pub fn just_released(&self, action: A) -> bool {
+        !self.pressed(action) && self.previous.get(&action).copied().unwrap_or(0.0) >= Self::PRESS_THRESHOLD
+    }
+}
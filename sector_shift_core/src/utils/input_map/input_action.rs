@@ -0,0 +1,15 @@
+use std::{fmt::Debug, hash::Hash};
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::utils::input_map::InputBinding;
+
+pub trait InputAction:
+    Copy + Eq + Hash + Debug + Send + Sync + Serialize + DeserializeOwned + 'static
+{
+    /// Where the bindings for these actions are stored
+    const BINDINGS_PATH: &'static str;
+
+    /// The bindings used when an action is missing from the bindings file
+    fn default_bindings() -> Vec<(Self, Vec<InputBinding>)>;
+}
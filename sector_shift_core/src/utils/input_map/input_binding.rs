@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Which half of a gamepad axis triggers a binding
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AxisDirection {
+    Positive,
+    Negative,
+}
+
+impl AxisDirection {
+    /// Returns `1.0` for [`AxisDirection::Positive`] and `-1.0` for [`AxisDirection::Negative`]
+    pub fn sign(self) -> f32 {
+        match self {
+            AxisDirection::Positive => 1.0,
+            AxisDirection::Negative => -1.0,
+        }
+    }
+}
+
+/// A physical input that can trigger an action
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputBinding {
+    /// A keyboard key
+    Key(KeyCode),
+    /// A mouse button
+    Mouse(MouseButton),
+    /// A gamepad button, analog buttons like triggers report how far they are pressed
+    GamepadButton(GamepadButton),
+    /// One half of a gamepad axis, e.g. pushing the left stick forward
+    GamepadAxis(GamepadAxis, AxisDirection),
+}
+
+impl InputBinding {
+    /// How far a gamepad axis has to be pushed to be captured by [`InputBinding::just_pressed`]
+    const CAPTURE_THRESHOLD: f32 = 0.75;
+
+    /// Returns whether this binding belongs to a gamepad rather than the keyboard and mouse
+    pub fn is_gamepad(&self) -> bool {
+        matches!(
+            self,
+            InputBinding::GamepadButton(_) | InputBinding::GamepadAxis(_, _)
+        )
+    }
+
+    /// Returns how far this binding is currently pressed between `0.0` and `1.0`
+    pub fn value<'a>(
+        &self,
+        keys: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+        gamepads: impl IntoIterator<Item = &'a Gamepad>,
+    ) -> f32 {
+        match *self {
+            InputBinding::Key(key) => keys.pressed(key) as u8 as f32,
+            InputBinding::Mouse(button) => mouse.pressed(button) as u8 as f32,
+            InputBinding::GamepadButton(button) => gamepads
+                .into_iter()
+                .map(|gamepad| gamepad.get(button).unwrap_or(gamepad.pressed(button) as u8 as f32))
+                .fold(0.0, f32::max),
+            InputBinding::GamepadAxis(axis, direction) => gamepads
+                .into_iter()
+                .map(|gamepad| gamepad.get(axis).unwrap_or(0.0) * direction.sign())
+                .fold(0.0, f32::max),
+        }
+    }
+
+    /// Returns the first input that started being pressed this frame, used to capture new bindings.
+    ///
+    /// Gamepad axes don't track when they were pushed, so `held_axes` holds the
+    /// [`pushed_axes`](Self::pushed_axes) of the previous frame and only axes crossing the threshold
+    /// now are captured.
+    pub fn just_pressed<'a>(
+        keys: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+        gamepads: impl IntoIterator<Item = &'a Gamepad>,
+        held_axes: &[Self],
+    ) -> Option<Self> {
+        if let Some(key) = keys.get_just_pressed().next() {
+            return Some(InputBinding::Key(*key));
+        }
+        if let Some(button) = mouse.get_just_pressed().next() {
+            return Some(InputBinding::Mouse(*button));
+        }
+        for gamepad in gamepads {
+            if let Some(button) = gamepad.digital().get_just_pressed().next() {
+                return Some(InputBinding::GamepadButton(*button));
+            }
+            if let Some(axis) =
+                Self::pushed_axes([gamepad]).into_iter().find(|axis| !held_axes.contains(axis))
+            {
+                return Some(axis);
+            }
+        }
+        None
+    }
+
+    /// Returns the stick axes pushed past the capture threshold, see [`InputBinding::just_pressed`]
+    pub fn pushed_axes<'a>(gamepads: impl IntoIterator<Item = &'a Gamepad>) -> Vec<Self> {
+        let mut axes = Vec::new();
+        for gamepad in gamepads {
+            for axis in [
+                GamepadAxis::LeftStickX,
+                GamepadAxis::LeftStickY,
+                GamepadAxis::RightStickX,
+                GamepadAxis::RightStickY,
+            ] {
+                let value = gamepad.get(axis).unwrap_or(0.0);
+                if value.abs() >= Self::CAPTURE_THRESHOLD {
+                    let direction =
+                        if value > 0.0 { AxisDirection::Positive } else { AxisDirection::Negative };
+                    axes.push(InputBinding::GamepadAxis(axis, direction));
+                }
+            }
+        }
+        axes
+    }
+
+    /// Returns a short human readable name for menus
+    pub fn label(&self) -> String {
+        match self {
+            InputBinding::Key(key) => format!("{key:?}").trim_start_matches("Key").to_string(),
+            InputBinding::Mouse(button) => format!("Mouse {button:?}"),
+            InputBinding::GamepadButton(button) => format!("Pad {button:?}"),
+            InputBinding::GamepadAxis(axis, AxisDirection::Positive) => format!("Pad {axis:?}+"),
+            InputBinding::GamepadAxis(axis, AxisDirection::Negative) => format!("Pad {axis:?}-"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::{AxisDirection, InputBinding};
+
+    #[test]
+    fn test_capture_axis_when_crossing_threshold() {
+        let keys = ButtonInput::<KeyCode>::default();
+        let mouse = ButtonInput::<MouseButton>::default();
+        let mut gamepad = Gamepad::default();
+        gamepad.analog_mut().set(GamepadAxis::LeftStickY, -0.9);
+
+        let pushed = InputBinding::GamepadAxis(GamepadAxis::LeftStickY, AxisDirection::Negative);
+        assert_eq!(InputBinding::pushed_axes([&gamepad]), vec![pushed]);
+        // Crossed this frame
+        assert_eq!(
+            InputBinding::just_pressed(&keys, &mouse, [&gamepad], &[]),
+            Some(pushed)
+        );
+        // Already held last frame
+        assert_eq!(
+            InputBinding::just_pressed(&keys, &mouse, [&gamepad], &[pushed]),
+            None
+        );
+    }
+}
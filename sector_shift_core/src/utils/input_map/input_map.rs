@@ -0,0 +1,143 @@
+use std::path::PathBuf;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::utils::input_map::{InputAction, InputBinding};
+
+#[derive(Debug, Error)]
+pub enum InputMapError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("RON error: {0}")]
+    Ron(#[from] ron::error::Error),
+    #[error("RON spanned error: {0}")]
+    RonSpanned(#[from] ron::error::SpannedError),
+}
+
+/// Maps each action to the inputs that trigger it
+#[derive(Resource, Serialize, Deserialize, Debug, Clone)]
+#[serde(bound = "A: InputAction")]
+pub struct InputMap<A: InputAction> {
+    /// The inputs bound to each action, any of them triggers the action
+    pub bindings: HashMap<A, Vec<InputBinding>>,
+}
+
+impl<A: InputAction> Default for InputMap<A> {
+    fn default() -> Self {
+        Self {
+            bindings: A::default_bindings().into_iter().collect(),
+        }
+    }
+}
+
+// Constructor + Serialization methods
+impl<A: InputAction> InputMap<A> {
+    fn get_path() -> PathBuf {
+        A::BINDINGS_PATH.into()
+    }
+
+    /// Loads the bindings from [`InputAction::BINDINGS_PATH`], actions missing from the file keep their
+    /// default bindings
+    pub fn load() -> Self {
+        let mut input_map = Self::default();
+        match Self::try_load() {
+            Ok(loaded) => input_map.bindings.extend(loaded.bindings),
+            Err(InputMapError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {},
+            Err(e) => warn!("Failed to load input bindings, using defaults: {e}"),
+        }
+        input_map
+    }
+
+    pub fn try_load() -> Result<Self, InputMapError> {
+        let data = std::fs::read_to_string(Self::get_path())?;
+        let input_map: Self = ron::de::from_str(&data)?;
+        Ok(input_map)
+    }
+
+    pub fn save(&self) -> Result<(), InputMapError> {
+        let data = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        let path = Self::get_path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+}
+
+// Accessor methods
+impl<A: InputAction> InputMap<A> {
+    /// Returns the inputs bound to an action
+    pub fn get(&self, action: A) -> &[InputBinding] {
+        self.bindings.get(&action).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Binds an input to one of an action's slots, replacing only the binding in that slot. Slots past
+    /// the last binding add the input instead, and inputs already bound to the action stay where they
+    /// are
+    pub fn rebind(&mut self, action: A, slot: usize, binding: InputBinding) {
+        let bindings = self.bindings.entry(action).or_default();
+        if bindings.contains(&binding) {
+            return;
+        }
+        match bindings.get_mut(slot) {
+            Some(existing) => *existing = binding,
+            None => bindings.push(binding),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    use super::InputMap;
+    use crate::utils::input_map::{InputAction, InputBinding};
+
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum TestAction {
+        Jump,
+    }
+
+    impl InputAction for TestAction {
+        const BINDINGS_PATH: &'static str = "./config/test_bindings.ron";
+
+        fn default_bindings() -> Vec<(Self, Vec<InputBinding>)> {
+            vec![(
+                TestAction::Jump,
+                vec![
+                    InputBinding::Key(KeyCode::Space),
+                    InputBinding::Key(KeyCode::KeyJ),
+                    InputBinding::GamepadButton(GamepadButton::South),
+                ],
+            )]
+        }
+    }
+
+    #[test]
+    fn test_rebind_replaces_one_slot() {
+        let mut input_map = InputMap::<TestAction>::default();
+
+        input_map.rebind(TestAction::Jump, 1, InputBinding::Key(KeyCode::KeyK));
+        assert_eq!(
+            input_map.get(TestAction::Jump),
+            [
+                InputBinding::Key(KeyCode::Space),
+                InputBinding::Key(KeyCode::KeyK),
+                InputBinding::GamepadButton(GamepadButton::South),
+            ]
+        );
+
+        // Free slots add a binding, inputs already bound aren't doubled
+        input_map.rebind(TestAction::Jump, 3, InputBinding::Mouse(MouseButton::Right));
+        input_map.rebind(TestAction::Jump, 0, InputBinding::Key(KeyCode::KeyK));
+        assert_eq!(input_map.get(TestAction::Jump).len(), 4);
+        assert_eq!(
+            input_map.get(TestAction::Jump)[0],
+            InputBinding::Key(KeyCode::Space)
+        );
+    }
+}
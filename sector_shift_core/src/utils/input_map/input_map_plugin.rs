@@ -0,0 +1,26 @@
+use std::marker::PhantomData;
+
+use bevy::{input::InputSystems, prelude::*};
+
+use crate::utils::input_map::{ActionState, InputAction, InputMap, systems::update_action_state};
+
+pub struct InputMapPlugin<A> {
+    _phantom: PhantomData<A>,
+}
+
+impl<A> Default for InputMapPlugin<A> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: InputAction> Plugin for InputMapPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(InputMap::<A>::load());
+        app.init_resource::<ActionState<A>>();
+
+        app.add_systems(PreUpdate, update_action_state::<A>.after(InputSystems));
+    }
+}
@@ -0,0 +1,12 @@
+mod action_state;
+mod input_action;
+mod input_binding;
+mod input_map;
+mod input_map_plugin;
+mod systems;
+
+pub use action_state::ActionState;
+pub use input_action::InputAction;
+pub use input_binding::*;
+pub use input_map::*;
+pub use input_map_plugin::InputMapPlugin;
@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+use crate::utils::input_map::{ActionState, InputAction, InputMap};
+
+pub fn update_action_state<A: InputAction>(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+    input_map: Res<InputMap<A>>,
+    mut action_state: ResMut<ActionState<A>>,
+) {
+    let gamepads = &gamepads;
+    action_state.update(input_map.bindings.iter().map(|(action, bindings)| {
+        let value = bindings.iter().map(|binding| binding.value(&keys, &mouse, gamepads)).fold(0.0, f32::max);
+        (*action, value)
+    }));
+}
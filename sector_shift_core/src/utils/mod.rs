@@ -4,6 +4,7 @@ pub mod generic_asset_loader;
 pub mod generic_directory_loader;
 pub mod grid;
 mod helpers;
+pub mod input_map;
 
 mod utils_plugin;
 pub use self::utils_plugin::*;
@@ -0,0 +1,2 @@
+mod object_sprite;
+pub use self::object_sprite::*;
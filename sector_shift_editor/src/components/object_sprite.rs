@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// The sprite shown for an enemy or item placed on the map
+#[derive(Component)]
+pub struct ObjectSprite;
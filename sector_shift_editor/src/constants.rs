@@ -1,2 +1,6 @@
 pub const CELL_SIZE: f32 = 32.0;
 pub const HALF_CELL_SIZE: f32 = CELL_SIZE / 2.0;
+/// The smallest and largest width or height of a map
+pub const MAP_SIZE_RANGE: (u32, u32) = (1, 256);
+/// The smallest and largest scale of the editor camera, below 1 zooms in
+pub const ZOOM_RANGE: (f32, f32) = (0.1, 8.0);
@@ -9,6 +9,28 @@ pub enum BrushType {
     Enemy(String),
     Exit(String),
     Item(String),
+    Env(String),
+    Secret,
+    PatrolTurn(Direction),
 
     EraseObject,
 }
+
+impl BrushType {
+    /// A short description used to label edits in the history
+    pub fn label(&self) -> String {
+        match self {
+            BrushType::PlayerStart(_) => "Move Player Start".to_string(),
+            BrushType::Tile(TileType::Floor) => "Paint Floor".to_string(),
+            BrushType::Tile(TileType::Wall) => "Paint Wall".to_string(),
+            BrushType::Tile(TileType::Door(_)) => "Paint Door".to_string(),
+            BrushType::Enemy(id) => format!("Place Enemy '{id}'"),
+            BrushType::Exit(id) => format!("Place Exit to '{id}'"),
+            BrushType::Item(id) => format!("Place Item '{id}'"),
+            BrushType::Env(id) => format!("Place Decoration '{id}'"),
+            BrushType::Secret => "Place Secret".to_string(),
+            BrushType::PatrolTurn(direction) => format!("Place Patrol Turn {direction}"),
+            BrushType::EraseObject => "Erase Objects".to_string(),
+        }
+    }
+}
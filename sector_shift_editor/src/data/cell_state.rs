@@ -0,0 +1,32 @@
+use sector_shift_core::prelude::*;
+
+/// Everything stored for a single cell of a level
+#[derive(Clone, Debug, PartialEq)]
+pub struct CellState {
+    /// The tile, `None` if the cell is outside the level
+    pub tile: Option<TileType>,
+    pub object: Option<MapObject>,
+}
+
+impl CellState {
+    /// Reads the cell at the given position
+    pub fn read(level: &Level, position: (i32, i32)) -> Self {
+        Self {
+            tile: level.tiles.get(position).copied(),
+            object: level.objects.get(&position).cloned(),
+        }
+    }
+
+    /// Writes this state back into the level
+    pub fn write(&self, level: &mut Level, position: (i32, i32)) {
+        if let Some(tile) = self.tile {
+            level.set_tile(position, tile);
+        }
+        match &self.object {
+            Some(object) => {
+                level.objects.insert(position, object.clone());
+            },
+            None => level.remove_object(position),
+        }
+    }
+}
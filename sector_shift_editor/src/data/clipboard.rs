@@ -0,0 +1,256 @@
+use bevy::platform::collections::HashMap;
+use sector_shift_core::prelude::*;
+
+/// A copied region of a level, positions are relative to its bottom-left cell
+#[derive(Clone)]
+pub struct Clipboard {
+    pub tiles: Grid<TileType>,
+    pub objects: HashMap<(i32, i32), MapObject>,
+}
+
+impl Clipboard {
+    /// Copies the tiles and objects inside the rectangle, cells outside the level are copied as walls
+    pub fn copy(level: &Level, rectangle: Rectangle) -> Self {
+        let size = (rectangle.width() as u32, rectangle.height() as u32);
+        let mut tiles = Grid::new_default(size);
+        Grid::blit(&mut tiles, (0, 0), size, &level.tiles, rectangle.min);
+
+        let objects = level
+            .objects
+            .iter()
+            .filter(|(position, _)| rectangle.contains(**position))
+            .map(|(position, object)| {
+                (
+                    (position.0 - rectangle.min.0, position.1 - rectangle.min.1),
+                    object.clone(),
+                )
+            })
+            .collect();
+
+        Self { tiles, objects }
+    }
+
+    pub fn size(&self) -> (u32, u32) {
+        self.tiles.size()
+    }
+
+    /// The area the clipboard covers when pasted at `origin`
+    pub fn area(&self, origin: (i32, i32)) -> Rectangle {
+        Rectangle::new_with_size(origin, self.size())
+    }
+
+    /// Writes the clipboard into the level, replacing every tile and object in its area
+    pub fn paste(&self, level: &mut Level, origin: (i32, i32)) {
+        let area = self.area(origin);
+        Grid::blit(&mut level.tiles, origin, self.size(), &self.tiles, (0, 0));
+
+        level.objects.retain(|position, _| !area.contains(*position));
+        for (position, object) in &self.objects {
+            let position = (position.0 + origin.0, position.1 + origin.1);
+            if level.tiles.in_bounds(position) {
+                level.objects.insert(position, object.clone());
+            }
+        }
+    }
+
+    /// Rotates the clipboard a quarter turn clockwise
+    pub fn rotate_clockwise(&mut self) {
+        let (width, height) = self.size();
+        // (x, y) moves to (y, width - 1 - x), so the new cell (x, y) came from (width - 1 - y, x)
+        self.tiles = Grid::new_fn((height, width), |_, (x, y)| {
+            match self.tiles.get((width as i32 - 1 - y, x)).copied().unwrap_or_default() {
+                // Doors turn with the map
+                TileType::Door(DoorAxis::Horizontal) => TileType::Door(DoorAxis::Vertical),
+                TileType::Door(DoorAxis::Vertical) => TileType::Door(DoorAxis::Horizontal),
+                tile => tile,
+            }
+        });
+        self.objects = self
+            .objects
+            .drain()
+            .map(|((x, y), object)| ((y, width as i32 - 1 - x), turn(object, Direction::right90)))
+            .collect();
+    }
+
+    /// Mirrors the clipboard left to right
+    pub fn flip_horizontal(&mut self) {
+        let (width, _) = self.size();
+        self.tiles = Grid::new_fn(self.size(), |_, (x, y)| {
+            self.tiles.get((width as i32 - 1 - x, y)).copied().unwrap_or_default()
+        });
+        self.objects = self
+            .objects
+            .drain()
+            .map(|((x, y), object)| {
+                let object = turn(object, |facing| {
+                    Direction::from_coord((-facing.coord().0, facing.coord().1))
+                });
+                ((width as i32 - 1 - x, y), object)
+            })
+            .collect();
+    }
+
+    /// Mirrors the clipboard top to bottom
+    pub fn flip_vertical(&mut self) {
+        let (_, height) = self.size();
+        self.tiles = Grid::new_fn(self.size(), |_, (x, y)| {
+            self.tiles.get((x, height as i32 - 1 - y)).copied().unwrap_or_default()
+        });
+        self.objects = self
+            .objects
+            .drain()
+            .map(|((x, y), object)| {
+                let object = turn(object, |facing| {
+                    Direction::from_coord((facing.coord().0, -facing.coord().1))
+                });
+                ((x, height as i32 - 1 - y), object)
+            })
+            .collect();
+    }
+}
+
+/// Objects facing a direction and patrol turns turn with the map
+fn turn(mut object: MapObject, f: impl Fn(Direction) -> Direction) -> MapObject {
+    if let MapObject::PatrolTurn(direction) = &mut object {
+        *direction = f(*direction);
+    }
+    if let Some(facing) = object.properties_mut().and_then(|properties| properties.facing.as_mut()) {
+        *facing = f(*facing);
+    }
+    object
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::platform::collections::HashMap;
+    use sector_shift_core::prelude::*;
+
+    use super::Clipboard;
+
+    const DOOR: TileType = TileType::Door(DoorAxis::Horizontal);
+
+    /// A 3x2 clipboard with an enemy facing north east in the bottom-left cell and an item in the
+    /// top-right one
+    fn clipboard() -> Clipboard {
+        let enemy = MapObject::Enemy(
+            "grunt".to_string(),
+            ObjectProperties {
+                facing: Some(Direction::NORTH_EAST),
+                ..Default::default()
+            },
+        );
+        let item = MapObject::Item("medkit".to_string(), ObjectProperties::default());
+        Clipboard {
+            tiles: Grid::new(
+                (3, 2),
+                vec![
+                    TileType::Floor,
+                    DOOR,
+                    TileType::Wall,
+                    TileType::Wall,
+                    TileType::Floor,
+                    TileType::Floor,
+                ],
+            ),
+            objects: HashMap::from_iter([((0, 0), enemy), ((2, 1), item)]),
+        }
+    }
+
+    /// The position and facing of every object, sorted by position
+    fn objects(clipboard: &Clipboard) -> Vec<((i32, i32), Option<Direction>)> {
+        let mut objects: Vec<_> = clipboard
+            .objects
+            .iter()
+            .map(|(position, object)| {
+                (
+                    *position,
+                    object.properties().and_then(|properties| properties.facing),
+                )
+            })
+            .collect();
+        objects.sort_by_key(|(position, _)| *position);
+        objects
+    }
+
+    #[test]
+    fn test_clipboard_rotate() {
+        let mut clipboard = clipboard();
+        clipboard.rotate_clockwise();
+
+        assert_eq!(clipboard.size(), (2, 3));
+        assert_eq!(
+            clipboard.tiles.data(),
+            &vec![
+                TileType::Wall,
+                TileType::Floor,
+                TileType::Door(DoorAxis::Vertical),
+                TileType::Floor,
+                TileType::Floor,
+                TileType::Wall,
+            ]
+        );
+        assert_eq!(
+            objects(&clipboard),
+            vec![((0, 2), Some(Direction::SOUTH_EAST)), ((1, 0), None)]
+        );
+
+        // A full turn puts everything back
+        for _ in 0..3 {
+            clipboard.rotate_clockwise();
+        }
+        assert_eq!(clipboard.tiles.data(), self::clipboard().tiles.data());
+        assert_eq!(objects(&clipboard), objects(&self::clipboard()));
+    }
+
+    #[test]
+    fn test_clipboard_rotate_patrol_turn() {
+        let mut clipboard = clipboard();
+        clipboard.objects.insert((1, 0), MapObject::PatrolTurn(Direction::EAST));
+        clipboard.rotate_clockwise();
+
+        // (1, 0) moves to (0, 1) and the route now heads south
+        assert_eq!(
+            clipboard.objects.get(&(0, 1)),
+            Some(&MapObject::PatrolTurn(Direction::SOUTH))
+        );
+    }
+
+    #[test]
+    fn test_clipboard_flip() {
+        let mut horizontal = clipboard();
+        horizontal.flip_horizontal();
+        assert_eq!(
+            horizontal.tiles.data(),
+            &vec![
+                TileType::Wall,
+                DOOR,
+                TileType::Floor,
+                TileType::Floor,
+                TileType::Floor,
+                TileType::Wall
+            ]
+        );
+        assert_eq!(
+            objects(&horizontal),
+            vec![((0, 1), None), ((2, 0), Some(Direction::NORTH_WEST))]
+        );
+
+        let mut vertical = clipboard();
+        vertical.flip_vertical();
+        assert_eq!(
+            vertical.tiles.data(),
+            &vec![
+                TileType::Wall,
+                TileType::Floor,
+                TileType::Floor,
+                TileType::Floor,
+                DOOR,
+                TileType::Wall
+            ]
+        );
+        assert_eq!(
+            objects(&vertical),
+            vec![((0, 1), Some(Direction::SOUTH_EAST)), ((2, 0), None)]
+        );
+    }
+}
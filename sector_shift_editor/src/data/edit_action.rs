@@ -0,0 +1,96 @@
+use sector_shift_core::prelude::*;
+
+use crate::data::EditCommand;
+
+/// A group of commands undone and redone together, such as one mouse stroke
+#[derive(Clone)]
+pub struct EditAction {
+    /// Shown in the history panel
+    pub label: String,
+    pub commands: Vec<EditCommand>,
+    /// Assigned by the history when the action is pushed, used to tell whether the level was saved after it
+    pub id: u64,
+}
+
+impl EditAction {
+    pub fn new(label: impl ToString) -> Self {
+        Self {
+            label: label.to_string(),
+            commands: Vec::new(),
+            id: 0,
+        }
+    }
+
+    /// Adds a command, folding repeated changes of the same cell, player start or par time into one
+    pub fn push(&mut self, command: EditCommand) {
+        for existing in self.commands.iter_mut().rev() {
+            match (existing, &command) {
+                (
+                    EditCommand::Cell {
+                        position, after, ..
+                    },
+                    EditCommand::Cell {
+                        position: new_position,
+                        after: new_after,
+                        ..
+                    },
+                ) if position == new_position => {
+                    *after = new_after.clone();
+                    return;
+                },
+                (
+                    EditCommand::PlayerStart { after, .. },
+                    EditCommand::PlayerStart {
+                        after: new_after, ..
+                    },
+                ) => {
+                    *after = *new_after;
+                    return;
+                },
+                (
+                    EditCommand::ParTime { after, .. },
+                    EditCommand::ParTime {
+                        after: new_after, ..
+                    },
+                ) => {
+                    *after = *new_after;
+                    return;
+                },
+                (
+                    EditCommand::Music { after, .. },
+                    EditCommand::Music {
+                        after: new_after, ..
+                    },
+                ) => {
+                    *after = new_after.clone();
+                    return;
+                },
+                // Earlier commands were overwritten by the level replacement
+                (EditCommand::Level { .. }, _) => break,
+                _ => {},
+            }
+        }
+        self.commands.push(command);
+    }
+
+    /// Drops commands that ended up changing nothing
+    pub fn prune(&mut self) {
+        self.commands.retain(|command| !command.is_noop());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    pub fn apply(&self, level: &mut Level) {
+        for command in &self.commands {
+            command.apply(level);
+        }
+    }
+
+    pub fn revert(&self, level: &mut Level) {
+        for command in self.commands.iter().rev() {
+            command.revert(level);
+        }
+    }
+}
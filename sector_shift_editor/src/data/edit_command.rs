@@ -0,0 +1,73 @@
+use sector_shift_core::prelude::*;
+
+use crate::data::CellState;
+
+/// A single reversible change to a level
+#[derive(Clone)]
+pub enum EditCommand {
+    /// A cell changed its tile and/or object
+    Cell {
+        position: (i32, i32),
+        before: CellState,
+        after: CellState,
+    },
+    /// The player start moved or turned
+    PlayerStart {
+        before: ((i32, i32), Direction),
+        after: ((i32, i32), Direction),
+    },
+    /// The par time was changed
+    ParTime {
+        before: Option<f32>,
+        after: Option<f32>,
+    },
+    /// The music track was changed
+    Music {
+        before: Option<String>,
+        after: Option<String>,
+    },
+    /// The whole level was replaced, used for edits touching large parts of it such as resizes
+    Level {
+        before: Box<Level>,
+        after: Box<Level>,
+    },
+}
+
+impl EditCommand {
+    /// Applies the change to the level
+    pub fn apply(&self, level: &mut Level) {
+        match self {
+            EditCommand::Cell {
+                position, after, ..
+            } => after.write(level, *position),
+            EditCommand::PlayerStart { after, .. } => level.player_start = *after,
+            EditCommand::ParTime { after, .. } => level.par_time = *after,
+            EditCommand::Music { after, .. } => level.music = after.clone(),
+            EditCommand::Level { after, .. } => *level = after.as_ref().clone(),
+        }
+    }
+
+    /// Whether applying the command leaves the level as it was
+    pub fn is_noop(&self) -> bool {
+        match self {
+            EditCommand::Cell { before, after, .. } => before == after,
+            EditCommand::PlayerStart { before, after } => before == after,
+            EditCommand::ParTime { before, after } => before == after,
+            EditCommand::Music { before, after } => before == after,
+            EditCommand::Level { .. } => false,
+        }
+    }
+
+    /// Reverts the change made to the level
+    pub fn revert(&self, level: &mut Level) {
+        match self {
+            EditCommand::Cell {
+                position, before, ..
+            } => before.write(level, *position),
+            EditCommand::PlayerStart { before, .. } => level.player_start = *before,
+            EditCommand::ParTime { before, .. } => level.par_time = *before,
+            EditCommand::Music { before, .. } => level.music = before.clone(),
+            EditCommand::Level { before, .. } => *level = before.as_ref().clone(),
+        }
+    }
+}
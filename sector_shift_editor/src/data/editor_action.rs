@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Editor camera controls, bound to physical inputs through the [`InputMap`]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EditorAction {
+    PanUp,
+    PanDown,
+    PanLeft,
+    PanRight,
+    ZoomIn,
+    ZoomOut,
+    /// Shows the whole map
+    FitMap,
+}
+
+impl InputAction for EditorAction {
+    const BINDINGS_PATH: &'static str = "./config/editor_bindings.ron";
+
+    fn default_bindings() -> Vec<(Self, Vec<InputBinding>)> {
+        use AxisDirection::{Negative, Positive};
+        use InputBinding::{GamepadAxis as Axis, GamepadButton as Button, Key};
+
+        vec![
+            (
+                EditorAction::PanUp,
+                vec![Key(KeyCode::KeyW), Axis(GamepadAxis::LeftStickY, Positive)],
+            ),
+            (
+                EditorAction::PanDown,
+                vec![Key(KeyCode::KeyS), Axis(GamepadAxis::LeftStickY, Negative)],
+            ),
+            (
+                EditorAction::PanLeft,
+                vec![Key(KeyCode::KeyA), Axis(GamepadAxis::LeftStickX, Negative)],
+            ),
+            (
+                EditorAction::PanRight,
+                vec![Key(KeyCode::KeyD), Axis(GamepadAxis::LeftStickX, Positive)],
+            ),
+            (
+                EditorAction::ZoomIn,
+                vec![
+                    Key(KeyCode::Equal),
+                    Key(KeyCode::NumpadAdd),
+                    Button(GamepadButton::RightTrigger),
+                ],
+            ),
+            (
+                EditorAction::ZoomOut,
+                vec![
+                    Key(KeyCode::Minus),
+                    Key(KeyCode::NumpadSubtract),
+                    Button(GamepadButton::LeftTrigger),
+                ],
+            ),
+            (
+                EditorAction::FitMap,
+                vec![Key(KeyCode::KeyF), Button(GamepadButton::North)],
+            ),
+        ]
+    }
+}
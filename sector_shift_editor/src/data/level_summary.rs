@@ -0,0 +1,17 @@
+use sector_shift_core::prelude::*;
+
+/// What the level browser shows about a level file
+pub struct LevelSummary {
+    pub id: String,
+    /// The size and number of objects, or why the file could not be read
+    pub contents: Result<((u32, u32), usize), String>,
+}
+
+impl LevelSummary {
+    pub fn read(id: String) -> Self {
+        let contents = Level::try_load(&id)
+            .map(|level| ((level.width(), level.height()), level.objects.len()))
+            .map_err(|e| e.to_string());
+        Self { id, contents }
+    }
+}
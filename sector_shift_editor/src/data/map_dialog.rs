@@ -0,0 +1,20 @@
+use sector_shift_core::prelude::*;
+
+use crate::data::ResizeAnchor;
+
+/// A dialog changing the whole map, only one is open at a time
+pub enum MapDialog {
+    /// Replaces the map with an empty one
+    New {
+        id: String,
+        size: (u32, u32),
+        fill: TileType,
+    },
+    /// Grows or shrinks the map around the anchor
+    Resize {
+        size: (u32, u32),
+        anchor: ResizeAnchor,
+        /// What the resize drops, shown for confirmation before applying
+        losses: Option<Vec<String>>,
+    },
+}
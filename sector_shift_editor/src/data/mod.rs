@@ -1,2 +1,29 @@
 mod brush_type;
 pub use self::brush_type::*;
+
+mod cell_state;
+pub use self::cell_state::*;
+
+mod clipboard;
+pub use self::clipboard::*;
+
+mod edit_action;
+pub use self::edit_action::*;
+
+mod edit_command;
+pub use self::edit_command::*;
+
+mod editor_action;
+pub use self::editor_action::*;
+
+mod level_summary;
+pub use self::level_summary::*;
+
+mod map_dialog;
+pub use self::map_dialog::*;
+
+mod resize_anchor;
+pub use self::resize_anchor::*;
+
+mod tool_type;
+pub use self::tool_type::*;
@@ -0,0 +1,62 @@
+/// The side of the map that stays in place when it is resized
+///
+/// Both axes go from -1 to 1: left/bottom, center, right/top.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ResizeAnchor {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl ResizeAnchor {
+    /// Where the old map's bottom-left cell ends up in the resized map
+    pub fn offset(self, old_size: (u32, u32), new_size: (u32, u32)) -> (i32, i32) {
+        let axis = |anchor: i32, old: u32, new: u32| {
+            let difference = new as i32 - old as i32;
+            match anchor {
+                ..0 => 0,
+                0 => difference / 2,
+                1.. => difference,
+            }
+        };
+        (
+            axis(self.x, old_size.0, new_size.0),
+            axis(self.y, old_size.1, new_size.1),
+        )
+    }
+
+    /// An arrow pointing towards the anchored side
+    pub fn symbol(self) -> &'static str {
+        match (self.x, self.y) {
+            (..0, 1..) => "↖",
+            (0, 1..) => "↑",
+            (1.., 1..) => "↗",
+            (..0, 0) => "←",
+            (0, 0) => "•",
+            (1.., 0) => "→",
+            (..0, ..0) => "↙",
+            (0, ..0) => "↓",
+            (1.., ..0) => "↘",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResizeAnchor;
+
+    #[test]
+    fn test_resize_anchor_offset() {
+        let bottom_left = ResizeAnchor { x: -1, y: -1 };
+        let center = ResizeAnchor::default();
+        let top_right = ResizeAnchor { x: 1, y: 1 };
+
+        assert_eq!(bottom_left.offset((4, 4), (8, 6)), (0, 0));
+        assert_eq!(center.offset((4, 4), (8, 6)), (2, 1));
+        assert_eq!(top_right.offset((4, 4), (8, 6)), (4, 2));
+
+        // Shrinking moves the map the other way
+        assert_eq!(center.offset((8, 8), (4, 4)), (-2, -2));
+        assert_eq!(top_right.offset((8, 8), (4, 4)), (-4, -4));
+        assert_eq!(ResizeAnchor { x: 1, y: -1 }.offset((8, 8), (4, 4)), (-4, 0));
+    }
+}
@@ -0,0 +1,68 @@
+use sector_shift_core::prelude::*;
+
+/// How the brush is applied to the map
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ToolType {
+    /// Paints every cell the cursor passes over
+    #[default]
+    Paint,
+    /// Drag from one cell to another
+    Line,
+    /// Drag between two corners, only the border is painted
+    Rectangle,
+    /// Drag between two corners, every cell inside is painted
+    FilledRectangle,
+    /// Drag from the center outwards, only the ring is painted
+    Circle,
+    /// Replaces the contiguous area of the clicked tile type
+    Fill,
+    /// Drag to select a region for the clipboard
+    Select,
+}
+
+impl ToolType {
+    pub const ALL: [Self; 7] = [
+        Self::Paint,
+        Self::Line,
+        Self::Rectangle,
+        Self::FilledRectangle,
+        Self::Circle,
+        Self::Fill,
+        Self::Select,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ToolType::Paint => "Paint",
+            ToolType::Line => "Line",
+            ToolType::Rectangle => "Rectangle",
+            ToolType::FilledRectangle => "Filled Rect",
+            ToolType::Circle => "Circle",
+            ToolType::Fill => "Fill",
+            ToolType::Select => "Select",
+        }
+    }
+
+    /// The cells covered by dragging from `start` to `end`, empty for tools that aren't shapes
+    pub fn shape_cells(self, start: (i32, i32), end: (i32, i32)) -> Vec<(i32, i32)> {
+        // Rectangles exclude their max corner, the cell under the cursor should be included
+        let rectangle = || {
+            Rectangle::new(
+                (start.0.min(end.0), start.1.min(end.1)),
+                (start.0.max(end.0) + 1, start.1.max(end.1) + 1),
+            )
+        };
+        match self {
+            ToolType::Line => Line::new(start, end).iter().collect(),
+            ToolType::Rectangle => rectangle().iter_border().collect(),
+            ToolType::FilledRectangle => rectangle().iter().collect(),
+            ToolType::Circle => {
+                let dx = (end.0 - start.0) as f32;
+                let dy = (end.1 - start.1) as f32;
+                let radius = (dx * dx + dy * dy).sqrt().round() as u32;
+                if radius == 0 { vec![start] } else { Circle::new(start, radius).iter_border().collect() }
+            },
+            ToolType::Paint | ToolType::Fill | ToolType::Select => Vec::new(),
+        }
+    }
+}
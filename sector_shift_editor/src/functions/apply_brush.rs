@@ -0,0 +1,41 @@
+use sector_shift_core::prelude::*;
+
+use crate::{
+    data::{BrushType, CellState, EditCommand},
+    resources::{BrushData, EditHistory},
+};
+
+/// Applies the brush to a single cell and records the change in the history
+pub fn apply_brush(
+    level: &mut Level,
+    history: &mut EditHistory,
+    brush_data: &BrushData,
+    position: (i32, i32),
+) {
+    let cell_before = CellState::read(level, position);
+    let player_start_before = level.player_start;
+
+    match &brush_data.brush {
+        BrushType::PlayerStart(direction) => level.set_player_start(position, *direction),
+        BrushType::Tile(tile_type) => {
+            level.set_tile(position, *tile_type);
+        },
+        BrushType::Enemy(id) => level.add_enemy(position, id, brush_data.properties.clone()),
+        BrushType::Exit(id) => level.add_exit(position, id),
+        BrushType::Item(id) => level.add_item(position, id, brush_data.properties.clone()),
+        BrushType::Env(id) => level.add_env(position, id, brush_data.properties.clone()),
+        BrushType::Secret => level.add_secret(position),
+        BrushType::PatrolTurn(direction) => level.add_patrol_turn(position, *direction),
+        BrushType::EraseObject => level.remove_object(position),
+    }
+
+    history.record(EditCommand::Cell {
+        position,
+        before: cell_before,
+        after: CellState::read(level, position),
+    });
+    history.record(EditCommand::PlayerStart {
+        before: player_start_before,
+        after: level.player_start,
+    });
+}
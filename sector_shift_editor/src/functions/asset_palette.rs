@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiTextureHandle, egui};
+
+/// An entry of an [`asset_palette`], the thumbnail is `None` for assets without a sprite such as decorations
+pub struct PaletteEntry {
+    pub id: String,
+    pub thumbnail: Option<egui::TextureId>,
+}
+
+/// Lists the entries as buttons with their thumbnail, returns the id of the clicked entry
+pub fn asset_palette(ui: &mut egui::Ui, entries: &[PaletteEntry], selected: &str) -> Option<String> {
+    const THUMBNAIL_SIZE: f32 = 32.0;
+
+    if entries.is_empty() {
+        ui.weak("Nothing loaded");
+        return None;
+    }
+
+    let mut clicked = None;
+    ui.horizontal_wrapped(|ui| {
+        for entry in entries {
+            let button = match entry.thumbnail {
+                Some(texture) => egui::Button::image_and_text(
+                    egui::load::SizedTexture::new(texture, [THUMBNAIL_SIZE, THUMBNAIL_SIZE]),
+                    &entry.id,
+                ),
+                None => egui::Button::new(&entry.id),
+            };
+            if ui.add(button.selected(entry.id == selected)).clicked() {
+                clicked = Some(entry.id.clone());
+            }
+        }
+    });
+    clicked
+}
+
+/// Registers the sprites with egui and returns palette entries sorted by id
+pub fn palette_entries<'a>(
+    contexts: &mut EguiContexts,
+    sprites: impl Iterator<Item = (&'a String, &'a Handle<Image>)>,
+) -> Vec<PaletteEntry> {
+    let mut entries: Vec<_> = sprites
+        .map(|(id, sprite)| PaletteEntry {
+            id: id.clone(),
+            // The library keeps the image alive
+            thumbnail: Some(contexts.add_image(EguiTextureHandle::Weak(sprite.id()))),
+        })
+        .collect();
+    entries.sort_by(|a, b| a.id.cmp(&b.id));
+    entries
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use crate::CELL_SIZE;
+
+/// The map cell under the cursor, if the cursor is inside the window
+pub fn cursor_cell(
+    window: &Window,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+) -> Option<(i32, i32)> {
+    let world_position = window
+        .cursor_position()
+        .and_then(|cursor_pos| camera.viewport_to_world_2d(camera_transform, cursor_pos).ok())?;
+
+    Some((
+        (world_position.x / CELL_SIZE).floor() as i32,
+        (world_position.y / CELL_SIZE).floor() as i32,
+    ))
+}
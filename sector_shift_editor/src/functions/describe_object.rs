@@ -0,0 +1,13 @@
+use sector_shift_core::prelude::*;
+
+/// A short text naming a placed object, e.g. "Enemy: slime"
+pub fn describe_object(object: &MapObject) -> String {
+    match object {
+        MapObject::Exit(level_id) => format!("Exit to {level_id}"),
+        MapObject::Enemy(id, _) => format!("Enemy: {id}"),
+        MapObject::Item(id, _) => format!("Item: {id}"),
+        MapObject::Env(id, _) => format!("Decoration: {id}"),
+        MapObject::Secret => "Secret".to_string(),
+        MapObject::PatrolTurn(direction) => format!("Patrol Turn: {direction}"),
+    }
+}
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{CELL_SIZE, ZOOM_RANGE};
+
+/// Room left around the map when it is fitted to the view
+const FIT_MARGIN: f32 = 1.1;
+
+/// Centers the camera on the level and zooms so all of it fits in a viewport of the given size
+pub fn fit_camera(
+    level: &Level,
+    viewport_size: Vec2,
+    transform: &mut Transform,
+    projection: &mut OrthographicProjection,
+) {
+    let map_size = Vec2::new(level.width() as f32, level.height() as f32) * CELL_SIZE;
+    transform.translation.x = map_size.x / 2.0;
+    transform.translation.y = map_size.y / 2.0;
+
+    if viewport_size.x > 0.0 && viewport_size.y > 0.0 {
+        let scale = (map_size / viewport_size).max_element() * FIT_MARGIN;
+        projection.scale = scale.clamp(ZOOM_RANGE.0, ZOOM_RANGE.1);
+    }
+}
@@ -0,0 +1,41 @@
+use bevy::platform::collections::HashSet;
+use sector_shift_core::prelude::*;
+
+/// Every cell connected to `start` through its four neighbours that has the same tile type as `start`.
+///
+/// Returns nothing if `start` is outside the grid.
+pub fn flood_fill(tiles: &Grid<TileType>, start: (i32, i32)) -> Vec<(i32, i32)> {
+    let Some(&target) = tiles.get(start) else {
+        return Vec::new();
+    };
+
+    tiles.flood(start, &mut HashSet::new(), |tile| *tile == target)
+}
+
+#[cfg(test)]
+mod tests {
+    use sector_shift_core::prelude::*;
+
+    use super::flood_fill;
+
+    #[test]
+    fn test_flood_fill() {
+        // A 4x3 level with an L of floor ending at a door, and one more floor cell in a corner
+        let mut level = Level::new("test", (4, 3));
+        for position in [(0, 0), (1, 0), (2, 0), (0, 1), (3, 2)] {
+            level.set_tile(position, TileType::Floor);
+        }
+        level.set_tile((3, 0), TileType::Door(DoorAxis::Vertical));
+
+        let mut floor = flood_fill(&level.tiles, (1, 0));
+        floor.sort();
+        assert_eq!(floor, vec![(0, 0), (0, 1), (1, 0), (2, 0)]);
+
+        let mut walls = flood_fill(&level.tiles, (3, 1));
+        walls.sort();
+        assert_eq!(walls, vec![(0, 2), (1, 1), (1, 2), (2, 1), (2, 2), (3, 1)]);
+        // The floor in the corner is walled in
+        assert_eq!(flood_fill(&level.tiles, (3, 2)), vec![(3, 2)]);
+        assert_eq!(flood_fill(&level.tiles, (4, 0)), Vec::new());
+    }
+}
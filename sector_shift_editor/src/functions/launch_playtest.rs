@@ -0,0 +1,46 @@
+use std::process::{Child, Command};
+
+use sector_shift_core::{maps::LevelError, prelude::*};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PlaytestError {
+    #[error("Could not save the playtest level: {0}")]
+    Save(#[from] LevelError),
+    #[error("Could not start the game: {0}")]
+    Launch(#[from] std::io::Error),
+}
+
+/// Saves a copy of the level as [`Level::PLAYTEST_ID`] and starts the game on it.
+///
+/// `start` replaces the player start of the copy. The game binary next to the editor is used if there is one,
+/// otherwise it is built and run with cargo.
+pub fn launch_playtest(
+    level: &Level,
+    start: Option<((i32, i32), Direction)>,
+) -> Result<Child, PlaytestError> {
+    let mut playtest = level.clone();
+    playtest.id = Level::PLAYTEST_ID.to_string();
+    playtest.save()?;
+
+    let mut args = vec!["--level".to_string(), playtest.id, "--playtest".to_string()];
+    if let Some(((x, y), direction)) = start {
+        args.extend(["--start".to_string(), format!("{x},{y}")]);
+        let facing = match direction {
+            Direction::SOUTH => "south",
+            Direction::EAST => "east",
+            Direction::WEST => "west",
+            _ => "north",
+        };
+        args.extend(["--facing".to_string(), facing.to_string()]);
+    }
+
+    let game =
+        std::env::current_exe()?.with_file_name(format!("sector_shift_game{}", std::env::consts::EXE_SUFFIX));
+    let child = if game.exists() {
+        Command::new(game).args(args).spawn()?
+    } else {
+        Command::new("cargo").args(["run", "-p", "sector_shift_game", "--"]).args(args).spawn()?
+    };
+    Ok(child)
+}
@@ -0,0 +1,29 @@
+mod apply_brush;
+pub use self::apply_brush::*;
+
+mod asset_palette;
+pub use self::asset_palette::*;
+
+mod cursor_cell;
+pub use self::cursor_cell::*;
+
+mod describe_object;
+pub use self::describe_object::*;
+
+mod fit_camera;
+pub use self::fit_camera::*;
+
+mod flood_fill;
+pub use self::flood_fill::*;
+
+mod launch_playtest;
+pub use self::launch_playtest::*;
+
+mod object_sprite;
+pub use self::object_sprite::*;
+
+mod resize_level;
+pub use self::resize_level::*;
+
+mod zoom_camera;
+pub use self::zoom_camera::*;
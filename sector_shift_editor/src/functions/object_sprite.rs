@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+/// The sprite of an object, `None` if it has none or its id isn't in the library
+pub fn object_sprite(
+    object: &MapObject,
+    enemy_library: &EnemyLibrary,
+    item_library: &ItemLibrary,
+) -> Option<Handle<Image>> {
+    match object {
+        MapObject::Enemy(id, _) => enemy_library.get(id).map(|enemy| enemy.sprite.clone()),
+        MapObject::Item(id, _) => item_library.get(id).map(|item| item.sprite.clone()),
+        MapObject::Exit(_) | MapObject::Secret | MapObject::Env(..) | MapObject::PatrolTurn(_) => None,
+    }
+}
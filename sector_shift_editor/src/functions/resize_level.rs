@@ -0,0 +1,140 @@
+use sector_shift_core::prelude::*;
+
+use crate::data::ResizeAnchor;
+
+/// A resized copy of a level and what did not fit into it
+pub struct ResizedLevel {
+    pub level: Level,
+    /// Tiles other than walls that ended up outside the map
+    pub lost_tiles: usize,
+    /// Objects that ended up outside the map, at their old position
+    pub lost_objects: Vec<((i32, i32), MapObject)>,
+    /// Whether the player start had to be moved back inside the map
+    pub player_start_moved: bool,
+}
+
+impl ResizedLevel {
+    /// Describes everything the resize drops, empty if nothing is lost
+    pub fn losses(&self) -> Vec<String> {
+        let mut losses = Vec::new();
+        if self.lost_tiles > 0 {
+            losses.push(format!("{} floor or door tiles", self.lost_tiles));
+        }
+        for (position, object) in &self.lost_objects {
+            losses.push(format!("{object:?} at {position:?}"));
+        }
+        if self.player_start_moved {
+            losses.push(format!(
+                "The player start, moved to {:?}",
+                self.level.player_start.0
+            ));
+        }
+        losses
+    }
+}
+
+/// Copies the level into a map of the new size, keeping the anchored side in place
+pub fn resize_level(level: &Level, size: (u32, u32), anchor: ResizeAnchor) -> ResizedLevel {
+    let old_size = level.tiles.size();
+    let offset = anchor.offset(old_size, size);
+    let shift = |position: (i32, i32)| (position.0 + offset.0, position.1 + offset.1);
+
+    // Everything that doesn't depend on the size carries over
+    let mut resized = Level {
+        tiles: Grid::new_default(size),
+        objects: Default::default(),
+        ..level.clone()
+    };
+    Grid::blit(&mut resized.tiles, offset, old_size, &level.tiles, (0, 0));
+
+    let lost_tiles = level
+        .tiles
+        .enumerate()
+        .filter(|(position, tile)| **tile != TileType::Wall && !resized.tiles.in_bounds(shift(*position)))
+        .count();
+
+    let mut lost_objects = Vec::new();
+    for (position, object) in level.objects.iter() {
+        if resized.tiles.in_bounds(shift(*position)) {
+            resized.objects.insert(shift(*position), object.clone());
+        } else {
+            lost_objects.push((*position, object.clone()));
+        }
+    }
+    lost_objects.sort_by_key(|(position, _)| *position);
+
+    let (start, direction) = level.player_start;
+    let start = shift(start);
+    let player_start_moved = !resized.tiles.in_bounds(start);
+    let start = (
+        start.0.clamp(0, size.0 as i32 - 1),
+        start.1.clamp(0, size.1 as i32 - 1),
+    );
+    if player_start_moved {
+        // Clears the way for the player
+        resized.set_player_start(start, direction);
+    } else {
+        resized.player_start = (start, direction);
+    }
+
+    ResizedLevel {
+        level: resized,
+        lost_tiles,
+        lost_objects,
+        player_start_moved,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sector_shift_core::prelude::*;
+
+    use super::resize_level;
+    use crate::data::ResizeAnchor;
+
+    #[test]
+    fn test_resize_keeps_level_settings() {
+        let mut level = Level::new("test", (4, 4));
+        level.par_time = Some(90.0);
+        level.music = Some("music_e1".to_string());
+
+        let resized = resize_level(&level, (6, 3), ResizeAnchor::default()).level;
+        assert_eq!(resized.id, "test");
+        assert_eq!(resized.par_time, Some(90.0));
+        assert_eq!(resized.music.as_deref(), Some("music_e1"));
+        assert_eq!(resized.tiles.size(), (6, 3));
+    }
+
+    #[test]
+    fn test_resize_losses() {
+        let mut level = Level::new("test", (4, 4));
+        for position in [(0, 0), (1, 1), (3, 3)] {
+            level.set_tile(position, TileType::Floor);
+        }
+        level.add_enemy((3, 3), "slime", ObjectProperties::default());
+        level.set_player_start((2, 3), Direction::NORTH);
+
+        // Shrinking towards the bottom left cuts off the top row and the right column
+        let resized = resize_level(&level, (3, 3), ResizeAnchor { x: -1, y: -1 });
+        assert_eq!(resized.lost_tiles, 2);
+        assert_eq!(resized.lost_objects.len(), 1);
+        assert_eq!(resized.lost_objects[0].0, (3, 3));
+        assert!(resized.player_start_moved);
+        assert_eq!(resized.level.player_start.0, (2, 2));
+        assert_eq!(resized.losses().len(), 3);
+
+        // Shrinking towards the top right only loses the bottom left floor
+        let resized = resize_level(&level, (3, 3), ResizeAnchor { x: 1, y: 1 });
+        assert_eq!(resized.lost_tiles, 1);
+        assert!(resized.lost_objects.is_empty());
+        assert!(resized.level.objects.contains_key(&(2, 2)));
+        assert!(!resized.player_start_moved);
+        assert_eq!(resized.level.player_start.0, (1, 2));
+        assert_eq!(resized.level.tiles.get((0, 0)), Some(&TileType::Floor));
+
+        // Growing never loses anything
+        let resized = resize_level(&level, (6, 6), ResizeAnchor::default());
+        assert!(resized.losses().is_empty());
+        assert_eq!(resized.level.player_start.0, (3, 4));
+    }
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::ZOOM_RANGE;
+
+/// Moves the camera so the world point under the cursor stays put while the scale changes
+pub fn zoom_camera(
+    anchor: Option<Vec2>,
+    scale: f32,
+    transform: &mut Transform,
+    projection: &mut OrthographicProjection,
+) {
+    let scale = scale.clamp(ZOOM_RANGE.0, ZOOM_RANGE.1);
+    if let Some(anchor) = anchor {
+        let camera = transform.translation.truncate();
+        let offset = (camera - anchor) * (scale / projection.scale);
+        transform.translation.x = anchor.x + offset.x;
+        transform.translation.y = anchor.y + offset.y;
+    }
+    projection.scale = scale;
+}
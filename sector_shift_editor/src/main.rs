@@ -1,14 +1,28 @@
 use bevy::prelude::*;
 use bevy_egui::EguiPlugin;
-use sector_shift_core::{SectorShiftCorePlugin, utils::asset_plugin_with_fixed_path};
+use sector_shift_core::{
+    SectorShiftCorePlugin,
+    utils::{asset_plugin_with_fixed_path, input_map::InputMapPlugin},
+};
 
 use crate::{
-    resources::{BrushData, MapData, UiState},
+    data::EditorAction,
+    resources::{
+        BrushData, EditHistory, LevelBrowser, LevelTabs, MapData, Playtest, Problems, Selection, UiState,
+    },
     states::{states::AppState, system_sets::AppSet},
-    systems::{draw_grid, draw_ui, handle_keyboard_input, handle_mouse_input, spawn_camera},
+    systems::{
+        draw_grid, draw_inspector, draw_level_tabs, draw_map_dialog, draw_patrol_routes, draw_problems,
+        draw_selection, draw_status_line, draw_tool_preview, draw_ui, handle_camera_mouse_input,
+        handle_clipboard_shortcuts, handle_close_requests, handle_history_shortcuts, handle_keyboard_input,
+        handle_mouse_input, handle_playtest_shortcuts, handle_selection_input, spawn_camera,
+        sync_object_sprites, validate_level,
+    },
 };
 
+pub mod components;
 pub mod data;
+pub mod functions;
 pub mod resources;
 pub mod states;
 pub mod systems;
@@ -26,6 +40,8 @@ fn main() {
                     title: "SectorShift".to_string(),
                     ..Default::default()
                 }),
+                // Unsaved levels are handled by handle_close_requests
+                close_when_requested: false,
                 ..Default::default()
             })
             .set(asset_plugin_with_fixed_path()),
@@ -37,16 +53,51 @@ fn main() {
 
     app.add_plugins(SectorShiftCorePlugin::new(AppState::LoadAssets));
 
+    app.add_plugins(InputMapPlugin::<EditorAction>::default());
+
     app.init_resource::<BrushData>();
+    app.init_resource::<EditHistory>();
+    app.init_resource::<LevelBrowser>();
+    app.init_resource::<LevelTabs>();
     app.init_resource::<MapData>();
+    app.init_resource::<Playtest>();
+    app.init_resource::<Problems>();
+    app.init_resource::<Selection>();
     app.init_resource::<UiState>();
 
     app.add_systems(Startup, spawn_camera);
     app.add_systems(
         Update,
-        (draw_grid, handle_keyboard_input, handle_mouse_input).in_set(AppSet::Running),
+        (
+            draw_grid,
+            draw_patrol_routes,
+            draw_selection,
+            draw_tool_preview,
+            handle_keyboard_input,
+            handle_camera_mouse_input,
+            // Placing a paste must not also paint the clicked cell
+            (handle_mouse_input, handle_selection_input).chain(),
+            handle_history_shortcuts,
+            handle_clipboard_shortcuts,
+            handle_playtest_shortcuts,
+            sync_object_sprites,
+            validate_level,
+        )
+            .in_set(AppSet::Running),
+    );
+    app.add_systems(Update, handle_close_requests);
+    app.add_systems(
+        bevy_egui::EguiPrimaryContextPass,
+        (
+            draw_level_tabs,
+            draw_status_line,
+            draw_ui,
+            draw_inspector,
+            draw_map_dialog,
+            draw_problems,
+        )
+            .chain(),
     );
-    app.add_systems(bevy_egui::EguiPrimaryContextPass, draw_ui);
 
     app.run();
 }
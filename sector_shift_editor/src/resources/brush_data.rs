@@ -1,17 +1,37 @@
 use bevy::prelude::*;
-use sector_shift_core::prelude::TileType;
+use sector_shift_core::prelude::{ObjectProperties, TileType};
 
-use crate::data::BrushType;
+use crate::data::{BrushType, ToolType};
 
 #[derive(Resource)]
 pub struct BrushData {
     pub brush: BrushType,
+    /// Properties given to newly placed enemies, items and decorations
+    pub properties: ObjectProperties,
+    /// How the brush is applied
+    pub tool: ToolType,
+    /// The cells a shape tool is being dragged between
+    pub drag: Option<((i32, i32), (i32, i32))>,
+    /// Whether the held left button started a paint stroke, presses that placed a paste or began
+    /// over the UI don't paint until released
+    pub painting: bool,
 }
 
 impl Default for BrushData {
     fn default() -> Self {
         BrushData {
             brush: BrushType::Tile(TileType::Floor),
+            properties: ObjectProperties::default(),
+            tool: ToolType::default(),
+            drag: None,
+            painting: false,
         }
     }
 }
+
+impl BrushData {
+    /// The cells the current shape drag would paint
+    pub fn drag_cells(&self) -> Vec<(i32, i32)> {
+        self.drag.map(|(start, end)| self.tool.shape_cells(start, end)).unwrap_or_default()
+    }
+}
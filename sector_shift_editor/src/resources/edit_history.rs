@@ -0,0 +1,203 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::data::{EditAction, EditCommand};
+
+/// Undo and redo stacks for the level being edited
+#[derive(Resource)]
+pub struct EditHistory {
+    undo_stack: VecDeque<EditAction>,
+    redo_stack: Vec<EditAction>,
+    /// The action collecting the commands of the current mouse stroke
+    stroke: Option<EditAction>,
+    /// The number of actions that can be undone
+    max_depth: usize,
+    /// The id given to the next pushed action
+    next_id: u64,
+    /// The id of the latest action when the level was last saved, the inner `None` for an empty history.
+    /// `None` if the level was never saved.
+    saved_at: Option<Option<u64>>,
+}
+
+impl Default for EditHistory {
+    fn default() -> Self {
+        Self {
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            stroke: None,
+            max_depth: Self::DEFAULT_DEPTH,
+            next_id: 1,
+            saved_at: Some(None),
+        }
+    }
+}
+
+impl EditHistory {
+    pub const DEFAULT_DEPTH: usize = 100;
+    pub const DEPTH_RANGE: (usize, usize) = (1, 1000);
+
+    /// An empty history keeping up to `max_depth` actions
+    pub fn with_max_depth(max_depth: usize) -> Self {
+        Self {
+            max_depth,
+            ..Default::default()
+        }
+    }
+
+    /// A history for a level that has never been saved
+    pub fn unsaved(max_depth: usize) -> Self {
+        Self {
+            saved_at: None,
+            ..Self::with_max_depth(max_depth)
+        }
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Changes how many actions are kept, dropping the oldest ones if needed
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = max_depth.clamp(Self::DEPTH_RANGE.0, Self::DEPTH_RANGE.1);
+        self.trim();
+    }
+
+    /// Starts grouping commands into one action, does nothing if a stroke is already open
+    pub fn begin_stroke(&mut self, label: impl ToString) {
+        if self.stroke.is_none() {
+            self.stroke = Some(EditAction::new(label));
+        }
+    }
+
+    /// Closes the current stroke and adds it to the history if it changed anything
+    pub fn end_stroke(&mut self) {
+        if let Some(mut action) = self.stroke.take() {
+            action.prune();
+            if !action.is_empty() {
+                self.push(action);
+            }
+        }
+    }
+
+    /// Records a command that was already applied to the level
+    pub fn record(&mut self, command: EditCommand) {
+        if command.is_noop() {
+            return;
+        }
+        match self.stroke.as_mut() {
+            Some(stroke) => stroke.push(command),
+            None => {
+                let mut action = EditAction::new("Edit");
+                action.push(command);
+                self.push(action);
+            },
+        }
+    }
+
+    /// Records a command, folding it into the latest action if it has the same label and wasn't saved
+    /// since, so typing into a field is undone at once
+    pub fn record_coalesced(&mut self, label: impl ToString, command: EditCommand) {
+        if command.is_noop() {
+            return;
+        }
+        self.end_stroke();
+        let label = label.to_string();
+        let saved = self.saved_at == Some(self.latest_id());
+        match self.undo_stack.back_mut() {
+            Some(action) if action.label == label && !saved && self.redo_stack.is_empty() => {
+                action.push(command);
+                action.prune();
+                if action.is_empty() {
+                    self.undo_stack.pop_back();
+                }
+            },
+            _ => {
+                let mut action = EditAction::new(label);
+                action.push(command);
+                self.push(action);
+            },
+        }
+    }
+
+    /// Records a change replacing the whole level, such as a resize or paste
+    pub fn record_level(&mut self, label: impl ToString, before: Level, after: Level) {
+        self.end_stroke();
+        let mut action = EditAction::new(label);
+        action.push(EditCommand::Level {
+            before: Box::new(before),
+            after: Box::new(after),
+        });
+        self.push(action);
+    }
+
+    /// Adds an already applied action, clearing everything that could be redone
+    pub fn push(&mut self, mut action: EditAction) {
+        action.id = self.next_id;
+        self.next_id += 1;
+        self.redo_stack.clear();
+        self.undo_stack.push_back(action);
+        self.trim();
+    }
+
+    /// Reverts the latest action, returning its label
+    pub fn undo(&mut self, level: &mut Level) -> Option<String> {
+        self.end_stroke();
+        let action = self.undo_stack.pop_back()?;
+        action.revert(level);
+        let label = action.label.clone();
+        self.redo_stack.push(action);
+        Some(label)
+    }
+
+    /// Re-applies the latest undone action, returning its label
+    pub fn redo(&mut self, level: &mut Level) -> Option<String> {
+        self.end_stroke();
+        let action = self.redo_stack.pop()?;
+        action.apply(level);
+        let label = action.label.clone();
+        self.undo_stack.push_back(action);
+        Some(label)
+    }
+
+    /// Remembers the current state as the one on disk
+    pub fn mark_saved(&mut self) {
+        self.end_stroke();
+        self.saved_at = Some(self.latest_id());
+    }
+
+    /// Whether the level changed since it was last saved
+    pub fn is_dirty(&self) -> bool {
+        self.saved_at != Some(self.latest_id())
+            || self.stroke.as_ref().is_some_and(|stroke| !stroke.is_empty())
+    }
+
+    fn latest_id(&self) -> Option<u64> {
+        self.undo_stack.back().map(|action| action.id)
+    }
+
+    /// Labels of the actions that can be undone, oldest first
+    pub fn undo_labels(&self) -> impl Iterator<Item = &str> {
+        self.undo_stack.iter().map(|action| action.label.as_str())
+    }
+
+    /// Labels of the actions that can be redone, next redo first
+    pub fn redo_labels(&self) -> impl Iterator<Item = &str> {
+        self.redo_stack.iter().rev().map(|action| action.label.as_str())
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty() || self.stroke.as_ref().is_some_and(|stroke| !stroke.is_empty())
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    fn trim(&mut self) {
+        while self.undo_stack.len() > self.max_depth {
+            self.undo_stack.pop_front();
+        }
+    }
+}
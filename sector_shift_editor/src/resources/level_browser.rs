@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use sector_shift_core::{maps::LevelError, prelude::*};
+
+use crate::data::LevelSummary;
+
+/// The level files found in the levels directory
+#[derive(Resource)]
+pub struct LevelBrowser {
+    pub levels: Vec<LevelSummary>,
+}
+
+impl Default for LevelBrowser {
+    fn default() -> Self {
+        let mut browser = Self { levels: Vec::new() };
+        if let Err(e) = browser.refresh() {
+            warn!("Could not list the levels: {e}");
+        }
+        browser
+    }
+}
+
+impl LevelBrowser {
+    /// Reads the levels directory again, keeping the old list if it can't be read
+    pub fn refresh(&mut self) -> Result<(), LevelError> {
+        self.levels = Level::list_ids()?.into_iter().map(LevelSummary::read).collect();
+        Ok(())
+    }
+
+    /// The ids of all level files, sorted alphabetically
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.levels.iter().map(|summary| summary.id.as_str())
+    }
+}
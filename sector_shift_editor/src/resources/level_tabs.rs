@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+use sector_shift_core::{maps::LevelError, prelude::*};
+
+use crate::resources::{EditHistory, MapData};
+
+/// A level open in the editor. The active tab's level and history live in [`MapData`] and
+/// [`EditHistory`], every other tab keeps its own here.
+#[derive(Default)]
+pub struct LevelTab {
+    level: Level,
+    history: EditHistory,
+}
+
+/// The levels open in the editor
+#[derive(Resource)]
+pub struct LevelTabs {
+    tabs: Vec<LevelTab>,
+    active: usize,
+    /// A tab waiting for the user to decide what happens to its unsaved changes
+    pub pending_close: Option<usize>,
+    /// The editor is waiting for the user to decide what happens to unsaved changes before quitting
+    pub pending_quit: bool,
+}
+
+impl Default for LevelTabs {
+    fn default() -> Self {
+        Self {
+            // The placeholder for the level already in MapData
+            tabs: vec![LevelTab::default()],
+            active: 0,
+            pending_close: None,
+            pending_quit: false,
+        }
+    }
+}
+
+impl LevelTabs {
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// The id of the level in a tab
+    pub fn id<'a>(&'a self, index: usize, map_data: &'a MapData) -> &'a str {
+        if index == self.active { &map_data.level.id } else { &self.tabs[index].level.id }
+    }
+
+    /// Whether a tab has unsaved changes
+    pub fn is_dirty(&self, index: usize, history: &EditHistory) -> bool {
+        if index == self.active { history.is_dirty() } else { self.tabs[index].history.is_dirty() }
+    }
+
+    /// Whether any tab has unsaved changes
+    pub fn any_dirty(&self, history: &EditHistory) -> bool {
+        (0..self.tabs.len()).any(|index| self.is_dirty(index, history))
+    }
+
+    /// Makes another tab the one being edited
+    pub fn switch(&mut self, index: usize, map_data: &mut MapData, history: &mut EditHistory) {
+        if index == self.active || index >= self.tabs.len() {
+            return;
+        }
+        history.end_stroke();
+        let stashed = &mut self.tabs[self.active];
+        std::mem::swap(&mut stashed.level, &mut map_data.level);
+        std::mem::swap(&mut stashed.history, history);
+
+        self.active = index;
+        let restored = &mut self.tabs[self.active];
+        std::mem::swap(&mut restored.level, &mut map_data.level);
+        std::mem::swap(&mut restored.history, history);
+    }
+
+    /// Opens the level in a new tab. A level with the same id that is already open keeps its tab
+    /// and its changes, that tab is switched to and `level` is dropped. Returns whether `level` got
+    /// its own tab
+    pub fn open(
+        &mut self,
+        level: Level,
+        saved: bool,
+        map_data: &mut MapData,
+        history: &mut EditHistory,
+    ) -> bool {
+        if let Some(index) = (0..self.tabs.len()).find(|index| self.id(*index, map_data) == level.id) {
+            self.switch(index, map_data, history);
+            return false;
+        }
+
+        let history_for_tab = if saved {
+            EditHistory::with_max_depth(history.max_depth())
+        } else {
+            EditHistory::unsaved(history.max_depth())
+        };
+        self.tabs.push(LevelTab {
+            level,
+            history: history_for_tab,
+        });
+        self.switch(self.tabs.len() - 1, map_data, history);
+        true
+    }
+
+    /// Saves the level of a tab and marks it as saved
+    pub fn save(
+        &mut self,
+        index: usize,
+        map_data: &MapData,
+        history: &mut EditHistory,
+    ) -> Result<(), LevelError> {
+        if index == self.active {
+            map_data.level.save()?;
+            history.mark_saved();
+        } else {
+            let tab = &mut self.tabs[index];
+            tab.level.save()?;
+            tab.history.mark_saved();
+        }
+        Ok(())
+    }
+
+    /// Closes a tab without saving, the last tab is replaced by an empty level
+    pub fn close(&mut self, index: usize, map_data: &mut MapData, history: &mut EditHistory) {
+        if index >= self.tabs.len() {
+            return;
+        }
+        // Keep a pending close pointing at the same tab
+        self.pending_close = match self.pending_close {
+            Some(pending) if pending == index => None,
+            Some(pending) if pending > index => Some(pending - 1),
+            pending => pending,
+        };
+        if self.tabs.len() == 1 {
+            map_data.level = Level::new("new_level", (32, 32));
+            *history = EditHistory::unsaved(history.max_depth());
+            return;
+        }
+
+        if index == self.active {
+            // Move away first so the closed tab holds its level
+            let neighbour = if index + 1 < self.tabs.len() { index + 1 } else { index - 1 };
+            self.switch(neighbour, map_data, history);
+        }
+        self.tabs.remove(index);
+        if self.active > index {
+            self.active -= 1;
+        }
+    }
+}
@@ -8,8 +8,10 @@ pub struct MapData {
 
 impl Default for MapData {
     fn default() -> Self {
-        Self {
-            level: Level::load("level_1"),
-        }
+        let level = Level::try_load("level_1").unwrap_or_else(|e| {
+            warn!("Could not load level_1, starting from an empty map: {e}");
+            Level::default()
+        });
+        Self { level }
     }
 }
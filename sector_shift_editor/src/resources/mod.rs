@@ -1,8 +1,26 @@
 mod brush_data;
 pub use self::brush_data::*;
 
+mod edit_history;
+pub use self::edit_history::*;
+
+mod level_browser;
+pub use self::level_browser::*;
+
+mod level_tabs;
+pub use self::level_tabs::*;
+
 mod map_data;
 pub use self::map_data::*;
 
+mod playtest;
+pub use self::playtest::*;
+
+mod problems;
+pub use self::problems::*;
+
+mod selection;
+pub use self::selection::*;
+
 mod ui_state;
 pub use self::ui_state::*;
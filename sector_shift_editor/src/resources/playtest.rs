@@ -0,0 +1,40 @@
+use std::process::Child;
+
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::functions::{PlaytestError, launch_playtest};
+
+/// The game started to playtest the level being edited
+#[derive(Resource, Default)]
+pub struct Playtest {
+    pub child: Option<Child>,
+}
+
+impl Playtest {
+    /// Starts the game on the level, see [`launch_playtest`]
+    pub fn launch(
+        &mut self,
+        level: &Level,
+        start: Option<((i32, i32), Direction)>,
+    ) -> Result<(), PlaytestError> {
+        let child = launch_playtest(level, start)?;
+        self.replace(child);
+        Ok(())
+    }
+
+    /// Closes a game still running from an earlier playtest and keeps track of the new one
+    pub fn replace(&mut self, child: Child) {
+        self.stop();
+        self.child = Some(child);
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(mut child) = self.child.take()
+            && let Ok(None) = child.try_wait()
+        {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+/// The problems found in the level being edited, kept up to date by `validate_level`
+#[derive(Resource, Default)]
+pub struct Problems {
+    pub list: Vec<LevelProblem>,
+}
+
+impl Problems {
+    pub fn count(&self, severity: ProblemSeverity) -> usize {
+        self.list.iter().filter(|problem| problem.severity() == severity).count()
+    }
+}
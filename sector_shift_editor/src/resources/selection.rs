@@ -0,0 +1,123 @@
+use bevy::prelude::Resource;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    data::{CellState, Clipboard, EditCommand},
+    resources::EditHistory,
+};
+
+/// The selected region of the map and the clipboard
+#[derive(Resource, Default)]
+pub struct Selection {
+    /// The selected cells, always inside the level
+    pub rectangle: Option<Rectangle>,
+    /// The cell a new selection is being dragged from
+    pub drag_start: Option<(i32, i32)>,
+    pub clipboard: Option<Clipboard>,
+    /// Whether the clipboard follows the cursor waiting to be placed
+    pub pasting: bool,
+}
+
+impl Selection {
+    /// Selects the cells between two corners, clipped to the level
+    pub fn select(&mut self, level: &Level, start: (i32, i32), end: (i32, i32)) {
+        let dragged = Rectangle::new(
+            (start.0.min(end.0), start.1.min(end.1)),
+            (start.0.max(end.0) + 1, start.1.max(end.1) + 1),
+        );
+        let bounds = Rectangle::new_with_size((0, 0), level.tiles.size());
+        let rectangle = dragged.intersection(bounds);
+        self.rectangle = (rectangle.width() > 0 && rectangle.height() > 0).then_some(rectangle);
+    }
+
+    /// The selected cell when exactly one is selected, such as after a click
+    pub fn cell(&self) -> Option<(i32, i32)> {
+        self.rectangle
+            .filter(|rectangle| rectangle.width() == 1 && rectangle.height() == 1)
+            .map(|rectangle| rectangle.min)
+    }
+
+    pub fn clear(&mut self) {
+        self.rectangle = None;
+        self.drag_start = None;
+        self.pasting = false;
+    }
+
+    /// Copies the selection to the clipboard, returns false if nothing is selected
+    pub fn copy(&mut self, level: &Level) -> bool {
+        let Some(rectangle) = self.rectangle else {
+            return false;
+        };
+        self.clipboard = Some(Clipboard::copy(level, rectangle));
+        true
+    }
+
+    /// Copies the selection to the clipboard and deletes it from the level
+    pub fn cut(&mut self, level: &mut Level, history: &mut EditHistory) -> bool {
+        self.copy(level) && self.delete(level, history, "Cut")
+    }
+
+    /// Turns the selected cells back into walls without objects
+    pub fn delete(&mut self, level: &mut Level, history: &mut EditHistory, label: &str) -> bool {
+        let Some(rectangle) = self.rectangle else {
+            return false;
+        };
+        let size = (rectangle.width() as u32, rectangle.height() as u32);
+        let empty = Clipboard {
+            tiles: Grid::new_default(size),
+            objects: Default::default(),
+        };
+        Self::write(level, history, label, &empty, rectangle.min);
+        true
+    }
+
+    /// Starts moving the clipboard with the cursor, returns false if the clipboard is empty
+    pub fn start_paste(&mut self) -> bool {
+        self.pasting = self.clipboard.is_some();
+        self.pasting
+    }
+
+    /// Where the clipboard lands when the cursor is over `cell`, centered on the cursor
+    pub fn paste_origin(&self, cell: (i32, i32)) -> Option<(i32, i32)> {
+        let (width, height) = self.clipboard.as_ref()?.size();
+        Some((cell.0 - width as i32 / 2, cell.1 - height as i32 / 2))
+    }
+
+    /// Places the clipboard at `origin` and selects the pasted area
+    pub fn place(&mut self, level: &mut Level, history: &mut EditHistory, origin: (i32, i32)) -> bool {
+        let Some(clipboard) = self.clipboard.as_ref() else {
+            return false;
+        };
+        Self::write(level, history, "Paste", clipboard, origin);
+        let area = clipboard.area(origin);
+        self.pasting = false;
+        self.select(level, area.min, (area.max.0 - 1, area.max.1 - 1));
+        true
+    }
+
+    /// Pastes a clipboard as one undoable action
+    fn write(
+        level: &mut Level,
+        history: &mut EditHistory,
+        label: &str,
+        clipboard: &Clipboard,
+        origin: (i32, i32),
+    ) {
+        let area = clipboard.area(origin);
+        let before: Vec<_> =
+            area.iter().map(|position| (position, CellState::read(level, position))).collect();
+
+        clipboard.paste(level, origin);
+
+        history.end_stroke();
+        history.begin_stroke(label);
+        for (position, before) in before {
+            history.record(EditCommand::Cell {
+                position,
+                before,
+                after: CellState::read(level, position),
+            });
+        }
+        history.end_stroke();
+    }
+}
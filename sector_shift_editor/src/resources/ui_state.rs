@@ -1,15 +1,23 @@
 use bevy::prelude::*;
 use sector_shift_core::prelude::*;
 
+use crate::data::MapDialog;
+
 #[derive(Resource)]
 pub struct UiState {
     pub status_message: String,
     pub level_name: String,
     pub selected_door_axis: DoorAxis,
     pub player_start_direction: Direction,
+    pub patrol_turn_direction: Direction,
     pub enemy_name: String,
     pub exit_name: String,
     pub item_name: String,
+    pub env_name: String,
+    /// The open New Map or Resize dialog
+    pub dialog: Option<MapDialog>,
+    /// The key typed in the inspector to add a value to the selected object
+    pub new_value_key: String,
 }
 
 impl Default for UiState {
@@ -19,9 +27,21 @@ impl Default for UiState {
             level_name: "level_1".to_string(),
             selected_door_axis: DoorAxis::Horizontal,
             player_start_direction: Direction::NORTH,
+            patrol_turn_direction: Direction::NORTH,
             enemy_name: "slime".to_string(),
             exit_name: "level_2".to_string(),
             item_name: "medkit".to_string(),
+            env_name: String::new(),
+            dialog: None,
+            new_value_key: String::new(),
         }
     }
 }
+
+impl UiState {
+    /// Shows the settings of a level that was opened or switched to
+    pub fn show_level(&mut self, level: &Level) {
+        self.level_name = level.id.clone();
+        self.player_start_direction = level.player_start.1;
+    }
+}
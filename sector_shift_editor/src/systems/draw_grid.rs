@@ -1,9 +1,22 @@
 use bevy::prelude::*;
-use sector_shift_core::{maps::MapObject, prelude::TileType};
+use sector_shift_core::{
+    maps::MapObject,
+    prelude::{EnemyLibrary, ItemLibrary, ProblemSeverity, TileType},
+};
 
-use crate::{CELL_SIZE, HALF_CELL_SIZE, resources::MapData};
+use crate::{
+    CELL_SIZE, HALF_CELL_SIZE,
+    functions::object_sprite,
+    resources::{MapData, Problems},
+};
 
-pub fn draw_grid(mut gizmos: Gizmos, editor: Res<MapData>) {
+pub fn draw_grid(
+    mut gizmos: Gizmos,
+    editor: Res<MapData>,
+    enemy_library: Res<EnemyLibrary>,
+    item_library: Res<ItemLibrary>,
+    problems: Res<Problems>,
+) {
     // Draw vertical lines
     let height = editor.level.height() as f32 * CELL_SIZE;
     for x in 0..=editor.level.width() {
@@ -42,8 +55,12 @@ pub fn draw_grid(mut gizmos: Gizmos, editor: Res<MapData>) {
         gizmos.rect_2d(center, Vec2::splat(CELL_SIZE * 0.9), tile.color());
     }
 
-    // Draw objects
+    // Draw objects, enemies and items with a known id are shown by their sprite instead
     for (position, object) in editor.level.objects.iter() {
+        if object_sprite(object, &enemy_library, &item_library).is_some() {
+            continue;
+        }
+
         let center = Vec2::new(
             position.0 as f32 * CELL_SIZE + HALF_CELL_SIZE,
             position.1 as f32 * CELL_SIZE + HALF_CELL_SIZE,
@@ -54,19 +71,63 @@ pub fn draw_grid(mut gizmos: Gizmos, editor: Res<MapData>) {
             MapObject::Exit(_) => {
                 gizmos.cross_2d(center, HALF_CELL_SIZE * 0.8, color);
             },
-            MapObject::Enemy(_) => {
+            MapObject::Enemy(..) => {
                 let isometry = Isometry2d {
                     rotation: Rot2::FRAC_PI_4,
                     translation: center,
                 };
                 gizmos.cross_2d(isometry, HALF_CELL_SIZE * 0.8, color);
             },
-            MapObject::Item(_) => {
+            MapObject::Item(..) => {
+                gizmos.circle_2d(center, HALF_CELL_SIZE * 0.8, color);
+            },
+            MapObject::Secret => {
+                gizmos.rect_2d(center, Vec2::splat(HALF_CELL_SIZE), color);
+            },
+            MapObject::Env(..) => {
+                gizmos.rect_2d(center, Vec2::splat(CELL_SIZE * 0.8), color);
+                gizmos.circle_2d(center, HALF_CELL_SIZE * 0.5, color);
+            },
+            MapObject::PatrolTurn(direction) => {
+                let coord = Vec2::new(direction.coord().0 as f32, direction.coord().1 as f32)
+                    .normalize_or_zero()
+                    * HALF_CELL_SIZE
+                    * 0.6;
                 gizmos.circle_2d(center, HALF_CELL_SIZE * 0.8, color);
+                gizmos.arrow_2d(center - coord, center + coord, color);
             },
         }
     }
 
+    // Mark objects that only appear on harder difficulties with one dot per tier above Easy
+    for (position, object) in editor.level.objects.iter() {
+        let Some(properties) = object.properties() else {
+            continue;
+        };
+        for i in 0..properties.min_difficulty as usize {
+            let dot = Vec2::new(
+                position.0 as f32 * CELL_SIZE + CELL_SIZE * 0.2 + i as f32 * CELL_SIZE * 0.2,
+                position.1 as f32 * CELL_SIZE + CELL_SIZE * 0.85,
+            );
+            gizmos.circle_2d(dot, CELL_SIZE * 0.05, Color::WHITE);
+        }
+    }
+
+    // Draw the facing of objects like the player start, in the object's color
+    for (position, object) in editor.level.objects.iter() {
+        let Some(facing) = object.properties().and_then(|properties| properties.facing) else {
+            continue;
+        };
+        let center = Vec2::new(
+            position.0 as f32 * CELL_SIZE + HALF_CELL_SIZE,
+            position.1 as f32 * CELL_SIZE + HALF_CELL_SIZE,
+        );
+        let coord = Vec2::new(facing.coord().0 as f32, facing.coord().1 as f32).normalize_or_zero()
+            * HALF_CELL_SIZE
+            * 0.8;
+        gizmos.arrow_2d(center - coord, center + coord, object.color());
+    }
+
     // Draw player start
     let (position, direction) = editor.level.player_start;
     let center = Vec2::new(
@@ -80,4 +141,21 @@ pub fn draw_grid(mut gizmos: Gizmos, editor: Res<MapData>) {
     let start = center - coord;
     let end = center + coord;
     gizmos.arrow_2d(start, end, Color::srgb(1.0, 1.0, 0.0));
+
+    // Outline cells with problems, errors drawn last so they stay visible
+    for severity in [ProblemSeverity::Warning, ProblemSeverity::Error] {
+        let color = match severity {
+            ProblemSeverity::Warning => Color::srgb(1.0, 0.5, 0.0),
+            ProblemSeverity::Error => Color::srgb(1.0, 0.0, 0.0),
+        };
+        for problem in problems.list.iter().filter(|problem| problem.severity() == severity) {
+            for position in problem.cells() {
+                let center = Vec2::new(
+                    position.0 as f32 * CELL_SIZE + HALF_CELL_SIZE,
+                    position.1 as f32 * CELL_SIZE + HALF_CELL_SIZE,
+                );
+                gizmos.rect_2d(center, Vec2::splat(CELL_SIZE * 0.98), color);
+            }
+        }
+    }
 }
@@ -0,0 +1,142 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use sector_shift_core::prelude::*;
+
+use crate::{
+    data::{CellState, EditCommand},
+    functions::describe_object,
+    resources::{EditHistory, MapData, Selection, UiState},
+};
+
+/// Edits the properties of the object in the selected cell, select a single cell with the Select tool
+pub fn draw_inspector(
+    mut contexts: EguiContexts,
+    mut map_data: ResMut<MapData>,
+    mut history: ResMut<EditHistory>,
+    selection: Res<Selection>,
+    mut ui_state: ResMut<UiState>,
+) {
+    let Some(position) = selection.cell() else {
+        return;
+    };
+    let Some(object) = map_data.level.objects.get(&position) else {
+        return;
+    };
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    // Edit a copy so the level is only touched when something changed
+    let mut edited = object.clone();
+    egui::Window::new("Inspector")
+        .id(egui::Id::new("inspector"))
+        .anchor(egui::Align2::RIGHT_TOP, [-8.0, 8.0])
+        .default_width(220.0)
+        .show(ctx, |ui| {
+            ui.heading(describe_object(&edited));
+            ui.label(format!("Cell ({}, {})", position.0, position.1));
+            let flags = edited.available_flags();
+            let Some(properties) = edited.properties_mut() else {
+                ui.weak("This object has no properties");
+                return;
+            };
+            ui.separator();
+
+            egui::Grid::new("inspector_properties").num_columns(2).show(ui, |ui| {
+                ui.label("Min Difficulty:");
+                egui::ComboBox::from_id_salt("inspector_min_difficulty")
+                    .selected_text(properties.min_difficulty.name())
+                    .show_ui(ui, |ui| {
+                        for difficulty in DifficultyLevel::ALL {
+                            ui.selectable_value(
+                                &mut properties.min_difficulty,
+                                difficulty,
+                                difficulty.name(),
+                            );
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Facing:");
+                let facing_text =
+                    properties.facing.map_or("Default".to_string(), |facing| facing.to_string());
+                egui::ComboBox::from_id_salt("inspector_facing").selected_text(facing_text).show_ui(
+                    ui,
+                    |ui| {
+                        ui.selectable_value(&mut properties.facing, None, "Default");
+                        for direction in Direction::iter_cardinal_ordinal() {
+                            ui.selectable_value(
+                                &mut properties.facing,
+                                Some(direction),
+                                direction.to_string(),
+                            );
+                        }
+                    },
+                );
+                ui.end_row();
+
+                if !flags.is_empty() {
+                    ui.label("Flags:");
+                    ui.vertical(|ui| {
+                        for &flag in flags {
+                            let mut enabled = properties.has_flag(flag);
+                            if ui.checkbox(&mut enabled, flag.name()).changed() {
+                                properties.set_flag(flag, enabled);
+                            }
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+
+            ui.separator();
+            ui.label("Values:");
+            let mut removed = None;
+            egui::Grid::new("inspector_values").num_columns(3).show(ui, |ui| {
+                for (key, value) in properties.values.iter_mut() {
+                    ui.label(key);
+                    ui.text_edit_singleline(value);
+                    if ui.small_button("x").on_hover_text("Remove").clicked() {
+                        removed = Some(key.clone());
+                    }
+                    ui.end_row();
+                }
+            });
+            if let Some(key) = removed {
+                properties.values.remove(&key);
+            }
+            ui.horizontal(|ui| {
+                ui.text_edit_singleline(&mut ui_state.new_value_key);
+                let key = ui_state.new_value_key.trim();
+                let can_add = !key.is_empty() && !properties.values.contains_key(key);
+                if ui.add_enabled(can_add, egui::Button::new("Add")).clicked() {
+                    properties.values.insert(key.to_string(), String::new());
+                    ui_state.new_value_key.clear();
+                }
+            });
+        });
+
+    let before = CellState::read(&map_data.level, position);
+    if before.object.as_ref() == Some(&edited) {
+        return;
+    }
+    // Changes of the same setting are undone together, typing a value isn't undone per character
+    let label = match (
+        before.object.as_ref().and_then(MapObject::properties),
+        edited.properties(),
+    ) {
+        (Some(old), Some(new)) if old.values != new.values => "Change Object Values",
+        (Some(old), Some(new)) if old.facing != new.facing => "Change Object Facing",
+        (Some(old), Some(new)) if old.flags != new.flags => "Change Object Flags",
+        _ => "Change Object Difficulty",
+    };
+    map_data.level.objects.insert(position, edited);
+    history.record_coalesced(
+        label,
+        EditCommand::Cell {
+            position,
+            before,
+            after: CellState::read(&map_data.level, position),
+        },
+    );
+}
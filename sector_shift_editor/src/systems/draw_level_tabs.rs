@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::resources::{EditHistory, LevelBrowser, LevelTabs, MapData, Selection, UiState};
+
+/// Shows the level browser, the open tabs and the questions about unsaved changes
+pub fn draw_level_tabs(
+    mut contexts: EguiContexts,
+    mut map_data: ResMut<MapData>,
+    mut history: ResMut<EditHistory>,
+    mut level_tabs: ResMut<LevelTabs>,
+    mut level_browser: ResMut<LevelBrowser>,
+    mut selection: ResMut<Selection>,
+    mut ui_state: ResMut<UiState>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let mut message = None;
+    // Whether another level is shown now
+    let mut switched = false;
+    // Whether a level file was written
+    let mut saved = false;
+
+    egui::SidePanel::left("level_browser").default_width(180.0).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            ui.heading("Levels");
+            if ui.button("Refresh").clicked()
+                && let Err(e) = level_browser.refresh()
+            {
+                message = Some(format!("Error Listing Levels: {e}"));
+            }
+        });
+        ui.separator();
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for summary in &level_browser.levels {
+                let response = match &summary.contents {
+                    Ok((size, objects)) => ui.selectable_label(
+                        false,
+                        format!("{}\n{}x{}, {} objects", summary.id, size.0, size.1, objects),
+                    ),
+                    Err(e) => ui
+                        .selectable_label(
+                            false,
+                            egui::RichText::new(&summary.id).color(egui::Color32::RED),
+                        )
+                        .on_hover_text(e),
+                };
+                if response.clicked() {
+                    match sector_shift_core::prelude::Level::try_load(&summary.id) {
+                        Ok(level) => {
+                            let id = level.id.clone();
+                            if !level_tabs.open(level, true, &mut map_data, &mut history) {
+                                message = Some(format!("{id} is already open, switched to its tab"));
+                            }
+                            switched = true;
+                        },
+                        Err(e) => message = Some(format!("Error Loading Map: {e}")),
+                    }
+                }
+            }
+        });
+    });
+
+    // Closed once the tabs are drawn, closing while iterating them would shift the indices
+    let mut closed = None;
+    egui::TopBottomPanel::top("level_tabs").show(ctx, |ui| {
+        ui.horizontal_wrapped(|ui| {
+            for index in 0..level_tabs.len() {
+                let dirty = if level_tabs.is_dirty(index, &history) { " *" } else { "" };
+                let title = format!("{}{dirty}", level_tabs.id(index, &map_data));
+                if ui.selectable_label(index == level_tabs.active(), title).clicked() {
+                    level_tabs.switch(index, &mut map_data, &mut history);
+                    switched = true;
+                }
+                if ui.small_button("x").on_hover_text("Close").clicked() {
+                    if dirty.is_empty() {
+                        closed = Some(index);
+                    } else {
+                        level_tabs.pending_close = Some(index);
+                    }
+                }
+                ui.separator();
+            }
+        });
+    });
+    if let Some(index) = closed {
+        level_tabs.close(index, &mut map_data, &mut history);
+        switched = true;
+    }
+
+    if let Some(index) = level_tabs.pending_close {
+        let id = level_tabs.id(index, &map_data).to_string();
+        egui::Window::new("Unsaved Changes").collapsible(false).resizable(false).show(ctx, |ui| {
+            ui.label(format!("Save the changes to {id} before closing?"));
+            ui.horizontal(|ui| {
+                if ui.button("Save").clicked() {
+                    match level_tabs.save(index, &map_data, &mut history) {
+                        Ok(()) => {
+                            level_tabs.close(index, &mut map_data, &mut history);
+                            switched = true;
+                            saved = true;
+                        },
+                        Err(e) => message = Some(format!("Error Saving Map: {e}")),
+                    }
+                    level_tabs.pending_close = None;
+                }
+                if ui.button("Discard").clicked() {
+                    level_tabs.close(index, &mut map_data, &mut history);
+                    switched = true;
+                    level_tabs.pending_close = None;
+                }
+                if ui.button("Cancel").clicked() {
+                    level_tabs.pending_close = None;
+                }
+            });
+        });
+    }
+
+    if level_tabs.pending_quit {
+        let dirty: Vec<_> =
+            (0..level_tabs.len()).filter(|index| level_tabs.is_dirty(*index, &history)).collect();
+        egui::Window::new("Quit").collapsible(false).resizable(false).show(ctx, |ui| {
+            ui.label("These levels have unsaved changes:");
+            for index in &dirty {
+                ui.label(format!("- {}", level_tabs.id(*index, &map_data)));
+            }
+            ui.horizontal(|ui| {
+                if ui.button("Save All and Quit").clicked() {
+                    let errors: Vec<_> = dirty
+                        .iter()
+                        .filter_map(|index| level_tabs.save(*index, &map_data, &mut history).err())
+                        .collect();
+                    saved = true;
+                    match errors.first() {
+                        None => {
+                            exit.write(AppExit::Success);
+                        },
+                        Some(e) => message = Some(format!("Error Saving Map: {e}")),
+                    }
+                    level_tabs.pending_quit = false;
+                }
+                if ui.button("Quit Without Saving").clicked() {
+                    exit.write(AppExit::Success);
+                }
+                if ui.button("Cancel").clicked() {
+                    level_tabs.pending_quit = false;
+                }
+            });
+        });
+    }
+
+    if saved && let Err(e) = level_browser.refresh() {
+        message = Some(format!("Error Listing Levels: {e}"));
+    }
+    if switched {
+        ui_state.show_level(&map_data.level);
+        selection.clear();
+    }
+    if let Some(message) = message {
+        ui_state.status_message = message;
+    }
+}
@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use sector_shift_core::prelude::*;
+
+use crate::{
+    MAP_SIZE_RANGE,
+    data::{MapDialog, ResizeAnchor},
+    functions::resize_level,
+    resources::{EditHistory, LevelTabs, MapData, Selection, UiState},
+};
+
+/// Shows the New Map or Resize dialog if one is open
+pub fn draw_map_dialog(
+    mut contexts: EguiContexts,
+    mut map_data: ResMut<MapData>,
+    mut ui_state: ResMut<UiState>,
+    mut history: ResMut<EditHistory>,
+    mut selection: ResMut<Selection>,
+    mut level_tabs: ResMut<LevelTabs>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let Some(dialog) = ui_state.dialog.as_mut() else {
+        return;
+    };
+
+    let mut close = false;
+    let mut message = None;
+    let mut level_name = None;
+    match dialog {
+        MapDialog::New { id, size, fill } => {
+            egui::Window::new("New Map").collapsible(false).resizable(false).show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Id:");
+                    ui.text_edit_singleline(id);
+                });
+                size_fields(ui, size);
+                ui.horizontal(|ui| {
+                    ui.label("Fill:");
+                    ui.radio_value(fill, TileType::Wall, "Wall");
+                    ui.radio_value(fill, TileType::Floor, "Floor");
+                });
+
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(!id.is_empty(), egui::Button::new("Create")).clicked() {
+                        let mut level = Level::new(&*id, *size);
+                        level.tiles = Grid::new_copy(*size, *fill);
+                        let opened = level_tabs.open(level, false, &mut map_data, &mut history);
+                        selection.clear();
+                        message = Some(if opened {
+                            format!("Created {id}")
+                        } else {
+                            format!("{id} is already open, switched to its tab instead")
+                        });
+                        level_name = Some(id.clone());
+                        close = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        close = true;
+                    }
+                });
+            });
+        },
+        MapDialog::Resize {
+            size,
+            anchor,
+            losses,
+        } => {
+            egui::Window::new("Resize Map").collapsible(false).resizable(false).show(ctx, |ui| {
+                if let Some(lost) = losses.as_ref() {
+                    ui.label("Resizing will drop:");
+                    egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                        for line in lost {
+                            ui.label(format!("- {line}"));
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        if ui.button("Resize Anyway").clicked() {
+                            let resized = resize_level(&map_data.level, *size, *anchor);
+                            apply_resize(&mut map_data.level, &mut history, resized.level);
+                            selection.clear();
+                            message = Some(format!("Resized to {}x{}", size.0, size.1));
+                            close = true;
+                        }
+                        if ui.button("Back").clicked() {
+                            *losses = None;
+                        }
+                    });
+                    return;
+                }
+
+                ui.label(format!(
+                    "Current Size: {}x{}",
+                    map_data.level.width(),
+                    map_data.level.height()
+                ));
+                size_fields(ui, size);
+
+                ui.label("Anchor:");
+                egui::Grid::new("resize_anchor").show(ui, |ui| {
+                    for y in [1, 0, -1] {
+                        for x in [-1, 0, 1] {
+                            let cell = ResizeAnchor { x, y };
+                            ui.selectable_value(anchor, cell, cell.symbol());
+                        }
+                        ui.end_row();
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    if ui.button("Resize").clicked() {
+                        let resized = resize_level(&map_data.level, *size, *anchor);
+                        let lost = resized.losses();
+                        if lost.is_empty() {
+                            apply_resize(&mut map_data.level, &mut history, resized.level);
+                            selection.clear();
+                            message = Some(format!("Resized to {}x{}", size.0, size.1));
+                            close = true;
+                        } else {
+                            *losses = Some(lost);
+                        }
+                    }
+                    if ui.button("Cancel").clicked() {
+                        close = true;
+                    }
+                });
+            });
+        },
+    }
+
+    if close {
+        ui_state.dialog = None;
+    }
+    if let Some(message) = message {
+        ui_state.status_message = message;
+    }
+    if let Some(level_name) = level_name {
+        ui_state.level_name = level_name;
+    }
+}
+
+fn size_fields(ui: &mut egui::Ui, size: &mut (u32, u32)) {
+    let range = MAP_SIZE_RANGE.0..=MAP_SIZE_RANGE.1;
+    ui.horizontal(|ui| {
+        ui.label("Width:");
+        ui.add(egui::DragValue::new(&mut size.0).range(range.clone()));
+        ui.label("Height:");
+        ui.add(egui::DragValue::new(&mut size.1).range(range));
+    });
+}
+
+fn apply_resize(level: &mut Level, history: &mut EditHistory, resized: Level) {
+    let before = std::mem::replace(level, resized);
+    history.record_level("Resize Map", before, level.clone());
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{CELL_SIZE, HALF_CELL_SIZE, resources::MapData};
+
+/// Previews the route of every patrolling enemy, the jump back to the start of a loop is drawn as an
+/// arrow and routes ending at a dead end get a cross
+pub fn draw_patrol_routes(mut gizmos: Gizmos, map_data: Res<MapData>) {
+    let color = MapObject::PatrolTurn(Direction::NORTH).color();
+    let to_center = |position: (i32, i32)| {
+        Vec2::new(
+            position.0 as f32 * CELL_SIZE + HALF_CELL_SIZE,
+            position.1 as f32 * CELL_SIZE + HALF_CELL_SIZE,
+        )
+    };
+
+    for position in map_data.level.objects.keys() {
+        let Some(route) = PatrolRoute::for_enemy(&map_data.level, *position) else {
+            continue;
+        };
+        gizmos.linestrip_2d(route.cells.iter().map(|cell| to_center(*cell)), color);
+
+        let Some(&last) = route.cells.last() else {
+            continue;
+        };
+        match route.loop_start {
+            Some(index) => {
+                gizmos.arrow_2d(to_center(last), to_center(route.cells[index]), color);
+            },
+            None => {
+                gizmos.cross_2d(to_center(last), HALF_CELL_SIZE * 0.4, color);
+            },
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use sector_shift_core::prelude::*;
+
+use crate::{CELL_SIZE, HALF_CELL_SIZE, resources::Problems};
+
+/// Lists the problems of the level, clicking one moves the camera to it
+pub fn draw_problems(
+    mut contexts: EguiContexts,
+    problems: Res<Problems>,
+    mut q_camera: Single<&mut Transform, With<Camera>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let errors = problems.count(ProblemSeverity::Error);
+    let warnings = problems.count(ProblemSeverity::Warning);
+    let title = format!("Problems ({errors} errors, {warnings} warnings)");
+    egui::Window::new(title)
+        .id(egui::Id::new("problems"))
+        .default_open(false)
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-8.0, -8.0])
+        .show(ctx, |ui| {
+            if problems.list.is_empty() {
+                ui.label("No problems found");
+                return;
+            }
+            egui::ScrollArea::vertical().max_height(240.0).show(ui, |ui| {
+                for problem in &problems.list {
+                    let color = match problem.severity() {
+                        ProblemSeverity::Error => egui::Color32::RED,
+                        ProblemSeverity::Warning => egui::Color32::ORANGE,
+                    };
+                    let text = egui::RichText::new(problem.to_string()).color(color);
+                    if ui.selectable_label(false, text).clicked()
+                        && let Some(position) = problem.cells().first()
+                    {
+                        q_camera.translation.x = position.0 as f32 * CELL_SIZE + HALF_CELL_SIZE;
+                        q_camera.translation.y = position.1 as f32 * CELL_SIZE + HALF_CELL_SIZE;
+                    }
+                }
+            });
+        });
+}
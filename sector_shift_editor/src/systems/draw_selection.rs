@@ -0,0 +1,61 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{CELL_SIZE, HALF_CELL_SIZE, functions::cursor_cell, resources::Selection};
+
+/// Outlines the selection and draws the clipboard under the cursor while pasting
+pub fn draw_selection(
+    mut gizmos: Gizmos,
+    selection: Res<Selection>,
+    q_window: Single<&Window, With<PrimaryWindow>>,
+    q_camera: Single<(&Camera, &GlobalTransform)>,
+) {
+    if let Some(rectangle) = selection.rectangle {
+        let min = Vec2::new(rectangle.min.0 as f32, rectangle.min.1 as f32) * CELL_SIZE;
+        let max = Vec2::new(rectangle.max.0 as f32, rectangle.max.1 as f32) * CELL_SIZE;
+        gizmos.rect_2d((min + max) / 2.0, max - min, Color::srgb(1.0, 1.0, 0.0));
+    }
+
+    if !selection.pasting {
+        return;
+    }
+    let (camera, camera_transform) = *q_camera;
+    let Some(clipboard) = selection.clipboard.as_ref() else {
+        return;
+    };
+    let Some(origin) =
+        cursor_cell(*q_window, camera, camera_transform).and_then(|cell| selection.paste_origin(cell))
+    else {
+        return;
+    };
+
+    let to_center = |position: (i32, i32)| {
+        Vec2::new(
+            (position.0 + origin.0) as f32 * CELL_SIZE + HALF_CELL_SIZE,
+            (position.1 + origin.1) as f32 * CELL_SIZE + HALF_CELL_SIZE,
+        )
+    };
+
+    for (position, tile) in clipboard.tiles.enumerate() {
+        gizmos.rect_2d(
+            to_center(position),
+            Vec2::splat(CELL_SIZE * 0.8),
+            tile.color().with_alpha(0.5),
+        );
+    }
+    for (position, object) in clipboard.objects.iter() {
+        gizmos.circle_2d(
+            to_center(*position),
+            HALF_CELL_SIZE * 0.6,
+            object.color().with_alpha(0.5),
+        );
+    }
+
+    let area = clipboard.area(origin);
+    let min = Vec2::new(area.min.0 as f32, area.min.1 as f32) * CELL_SIZE;
+    let max = Vec2::new(area.max.0 as f32, area.max.1 as f32) * CELL_SIZE;
+    gizmos.rect_2d(
+        (min + max) / 2.0,
+        max - min,
+        Color::srgba(0.0, 1.0, 1.0, 0.8),
+    );
+}
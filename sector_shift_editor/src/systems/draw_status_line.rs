@@ -0,0 +1,61 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_egui::{EguiContexts, egui};
+
+use crate::{
+    functions::{cursor_cell, describe_object, fit_camera},
+    resources::MapData,
+};
+
+/// Shows what is under the cursor and the zoom along the bottom of the window
+pub fn draw_status_line(
+    mut contexts: EguiContexts,
+    map_data: Res<MapData>,
+    q_window: Single<&Window, With<PrimaryWindow>>,
+    q_camera: Single<(&Camera, &GlobalTransform, &mut Transform, &mut Projection)>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let (camera, camera_transform, mut transform, mut projection) = q_camera.into_inner();
+    let scale = match &*projection {
+        Projection::Orthographic(projection) => projection.scale,
+        _ => 1.0,
+    };
+
+    let level = &map_data.level;
+    let hovered = cursor_cell(*q_window, camera, camera_transform)
+        .filter(|cell| level.tiles.in_bounds(*cell) && !ctx.is_pointer_over_area());
+
+    egui::TopBottomPanel::bottom("status_line").show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            match hovered {
+                Some(cell) => {
+                    ui.label(format!("({}, {})", cell.0, cell.1));
+                    ui.separator();
+                    ui.label(format!("{:?}", level.tiles[cell]));
+                    if let Some(object) = level.objects.get(&cell) {
+                        ui.separator();
+                        ui.label(describe_object(object));
+                    }
+                    if level.player_start.0 == cell {
+                        ui.separator();
+                        ui.label("Player Start");
+                    }
+                },
+                None => {
+                    ui.weak("No cell hovered");
+                },
+            }
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button("Fit Map").on_hover_text("F").clicked()
+                    && let Projection::Orthographic(projection) = &mut *projection
+                {
+                    let viewport_size = camera.logical_viewport_size().unwrap_or_default();
+                    fit_camera(level, viewport_size, &mut transform, projection);
+                }
+                ui.label(format!("Zoom: {:.0}%", 100.0 / scale));
+            });
+        });
+    });
+}
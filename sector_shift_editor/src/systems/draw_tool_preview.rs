@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use crate::{CELL_SIZE, HALF_CELL_SIZE, resources::BrushData};
+
+/// Outlines the cells a shape tool will paint when the mouse is released
+pub fn draw_tool_preview(mut gizmos: Gizmos, brush_data: Res<BrushData>) {
+    let Some((start, end)) = brush_data.drag else {
+        return;
+    };
+
+    let to_center = |position: (i32, i32)| {
+        Vec2::new(
+            position.0 as f32 * CELL_SIZE + HALF_CELL_SIZE,
+            position.1 as f32 * CELL_SIZE + HALF_CELL_SIZE,
+        )
+    };
+
+    for position in brush_data.drag_cells() {
+        gizmos.rect_2d(
+            to_center(position),
+            Vec2::splat(CELL_SIZE * 0.7),
+            Color::srgba(1.0, 1.0, 1.0, 0.6),
+        );
+    }
+
+    // Connect the cells the drag started and ends on
+    gizmos.line_2d(
+        to_center(start),
+        to_center(end),
+        Color::srgba(1.0, 1.0, 0.0, 0.4),
+    );
+}
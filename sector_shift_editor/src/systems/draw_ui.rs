@@ -4,8 +4,11 @@ use bevy_egui::{EguiContexts, egui};
 use sector_shift_core::prelude::*;
 
 use crate::{
-    data::BrushType,
-    resources::{BrushData, MapData, UiState},
+    data::{BrushType, EditCommand, MapDialog, ResizeAnchor, ToolType},
+    functions::{PaletteEntry, asset_palette, palette_entries},
+    resources::{
+        BrushData, EditHistory, LevelBrowser, LevelTabs, MapData, Playtest, Problems, Selection, UiState,
+    },
 };
 
 pub fn draw_ui(
@@ -13,17 +16,101 @@ pub fn draw_ui(
     mut map_data: ResMut<MapData>,
     mut brush_data: ResMut<BrushData>,
     mut ui_state: ResMut<UiState>,
+    mut history: ResMut<EditHistory>,
+    mut selection: ResMut<Selection>,
+    enemy_library: Res<EnemyLibrary>,
+    item_library: Res<ItemLibrary>,
+    env_library: Res<EnvObjLibrary>,
+    sound_library: Res<SoundLibrary>,
+    mut level_tabs: ResMut<LevelTabs>,
+    mut level_browser: ResMut<LevelBrowser>,
+    mut playtest: ResMut<Playtest>,
+    problems: Res<Problems>,
 ) {
+    let enemy_entries = palette_entries(
+        &mut contexts,
+        enemy_library.map.values().map(|enemy| (&enemy.id, &enemy.sprite)),
+    );
+    let item_entries = palette_entries(
+        &mut contexts,
+        item_library.map.values().map(|item| (&item.id, &item.sprite)),
+    );
+
+    // Decorations are models, listed by name only. Global ones like the skybox can't be placed
+    let mut env_entries: Vec<_> = env_library
+        .map
+        .values()
+        .filter(|env| env.placeable)
+        .map(|env| PaletteEntry {
+            id: env.id.clone(),
+            thumbnail: None,
+        })
+        .collect();
+    env_entries.sort_by(|a, b| a.id.cmp(&b.id));
+
     let Ok(ctx) = contexts.ctx_mut() else {
         return;
     };
     egui::Window::new("SectorShift Editor").default_width(200.0).show(ctx, |ui| {
         ui.heading("Map Name:");
         ui.text_edit_singleline(&mut ui_state.level_name);
+        ui.horizontal(|ui| {
+            let before = map_data.level.par_time;
+            let mut par_time = before;
+            let mut has_par_time = par_time.is_some();
+            if ui.checkbox(&mut has_par_time, "Par Time (s):").changed() {
+                par_time = has_par_time.then_some(60.0);
+            }
+            if let Some(value) = par_time.as_mut() {
+                ui.add(egui::DragValue::new(value).range(0.0..=3600.0).speed(1.0));
+            }
+            // Only touch the level when the value changes so its sprites aren't rebuilt every frame
+            if par_time != before {
+                map_data.level.par_time = par_time;
+                // Dragging the value is one stroke, closed when the mouse is released
+                history.begin_stroke("Change Par Time");
+                history.record(EditCommand::ParTime {
+                    before,
+                    after: par_time,
+                });
+            }
+        });
+        ui.horizontal(|ui| {
+            let before = map_data.level.music.clone();
+            let mut music = before.clone();
+            let mut sounds: Vec<_> = sound_library.map.keys().collect();
+            sounds.sort();
+            egui::ComboBox::from_label("Music").selected_text(music.as_deref().unwrap_or("None")).show_ui(
+                ui,
+                |ui| {
+                    ui.selectable_value(&mut music, None, "None");
+                    for sound in sounds {
+                        ui.selectable_value(&mut music, Some(sound.clone()), sound.as_str());
+                    }
+                },
+            );
+            if music != before {
+                map_data.level.music = music.clone();
+                history.begin_stroke("Change Music");
+                history.record(EditCommand::Music {
+                    before,
+                    after: music,
+                });
+            }
+        });
         ui.separator();
 
         ui.heading("Brush Settings");
 
+        ui.label("Tool:");
+        ui.horizontal_wrapped(|ui| {
+            for tool in ToolType::ALL {
+                if ui.selectable_value(&mut brush_data.tool, tool, tool.name()).changed() {
+                    brush_data.drag = None;
+                }
+            }
+        });
+
         ui.label("Brush Select:");
         ui.horizontal(|ui| {
             ui.selectable_value(
@@ -58,11 +145,23 @@ pub fn draw_ui(
                 brush_data.brush = BrushType::Enemy(ui_state.enemy_name.clone());
             }
 
+            let is_env = matches!(brush_data.brush, BrushType::Env(_));
+            if ui.selectable_label(is_env, "Decoration").clicked() {
+                brush_data.brush = BrushType::Env(ui_state.env_name.clone());
+            }
+
             let is_exit = matches!(brush_data.brush, BrushType::Exit(_));
             if ui.selectable_label(is_exit, "Exit").clicked() {
                 brush_data.brush = BrushType::Exit(ui_state.exit_name.clone());
             }
 
+            ui.selectable_value(&mut brush_data.brush, BrushType::Secret, "Secret");
+
+            let is_patrol_turn = matches!(brush_data.brush, BrushType::PatrolTurn(_));
+            if ui.selectable_label(is_patrol_turn, "Patrol Turn").clicked() {
+                brush_data.brush = BrushType::PatrolTurn(ui_state.patrol_turn_direction);
+            }
+
             ui.selectable_value(
                 &mut brush_data.brush,
                 BrushType::EraseObject,
@@ -128,27 +227,115 @@ pub fn draw_ui(
                     brush_data.brush = BrushType::PlayerStart(ui_state.player_start_direction);
                 }
             });
-        } else if let BrushType::Item(_) = brush_data.brush {
+        } else if let BrushType::PatrolTurn(_) = brush_data.brush {
             ui.horizontal(|ui| {
-                ui.label("Item Name:");
-                if ui.text_edit_singleline(&mut ui_state.item_name).changed() {
-                    brush_data.brush = BrushType::Item(ui_state.item_name.clone());
+                ui.label("Turn Towards:");
+                let mut changed = false;
+                for (direction, name) in [
+                    (Direction::NORTH, "North"),
+                    (Direction::SOUTH, "South"),
+                    (Direction::EAST, "East"),
+                    (Direction::WEST, "West"),
+                ] {
+                    changed |= ui.radio_value(&mut ui_state.patrol_turn_direction, direction, name).changed();
+                }
+                if changed {
+                    brush_data.brush = BrushType::PatrolTurn(ui_state.patrol_turn_direction);
                 }
             });
+        } else if let BrushType::Item(_) = brush_data.brush {
+            ui.label("Item:");
+            if let Some(id) = asset_palette(ui, &item_entries, &ui_state.item_name) {
+                ui_state.item_name = id;
+                brush_data.brush = BrushType::Item(ui_state.item_name.clone());
+            }
         } else if let BrushType::Enemy(_) = brush_data.brush {
+            ui.label("Enemy:");
+            if let Some(id) = asset_palette(ui, &enemy_entries, &ui_state.enemy_name) {
+                ui_state.enemy_name = id;
+                brush_data.brush = BrushType::Enemy(ui_state.enemy_name.clone());
+            }
+        } else if let BrushType::Env(_) = brush_data.brush {
+            ui.label("Decoration:");
+            if let Some(id) = asset_palette(ui, &env_entries, &ui_state.env_name) {
+                ui_state.env_name = id;
+                brush_data.brush = BrushType::Env(ui_state.env_name.clone());
+            }
+        } else if let BrushType::Exit(_) = brush_data.brush {
             ui.horizontal(|ui| {
-                ui.label("Enemy Name:");
-                if ui.text_edit_singleline(&mut ui_state.enemy_name).changed() {
-                    brush_data.brush = BrushType::Enemy(ui_state.enemy_name.clone());
+                ui.label("Exit To:");
+                let mut changed = false;
+                let exit_name = &mut ui_state.exit_name;
+                egui::ComboBox::from_id_salt("exit_target").selected_text(exit_name.as_str()).show_ui(
+                    ui,
+                    |ui| {
+                        for id in level_browser.ids() {
+                            changed |= ui.selectable_value(exit_name, id.to_string(), id).changed();
+                        }
+                    },
+                );
+                if ui.button("Refresh").clicked()
+                    && let Err(e) = level_browser.refresh()
+                {
+                    ui_state.status_message = format!("Error Listing Levels: {e}");
+                }
+                if changed {
+                    brush_data.brush = BrushType::Exit(ui_state.exit_name.clone());
                 }
             });
-        } else if let BrushType::Exit(_) = brush_data.brush {
+        }
+
+        if matches!(
+            brush_data.brush,
+            BrushType::Item(_) | BrushType::Enemy(_) | BrushType::Env(_)
+        ) {
             ui.horizontal(|ui| {
-                ui.label("Exit Name:");
-                if ui.text_edit_singleline(&mut ui_state.exit_name).changed() {
-                    brush_data.brush = BrushType::Exit(ui_state.exit_name.clone());
+                ui.label("Min Difficulty:");
+                egui::ComboBox::from_id_salt("min_difficulty")
+                    .selected_text(brush_data.properties.min_difficulty.name())
+                    .show_ui(ui, |ui| {
+                        for difficulty in DifficultyLevel::ALL {
+                            ui.selectable_value(
+                                &mut brush_data.properties.min_difficulty,
+                                difficulty,
+                                difficulty.name(),
+                            );
+                        }
+                    });
+            });
+        }
+
+        if brush_data.tool == ToolType::Select || selection.clipboard.is_some() {
+            ui.label("Selection:");
+            ui.weak("Click an object to inspect it");
+            ui.horizontal_wrapped(|ui| {
+                let has_selection = selection.rectangle.is_some();
+                if ui.add_enabled(has_selection, egui::Button::new("Copy")).clicked() {
+                    selection.copy(&map_data.level);
+                }
+                if ui.add_enabled(has_selection, egui::Button::new("Cut")).clicked() {
+                    selection.cut(&mut map_data.level, &mut history);
+                }
+                if ui.add_enabled(has_selection, egui::Button::new("Delete")).clicked() {
+                    selection.delete(&mut map_data.level, &mut history, "Delete");
+                }
+                if ui.add_enabled(selection.clipboard.is_some(), egui::Button::new("Paste")).clicked() {
+                    selection.start_paste();
                 }
             });
+            if let Some(clipboard) = selection.clipboard.as_mut() {
+                ui.horizontal(|ui| {
+                    if ui.button("Rotate").clicked() {
+                        clipboard.rotate_clockwise();
+                    }
+                    if ui.button("Flip H").clicked() {
+                        clipboard.flip_horizontal();
+                    }
+                    if ui.button("Flip V").clicked() {
+                        clipboard.flip_vertical();
+                    }
+                });
+            }
         }
 
         ui.separator();
@@ -157,23 +344,119 @@ pub fn draw_ui(
             // SAVE BUTTON
             if ui.button("Save Map").clicked() {
                 map_data.level.id = ui_state.level_name.clone();
-                if let Err(e) = map_data.level.save() {
+                let active = level_tabs.active();
+                if let Err(e) = level_tabs.save(active, &map_data, &mut history) {
                     ui_state.status_message = format!("Error Saving Map: {}", e);
                 } else {
-                    ui_state.status_message = "Map Saved Successfully".to_string();
+                    let errors = problems.count(ProblemSeverity::Error);
+                    ui_state.status_message = if errors == 0 {
+                        "Map Saved Successfully".to_string()
+                    } else {
+                        format!("Map Saved with {errors} errors, see Problems")
+                    };
+                    if let Err(e) = level_browser.refresh() {
+                        ui_state.status_message = format!("Error Listing Levels: {e}");
+                    }
                 }
             }
 
             // LOAD BUTTON
             if ui.button("Load Map").clicked() {
                 if let Ok(level) = Level::try_load(ui_state.level_name.clone()) {
-                    ui_state.player_start_direction = level.player_start.1;
-                    map_data.level = level;
-                    ui_state.status_message = "Map Loaded Successfully".to_string();
+                    let id = level.id.clone();
+                    let opened = level_tabs.open(level, true, &mut map_data, &mut history);
+                    ui_state.show_level(&map_data.level);
+                    selection.clear();
+                    ui_state.status_message = if opened {
+                        "Map Loaded Successfully".to_string()
+                    } else {
+                        format!("{id} is already open, switched to its tab")
+                    };
                 } else {
                     ui_state.status_message = "Error Loading Map".to_string();
                 }
             }
         });
+
+        ui.horizontal(|ui| {
+            if ui.button("Play").on_hover_text("F5, Shift+F5 to start from the hovered cell").clicked() {
+                ui_state.status_message = match playtest.launch(&map_data.level, None) {
+                    Ok(()) => "Playtest Started".to_string(),
+                    Err(e) => e.to_string(),
+                };
+            }
+            if ui.button("New Map").clicked() {
+                ui_state.dialog = Some(MapDialog::New {
+                    id: "new_level".to_string(),
+                    size: (map_data.level.width(), map_data.level.height()),
+                    fill: TileType::Wall,
+                });
+            }
+            if ui.button("Resize Map").clicked() {
+                ui_state.dialog = Some(MapDialog::Resize {
+                    size: (map_data.level.width(), map_data.level.height()),
+                    anchor: ResizeAnchor::default(),
+                    losses: None,
+                });
+            }
+        });
+
+        ui.label(&ui_state.status_message);
+
+        ui.separator();
+
+        egui::CollapsingHeader::new("History").default_open(false).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                if ui.add_enabled(history.can_undo(), egui::Button::new("Undo")).clicked()
+                    && let Some(label) = history.undo(&mut map_data.level)
+                {
+                    ui_state.status_message = format!("Undid {label}");
+                }
+                if ui.add_enabled(history.can_redo(), egui::Button::new("Redo")).clicked()
+                    && let Some(label) = history.redo(&mut map_data.level)
+                {
+                    ui_state.status_message = format!("Redid {label}");
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Depth:");
+                let mut max_depth = history.max_depth();
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut max_depth)
+                            .range(EditHistory::DEPTH_RANGE.0..=EditHistory::DEPTH_RANGE.1),
+                    )
+                    .changed()
+                {
+                    history.set_max_depth(max_depth);
+                }
+            });
+
+            // Clicking an entry walks the history to just after it
+            let mut undo_steps = 0;
+            let mut redo_steps = 0;
+            egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                let undo_labels: Vec<_> = history.undo_labels().collect();
+                let undo_count = undo_labels.len();
+                for (i, label) in undo_labels.into_iter().enumerate() {
+                    if ui.selectable_label(i + 1 == undo_count, label).clicked() {
+                        undo_steps = undo_count - (i + 1);
+                    }
+                }
+                for (i, label) in history.redo_labels().enumerate() {
+                    let text = egui::RichText::new(label).weak();
+                    if ui.selectable_label(false, text).clicked() {
+                        redo_steps = i + 1;
+                    }
+                }
+            });
+            for _ in 0..undo_steps {
+                history.undo(&mut map_data.level);
+            }
+            for _ in 0..redo_steps {
+                history.redo(&mut map_data.level);
+            }
+        });
     });
 }
@@ -0,0 +1,62 @@
+use bevy::{
+    input::mouse::{AccumulatedMouseScroll, MouseScrollUnit},
+    prelude::*,
+    window::PrimaryWindow,
+};
+use bevy_egui::EguiContexts;
+
+use crate::functions::zoom_camera;
+
+/// Scale factor applied per line scrolled
+const ZOOM_STEP: f32 = 1.1;
+/// Pixels scrolled by touchpads that count as one line
+const PIXELS_PER_LINE: f32 = 50.0;
+
+/// The mouse wheel zooms around the cursor, dragging with the middle button pans the map
+pub fn handle_camera_mouse_input(
+    mut contexts: EguiContexts,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    scroll: Res<AccumulatedMouseScroll>,
+    // The cursor position the last frame of a middle drag
+    mut drag: Local<Option<Vec2>>,
+    q_window: Single<&Window, With<PrimaryWindow>>,
+    q_camera: Single<(&Camera, &GlobalTransform, &mut Transform, &mut Projection)>,
+) {
+    let cursor = q_window.cursor_position();
+    let over_ui = contexts.ctx_mut().is_ok_and(|ctx| ctx.is_pointer_over_area() || ctx.wants_pointer_input());
+
+    let mut delta = Vec2::ZERO;
+    if !mouse_buttons.pressed(MouseButton::Middle) {
+        *drag = None;
+    } else if mouse_buttons.just_pressed(MouseButton::Middle) && !over_ui {
+        *drag = cursor;
+    } else if let (Some(last), Some(cursor)) = (*drag, cursor) {
+        delta = cursor - last;
+        *drag = Some(cursor);
+    }
+
+    let lines = match scroll.unit {
+        MouseScrollUnit::Line => scroll.delta.y,
+        MouseScrollUnit::Pixel => scroll.delta.y / PIXELS_PER_LINE,
+    };
+    let zooming = lines != 0.0 && !over_ui;
+    // Only borrow the camera mutably when it moves so it isn't marked as changed every frame
+    if delta == Vec2::ZERO && !zooming {
+        return;
+    }
+
+    let (camera, camera_transform, mut transform, mut projection) = q_camera.into_inner();
+    let Projection::Orthographic(projection) = &mut *projection else {
+        return;
+    };
+
+    // Screen y points down while world y points up
+    transform.translation.x -= delta.x * projection.scale;
+    transform.translation.y += delta.y * projection.scale;
+
+    if zooming {
+        let anchor = cursor.and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok());
+        let scale = projection.scale * ZOOM_STEP.powf(-lines);
+        zoom_camera(anchor, scale, &mut transform, projection);
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use bevy_egui::EguiContexts;
+
+use crate::resources::{EditHistory, MapData, Selection, UiState};
+
+/// Ctrl+C copies, Ctrl+X cuts, Ctrl+V pastes and Delete clears the selection.
+/// While pasting R rotates, H flips horizontally and V flips vertically.
+pub fn handle_clipboard_shortcuts(
+    mut contexts: EguiContexts,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut map_data: ResMut<MapData>,
+    mut history: ResMut<EditHistory>,
+    mut selection: ResMut<Selection>,
+    mut ui_state: ResMut<UiState>,
+) {
+    // Text fields handle their own clipboard
+    if let Ok(ctx) = contexts.ctx_mut()
+        && ctx.wants_keyboard_input()
+    {
+        return;
+    }
+
+    let message = if keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        if keyboard_input.just_pressed(KeyCode::KeyC) {
+            selection.copy(&map_data.level).then_some("Copied")
+        } else if keyboard_input.just_pressed(KeyCode::KeyX) {
+            selection.cut(&mut map_data.level, &mut history).then_some("Cut")
+        } else if keyboard_input.just_pressed(KeyCode::KeyV) {
+            selection.start_paste().then_some("Click to place, right click to cancel")
+        } else {
+            None
+        }
+    } else if keyboard_input.just_pressed(KeyCode::Delete) {
+        selection.delete(&mut map_data.level, &mut history, "Delete").then_some("Deleted")
+    } else if selection.pasting
+        && let Some(clipboard) = selection.clipboard.as_mut()
+    {
+        if keyboard_input.just_pressed(KeyCode::KeyR) {
+            clipboard.rotate_clockwise();
+        } else if keyboard_input.just_pressed(KeyCode::KeyH) {
+            clipboard.flip_horizontal();
+        } else if keyboard_input.just_pressed(KeyCode::KeyV) {
+            clipboard.flip_vertical();
+        }
+        None
+    } else {
+        None
+    };
+
+    if let Some(message) = message {
+        ui_state.status_message = message.to_string();
+    }
+}
@@ -0,0 +1,21 @@
+use bevy::{prelude::*, window::WindowCloseRequested};
+
+use crate::resources::{EditHistory, LevelTabs};
+
+/// Closing the window quits right away unless a level has unsaved changes
+pub fn handle_close_requests(
+    mut close_requests: MessageReader<WindowCloseRequested>,
+    mut level_tabs: ResMut<LevelTabs>,
+    history: Res<EditHistory>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    if close_requests.read().count() == 0 {
+        return;
+    }
+
+    if level_tabs.any_dirty(&history) {
+        level_tabs.pending_quit = true;
+    } else {
+        exit.write(AppExit::Success);
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy_egui::EguiContexts;
+
+use crate::resources::{EditHistory, MapData, UiState};
+
+/// Ctrl+Z undoes, Ctrl+Y or Ctrl+Shift+Z redoes
+pub fn handle_history_shortcuts(
+    mut contexts: EguiContexts,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut map_data: ResMut<MapData>,
+    mut history: ResMut<EditHistory>,
+    mut ui_state: ResMut<UiState>,
+) {
+    // Text fields handle their own undo
+    if let Ok(ctx) = contexts.ctx_mut()
+        && ctx.wants_keyboard_input()
+    {
+        return;
+    }
+
+    if !keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        return;
+    }
+    let shift = keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    let z = keyboard_input.just_pressed(KeyCode::KeyZ);
+    let message = if z && !shift {
+        history.undo(&mut map_data.level).map(|label| format!("Undid {label}"))
+    } else if keyboard_input.just_pressed(KeyCode::KeyY) || (z && shift) {
+        history.redo(&mut map_data.level).map(|label| format!("Redid {label}"))
+    } else {
+        None
+    };
+
+    if let Some(message) = message {
+        ui_state.status_message = message;
+    }
+}
@@ -1,27 +1,61 @@
 use bevy::prelude::*;
+use bevy_egui::EguiContexts;
+use sector_shift_core::prelude::*;
 
-const SPEED: f32 = 10.0;
+use crate::{
+    data::EditorAction,
+    functions::{fit_camera, zoom_camera},
+    resources::MapData,
+};
+
+/// Screen pixels panned per second, so the map moves at the same apparent speed at any zoom
+const PAN_SPEED: f32 = 600.0;
+/// How fast holding a zoom key changes the scale, as a factor per second
+const ZOOM_SPEED: f32 = 3.0;
 
 pub fn handle_keyboard_input(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut q_camera: Single<&mut Transform, With<Camera>>,
+    mut contexts: EguiContexts,
+    time: Res<Time>,
+    actions: Res<ActionState<EditorAction>>,
+    map_data: Res<MapData>,
+    q_camera: Single<(&Camera, &mut Transform, &mut Projection)>,
 ) {
-    let mut transform = Vec2::ZERO;
-    if keyboard_input.pressed(KeyCode::KeyW) {
-        transform.y += 1.0;
+    if let Ok(ctx) = contexts.ctx_mut()
+        && ctx.wants_keyboard_input()
+    {
+        return;
     }
-    if keyboard_input.pressed(KeyCode::KeyS) {
-        transform.y -= 1.0;
+    let fit = actions.just_pressed(EditorAction::FitMap);
+    let zoom = actions.axis(EditorAction::ZoomIn, EditorAction::ZoomOut);
+    let pan = Vec2::new(
+        actions.axis(EditorAction::PanLeft, EditorAction::PanRight),
+        actions.axis(EditorAction::PanDown, EditorAction::PanUp),
+    )
+    .clamp_length_max(1.0);
+    // Only borrow the camera mutably when it moves so it isn't marked as changed every frame
+    if !fit && zoom == 0.0 && pan == Vec2::ZERO {
+        return;
     }
-    if keyboard_input.pressed(KeyCode::KeyA) {
-        transform.x -= 1.0;
+
+    let (camera, mut transform, mut projection) = q_camera.into_inner();
+    let Projection::Orthographic(projection) = &mut *projection else {
+        return;
+    };
+
+    if fit {
+        let viewport_size = camera.logical_viewport_size().unwrap_or_default();
+        fit_camera(&map_data.level, viewport_size, &mut transform, projection);
+        return;
     }
-    if keyboard_input.pressed(KeyCode::KeyD) {
-        transform.x += 1.0;
+
+    if zoom != 0.0 {
+        let scale = projection.scale * ZOOM_SPEED.powf(zoom * time.delta_secs());
+        zoom_camera(None, scale, &mut transform, projection);
     }
 
-    if transform != Vec2::ZERO {
-        q_camera.translation.x += transform.x * SPEED;
-        q_camera.translation.y += transform.y * SPEED;
+    if pan != Vec2::ZERO {
+        let distance = PAN_SPEED * projection.scale * time.delta_secs();
+        transform.translation.x += pan.x * distance;
+        transform.translation.y += pan.y * distance;
     }
 }
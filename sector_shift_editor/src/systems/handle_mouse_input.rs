@@ -2,19 +2,58 @@ use bevy::{prelude::*, window::PrimaryWindow};
 use bevy_egui::EguiContexts;
 
 use crate::{
-    CELL_SIZE,
-    data::BrushType,
-    resources::{BrushData, MapData},
+    data::ToolType,
+    functions::{apply_brush, cursor_cell, flood_fill},
+    resources::{BrushData, EditHistory, MapData, Selection},
 };
 
 pub fn handle_mouse_input(
     mut contexts: EguiContexts,
     mouse_input: Res<ButtonInput<MouseButton>>,
     mut map_data: ResMut<MapData>,
-    brush_data: Res<BrushData>,
+    mut history: ResMut<EditHistory>,
+    mut brush_data: ResMut<BrushData>,
+    selection: Res<Selection>,
     q_window: Single<&Window, With<PrimaryWindow>>,
     q_camera: Single<(&Camera, &GlobalTransform)>,
 ) {
+    let (camera, camera_transform) = *q_camera;
+    let cell = cursor_cell(*q_window, camera, camera_transform);
+
+    // Shapes are applied on release, even if the cursor ended up over the UI
+    if mouse_input.just_released(MouseButton::Left)
+        && let Some((start, end)) = brush_data.drag.take()
+    {
+        history.begin_stroke(format!(
+            "{}: {}",
+            brush_data.tool.name(),
+            brush_data.brush.label()
+        ));
+        for position in brush_data.tool.shape_cells(start, end) {
+            apply_brush(&mut map_data.level, &mut history, &brush_data, position);
+        }
+    }
+
+    // Everything painted while the button was held is undone together
+    if !mouse_input.pressed(MouseButton::Left) {
+        history.end_stroke();
+        brush_data.painting = false;
+    }
+
+    // Right click cancels a shape
+    if mouse_input.just_pressed(MouseButton::Right) {
+        brush_data.drag = None;
+    }
+
+    // Keep following the cursor while dragging a shape
+    if mouse_input.pressed(MouseButton::Left)
+        && let Some(cell) = cell
+        && let Some((_, end)) = brush_data.drag.as_mut()
+    {
+        *end = cell;
+        return;
+    }
+
     // Don't click through
     if let Ok(ctx) = contexts.ctx_mut()
         && (ctx.is_pointer_over_area() || ctx.wants_pointer_input())
@@ -22,29 +61,40 @@ pub fn handle_mouse_input(
         return;
     }
 
-    if mouse_input.pressed(MouseButton::Left) {
-        let (camera, camera_transform) = *q_camera;
-        let window = *q_window;
-
-        if let Some(world_position) = window
-            .cursor_position()
-            .and_then(|cursor_pos| camera.viewport_to_world_2d(camera_transform, cursor_pos).ok())
-        {
-            let cell_x = (world_position.x / CELL_SIZE).floor() as i32;
-            let cell_y = (world_position.y / CELL_SIZE).floor() as i32;
-
-            let position = (cell_x, cell_y);
-
-            match &brush_data.brush {
-                BrushType::PlayerStart(direction) => map_data.level.set_player_start(position, *direction),
-                BrushType::Tile(tile_type) => {
-                    map_data.level.set_tile(position, *tile_type);
-                },
-                BrushType::Enemy(id) => map_data.level.add_enemy(position, id),
-                BrushType::Exit(id) => map_data.level.add_exit(position, id),
-                BrushType::Item(id) => map_data.level.add_item(position, id),
-                BrushType::EraseObject => map_data.level.remove_object(position),
+    // The clipboard is being placed instead
+    if selection.pasting {
+        return;
+    }
+
+    let Some(position) = cell else {
+        return;
+    };
+
+    match brush_data.tool {
+        ToolType::Paint => {
+            if mouse_input.just_pressed(MouseButton::Left) {
+                brush_data.painting = true;
             }
-        }
+            if brush_data.painting && mouse_input.pressed(MouseButton::Left) {
+                history.begin_stroke(brush_data.brush.label());
+                apply_brush(&mut map_data.level, &mut history, &brush_data, position);
+            }
+        },
+        ToolType::Fill => {
+            if mouse_input.just_pressed(MouseButton::Left) {
+                history.begin_stroke(format!("Fill: {}", brush_data.brush.label()));
+                for position in flood_fill(&map_data.level.tiles, position) {
+                    apply_brush(&mut map_data.level, &mut history, &brush_data, position);
+                }
+                history.end_stroke();
+            }
+        },
+        ToolType::Line | ToolType::Rectangle | ToolType::FilledRectangle | ToolType::Circle => {
+            if mouse_input.just_pressed(MouseButton::Left) {
+                brush_data.drag = Some((position, position));
+            }
+        },
+        // Handled by handle_selection_input
+        ToolType::Select => {},
     }
 }
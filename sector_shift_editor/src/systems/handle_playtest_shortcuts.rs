@@ -0,0 +1,45 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_egui::EguiContexts;
+
+use crate::{
+    functions::cursor_cell,
+    resources::{MapData, Playtest, UiState},
+};
+
+/// F5 plays the level from its player start, Shift+F5 from the hovered cell
+pub fn handle_playtest_shortcuts(
+    mut contexts: EguiContexts,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    map_data: Res<MapData>,
+    mut playtest: ResMut<Playtest>,
+    mut ui_state: ResMut<UiState>,
+    q_window: Single<&Window, With<PrimaryWindow>>,
+    q_camera: Single<(&Camera, &GlobalTransform)>,
+) {
+    if let Ok(ctx) = contexts.ctx_mut()
+        && ctx.wants_keyboard_input()
+    {
+        return;
+    }
+    if !keyboard_input.just_pressed(KeyCode::F5) {
+        return;
+    }
+
+    let start = if keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        let (camera, camera_transform) = *q_camera;
+        let Some(cell) = cursor_cell(*q_window, camera, camera_transform)
+            .filter(|cell| map_data.level.tiles.in_bounds(*cell))
+        else {
+            ui_state.status_message = "Hover a cell of the map to play from it".to_string();
+            return;
+        };
+        Some((cell, map_data.level.player_start.1))
+    } else {
+        None
+    };
+
+    ui_state.status_message = match playtest.launch(&map_data.level, start) {
+        Ok(()) => "Playtest Started".to_string(),
+        Err(e) => e.to_string(),
+    };
+}
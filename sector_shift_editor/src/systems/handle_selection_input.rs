@@ -0,0 +1,69 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_egui::EguiContexts;
+
+use crate::{
+    data::ToolType,
+    functions::cursor_cell,
+    resources::{BrushData, EditHistory, MapData, Selection, UiState},
+};
+
+/// Dragging with the select tool selects a region, clicking while pasting places the clipboard
+pub fn handle_selection_input(
+    mut contexts: EguiContexts,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut map_data: ResMut<MapData>,
+    mut history: ResMut<EditHistory>,
+    mut selection: ResMut<Selection>,
+    mut ui_state: ResMut<UiState>,
+    brush_data: Res<BrushData>,
+    q_window: Single<&Window, With<PrimaryWindow>>,
+    q_camera: Single<(&Camera, &GlobalTransform)>,
+) {
+    let (camera, camera_transform) = *q_camera;
+    let cell = cursor_cell(*q_window, camera, camera_transform);
+
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        if selection.pasting {
+            selection.pasting = false;
+        } else {
+            selection.clear();
+        }
+    }
+
+    if mouse_input.just_released(MouseButton::Left) {
+        selection.drag_start = None;
+    }
+
+    if let Some(start) = selection.drag_start
+        && let Some(cell) = cell
+    {
+        selection.select(&map_data.level, start, cell);
+        return;
+    }
+
+    // Don't click through
+    if let Ok(ctx) = contexts.ctx_mut()
+        && (ctx.is_pointer_over_area() || ctx.wants_pointer_input())
+    {
+        return;
+    }
+
+    let Some(cell) = cell else {
+        return;
+    };
+
+    if selection.pasting {
+        if mouse_input.just_pressed(MouseButton::Right) {
+            selection.pasting = false;
+        } else if mouse_input.just_pressed(MouseButton::Left)
+            && let Some(origin) = selection.paste_origin(cell)
+            && selection.place(&mut map_data.level, &mut history, origin)
+        {
+            ui_state.status_message = "Pasted".to_string();
+        }
+    } else if brush_data.tool == ToolType::Select && mouse_input.just_pressed(MouseButton::Left) {
+        selection.drag_start = Some(cell);
+        selection.select(&map_data.level, cell, cell);
+    }
+}
@@ -1,14 +1,62 @@
 mod draw_grid;
 pub use self::draw_grid::*;
 
+mod draw_inspector;
+pub use self::draw_inspector::*;
+
+mod draw_level_tabs;
+pub use self::draw_level_tabs::*;
+
+mod draw_map_dialog;
+pub use self::draw_map_dialog::*;
+
+mod draw_patrol_routes;
+pub use self::draw_patrol_routes::*;
+
+mod draw_problems;
+pub use self::draw_problems::*;
+
+mod draw_selection;
+pub use self::draw_selection::*;
+
+mod draw_status_line;
+pub use self::draw_status_line::*;
+
+mod draw_tool_preview;
+pub use self::draw_tool_preview::*;
+
 mod draw_ui;
 pub use self::draw_ui::*;
 
+mod handle_camera_mouse_input;
+pub use self::handle_camera_mouse_input::*;
+
+mod handle_clipboard_shortcuts;
+pub use self::handle_clipboard_shortcuts::*;
+
+mod handle_close_requests;
+pub use self::handle_close_requests::*;
+
+mod handle_history_shortcuts;
+pub use self::handle_history_shortcuts::*;
+
 mod handle_keyboard_input;
 pub use self::handle_keyboard_input::*;
 
 mod handle_mouse_input;
 pub use self::handle_mouse_input::*;
 
+mod handle_playtest_shortcuts;
+pub use self::handle_playtest_shortcuts::*;
+
+mod handle_selection_input;
+pub use self::handle_selection_input::*;
+
 mod spawn_camera;
 pub use self::spawn_camera::*;
+
+mod sync_object_sprites;
+pub use self::sync_object_sprites::*;
+
+mod validate_level;
+pub use self::validate_level::*;
@@ -1,11 +1,20 @@
-use bevy::prelude::*;
+use bevy::{prelude::*, window::PrimaryWindow};
 
-use crate::CELL_SIZE;
+use crate::{functions::fit_camera, resources::MapData};
 
-pub fn spawn_camera(mut commands: Commands) {
-    commands.spawn((
-        Camera2d,
-        // TODO: Do not hard code editor map size
-        Transform::from_xyz(32.0 / 2.0 * CELL_SIZE, 32.0 / 2.0 * CELL_SIZE, 0.0),
-    ));
+pub fn spawn_camera(
+    mut commands: Commands,
+    map_data: Res<MapData>,
+    q_window: Single<&Window, With<PrimaryWindow>>,
+) {
+    let mut transform = Transform::default();
+    let mut projection = OrthographicProjection::default_2d();
+    fit_camera(
+        &map_data.level,
+        q_window.size(),
+        &mut transform,
+        &mut projection,
+    );
+
+    commands.spawn((Camera2d, transform, Projection::Orthographic(projection)));
 }
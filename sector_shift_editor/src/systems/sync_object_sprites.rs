@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use sector_shift_core::{maps::MapObject, prelude::*};
+
+use crate::{
+    CELL_SIZE, HALF_CELL_SIZE, components::ObjectSprite, functions::object_sprite, resources::MapData,
+};
+
+/// Rebuilds the sprites of placed enemies and items whenever the level or the libraries change
+pub fn sync_object_sprites(
+    mut commands: Commands,
+    map_data: Res<MapData>,
+    enemy_library: Res<EnemyLibrary>,
+    item_library: Res<ItemLibrary>,
+    q_sprites: Query<Entity, With<ObjectSprite>>,
+) {
+    if !(map_data.is_changed() || enemy_library.is_changed() || item_library.is_changed()) {
+        return;
+    }
+
+    for entity in q_sprites.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    for (position, object) in map_data.level.objects.iter() {
+        let Some(image) = object_sprite(object, &enemy_library, &item_library) else {
+            continue;
+        };
+        // Animated items show the first frame of their sheet
+        let rect = match object {
+            MapObject::Item(id, _) => item_library
+                .get(id)
+                .and_then(|item| item.animation.as_ref())
+                .map(|animation| animation.first_frame()),
+            _ => None,
+        };
+        commands.spawn((
+            ObjectSprite,
+            Sprite {
+                image,
+                rect,
+                custom_size: Some(Vec2::splat(CELL_SIZE * 0.8)),
+                ..Default::default()
+            },
+            Transform::from_xyz(
+                position.0 as f32 * CELL_SIZE + HALF_CELL_SIZE,
+                position.1 as f32 * CELL_SIZE + HALF_CELL_SIZE,
+                1.0,
+            ),
+        ));
+    }
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::resources::{LevelBrowser, MapData, Problems};
+
+/// Checks the level again whenever it or what its objects refer to changes
+pub fn validate_level(
+    map_data: Res<MapData>,
+    enemy_library: Res<EnemyLibrary>,
+    item_library: Res<ItemLibrary>,
+    env_library: Res<EnvObjLibrary>,
+    level_browser: Res<LevelBrowser>,
+    mut problems: ResMut<Problems>,
+) {
+    if !(map_data.is_changed()
+        || enemy_library.is_changed()
+        || item_library.is_changed()
+        || env_library.is_changed()
+        || level_browser.is_changed())
+    {
+        return;
+    }
+
+    problems.list = map_data.level.validate(|object| match object {
+        MapObject::Enemy(id, _) => enemy_library.get(id).is_some(),
+        MapObject::Item(id, _) => item_library.get(id).is_some(),
+        MapObject::Exit(id) => *id == map_data.level.id || level_browser.ids().any(|level_id| level_id == id),
+        MapObject::Env(id, _) => env_library.get(id).is_some_and(|env| env.placeable),
+        MapObject::Secret | MapObject::PatrolTurn(_) => true,
+    });
+}
@@ -1,6 +1,33 @@
 use bevy::prelude::*;
 
+use crate::{
+    actors::{
+        messages::{Damage, Killed},
+        systems::{
+            animate_directional_sprites, animate_enemy_models, apply_damage, decay_overheal, enemy_attacks,
+            patrol_enemies, play_enemy_reactions, revive_player, tick_power_ups,
+        },
+    },
+    states::system_sets::GameSet,
+};
+
 pub struct ActorsPlugin;
 impl Plugin for ActorsPlugin {
-    fn build(&self, app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        app.add_message::<Damage>().add_message::<Killed>();
+
+        app.add_systems(
+            Update,
+            (
+                (apply_damage, revive_player, play_enemy_reactions).chain(),
+                decay_overheal,
+                tick_power_ups,
+                patrol_enemies,
+                enemy_attacks,
+                animate_directional_sprites,
+                animate_enemy_models,
+            )
+                .in_set(GameSet::Running),
+        );
+    }
 }
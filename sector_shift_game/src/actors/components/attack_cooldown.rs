@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+/// Seconds before an enemy can attack again
+#[derive(Component, Reflect, Debug, Default, Clone, Copy)]
+#[reflect(Component)]
+pub struct AttackCooldown(pub f32);
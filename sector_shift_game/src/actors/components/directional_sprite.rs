@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+use sector_shift_core::enemies::data::EnemySpriteSheet;
+
+/// Draws a sprite sheet enemy, showing the frame of its [`EnemyAnimationState`](super::EnemyAnimationState)
+/// for the angle the camera sees it from
+#[derive(Component)]
+pub struct DirectionalSprite {
+    pub sheet: EnemySpriteSheet,
+    pub image: Handle<Image>,
+    /// The child entity with the billboard mesh showing the frame
+    pub billboard: Entity,
+    /// The column and row of the sheet currently shown, the material is only touched when it changes
+    pub shown: Option<(u32, u32)>,
+}
+
+impl DirectionalSprite {
+    pub fn new(sheet: EnemySpriteSheet, image: Handle<Image>, billboard: Entity) -> Self {
+        Self {
+            sheet,
+            image,
+            billboard,
+            shown: None,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+/// The animation an enemy is playing, shown by its [`DirectionalSprite`](super::DirectionalSprite)
+/// or model
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct EnemyAnimationState {
+    pub state: EnemyAnimation,
+    /// Seconds since the state started playing
+    pub elapsed: f32,
+}
+
+impl Default for EnemyAnimationState {
+    fn default() -> Self {
+        Self {
+            state: EnemyAnimation::Idle,
+            elapsed: 0.0,
+        }
+    }
+}
+
+impl EnemyAnimationState {
+    /// Switches to another state, starting its animation over
+    pub fn play(&mut self, state: EnemyAnimation) {
+        if self.state != state {
+            self.state = state;
+            self.elapsed = 0.0;
+        }
+    }
+
+    /// Switches to another state once the reaction playing, if any, has finished
+    pub fn play_after_reaction(&mut self, state: EnemyAnimation) {
+        if !self.state.is_reaction() {
+            self.play(state);
+        }
+    }
+}
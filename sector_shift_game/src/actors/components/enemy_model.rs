@@ -0,0 +1,28 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use sector_shift_core::prelude::*;
+
+/// Plays the clips of a glTF enemy for its [`EnemyAnimationState`](super::EnemyAnimationState)
+#[derive(Component)]
+pub struct EnemyModel {
+    pub graph: Handle<AnimationGraph>,
+    /// The node of the graph for each state that has a clip, always contains `Idle`
+    pub animations: HashMap<EnemyAnimation, AnimationNodeIndex>,
+    /// The entity of the scene with the `AnimationPlayer`, found once the scene has spawned
+    pub player: Option<Entity>,
+    /// The state whose clip is playing
+    pub shown: Option<EnemyAnimation>,
+}
+
+impl EnemyModel {
+    pub fn new(
+        graph: Handle<AnimationGraph>,
+        animations: HashMap<EnemyAnimation, AnimationNodeIndex>,
+    ) -> Self {
+        Self {
+            graph,
+            animations,
+            player: None,
+            shown: None,
+        }
+    }
+}
@@ -1,26 +1,67 @@
 use bevy::prelude::*;
 
+use crate::ARMOR_ABSORPTION;
+
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
 pub struct Health {
     pub current: i32,
+    /// Healing stops here, anything above it is overheal and decays back down
     pub max: i32,
+    /// Absorbs part of the damage taken until it runs out
+    pub armor: i32,
 }
 
 impl Health {
     pub fn new(max: i32) -> Self {
-        Self { current: max, max }
+        Self {
+            current: max,
+            max,
+            armor: 0,
+        }
     }
 
     pub fn is_alive(&self) -> bool {
         self.current > 0
     }
 
-    pub fn heal(&mut self, amount: i32) {
-        self.current = (self.current + amount).min(self.max);
+    pub fn take_damage(&mut self, amount: i32) {
+        let absorbed = ((amount as f32 * ARMOR_ABSORPTION) as i32).min(self.armor);
+        self.armor -= absorbed;
+        self.current -= amount - absorbed;
     }
 
-    pub fn take_damage(&mut self, amount: i32) {
-        self.current -= amount;
+    /// Lowers overheal by `amount` without going below the maximum
+    pub fn decay_overheal(&mut self, amount: i32) {
+        if self.current > self.max {
+            self.current = (self.current - amount).max(self.max);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Health;
+
+    #[test]
+    fn test_armor_absorbs_damage() {
+        let mut health = Health {
+            armor: 20,
+            ..Health::new(100)
+        };
+
+        // A third of the damage goes to the armor
+        health.take_damage(30);
+        assert_eq!(health.current, 80);
+        assert_eq!(health.armor, 10);
+
+        // Armor absorbs no more than is left of it
+        health.take_damage(60);
+        assert_eq!(health.current, 30);
+        assert_eq!(health.armor, 0);
+
+        health.take_damage(30);
+        assert_eq!(health.current, 0);
+        assert!(!health.is_alive());
     }
 }
@@ -1,5 +1,23 @@
+mod attack_cooldown;
+pub use self::attack_cooldown::*;
+
+mod directional_sprite;
+pub use self::directional_sprite::*;
+
+mod enemy_animation_state;
+pub use self::enemy_animation_state::*;
+
+mod enemy_model;
+pub use self::enemy_model::*;
+
 mod health;
 pub use self::health::*;
 
+mod patrol;
+pub use self::patrol::*;
+
 mod player;
 pub use self::player::*;
+
+mod power_ups;
+pub use self::power_ups::*;
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+/// An enemy walking its [`PatrolRoute`] until something alerts it
+#[derive(Component, Debug, Clone)]
+pub struct Patrol {
+    pub route: PatrolRoute,
+    /// The index of the cell it is walking to
+    pub target: usize,
+}
+
+impl Patrol {
+    pub fn new(route: PatrolRoute) -> Self {
+        Self {
+            target: route.next(0).unwrap_or_default(),
+            route,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use sector_shift_core::prelude::*;
+
+/// The power-ups of the player and the seconds left on each
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct PowerUps {
+    pub remaining: HashMap<PowerUp, f32>,
+}
+
+impl PowerUps {
+    pub fn is_active(&self, power_up: PowerUp) -> bool {
+        self.remaining(power_up) > 0.0
+    }
+
+    pub fn remaining(&self, power_up: PowerUp) -> f32 {
+        self.remaining.get(&power_up).copied().unwrap_or_default()
+    }
+
+    /// Counts the timers down, dropping the power-ups that run out
+    pub fn tick(&mut self, seconds: f32) {
+        self.remaining.retain(|_, remaining| {
+            *remaining -= seconds;
+            *remaining > 0.0
+        });
+    }
+}
@@ -1,33 +1,97 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
-use sector_shift_core::prelude::*;
+use sector_shift_core::{enemies::data::EnemyAppearance, prelude::*};
+
+use crate::{
+    ENEMY_SPRITE_HEIGHT,
+    actors::components::{AttackCooldown, DirectionalSprite, EnemyAnimationState, EnemyModel, Health},
+    perception::components::Perception,
+};
 
 /// Spawns an enemy with the following components:
 /// - Name
 /// - Enemy
-/// - SceneRoot
+/// - EnemyStats
+/// - Health
+/// - Perception
+/// - AttackCooldown
+/// - EnemyAnimationState
+/// - SoundOverrides
 /// - Transform
 /// - Collider (capsule)
 ///
+/// and depending on its appearance either:
+/// - SceneRoot
+/// - EnemyModel
+///
+/// or:
+/// - DirectionalSprite, with a billboard child showing the frame
+///
 pub fn spawn_enemy(
     commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
     enemy_library: &EnemyLibrary,
     enemy_id: &str,
     transform: Transform,
 ) -> Option<Entity> {
-    if let Some(definition) = enemy_library.get(enemy_id) {
-        let entity = commands
-            .spawn((
-                Name::new(definition.id.clone()),
-                Enemy,
-                SceneRoot(definition.scene.clone()),
-                transform,
-                Collider::capsule(0.5, 1.0), // Match sprite size
-            ))
-            .id();
-        Some(entity)
-    } else {
+    let Some(definition) = enemy_library.get(enemy_id) else {
         warn!("Enemy ID '{}' not found in EnemyLibrary.", enemy_id);
-        None
+        return None;
+    };
+
+    let entity = commands
+        .spawn((
+            Name::new(definition.id.clone()),
+            Enemy,
+            definition.stats.clone(),
+            Health::new(definition.stats.health),
+            Perception::new(&definition.stats),
+            AttackCooldown::default(),
+            EnemyAnimationState::default(),
+            definition.sounds.clone(),
+            transform,
+            Collider::capsule(0.5, 1.0), // Match sprite size
+        ))
+        .id();
+
+    match &definition.appearance {
+        EnemyAppearance::Model {
+            scene,
+            graph,
+            animations,
+            ..
+        } => {
+            commands.entity(entity).insert((
+                SceneRoot(scene.clone()),
+                EnemyModel::new(graph.clone(), animations.clone()),
+            ));
+        },
+        EnemyAppearance::SpriteSheet { image, sheet } => {
+            let (frame_width, frame_height) = sheet.frame_size;
+            let width = ENEMY_SPRITE_HEIGHT * frame_width as f32 / frame_height.max(1) as f32;
+            // Each enemy shows its own frame, so it needs its own material
+            let material = materials.add(StandardMaterial {
+                base_color_texture: Some(image.clone()),
+                alpha_mode: AlphaMode::Mask(0.5),
+                cull_mode: None,
+                ..Default::default()
+            });
+            let billboard = commands
+                .spawn((
+                    Mesh3d(meshes.add(bevy::prelude::Rectangle::new(width, ENEMY_SPRITE_HEIGHT))),
+                    MeshMaterial3d(material),
+                    Transform::default(),
+                    Billboard::YAxis,
+                    ChildOf(entity),
+                ))
+                .id();
+            commands.entity(entity).insert(DirectionalSprite::new(
+                sheet.clone(),
+                image.clone(),
+                billboard,
+            ));
+        },
     }
+    Some(entity)
 }
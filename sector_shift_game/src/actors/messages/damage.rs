@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+/// Requests that an actor loses health
+#[derive(Message, Debug, Clone, Copy)]
+pub struct Damage {
+    /// The actor taking the damage
+    pub target: Entity,
+    /// The unscaled amount of health to remove
+    pub amount: i32,
+    /// The actor that dealt the damage, `None` for the level itself
+    pub source: Option<Entity>,
+}
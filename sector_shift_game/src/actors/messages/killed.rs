@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+/// Sent when an actor's health drops to zero
+#[derive(Message, Debug, Clone, Copy)]
+pub struct Killed {
+    /// The actor that died
+    pub target: Entity,
+    /// The actor credited with the kill, `None` for the level itself
+    pub source: Option<Entity>,
+}
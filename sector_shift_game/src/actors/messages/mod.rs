@@ -0,0 +1,5 @@
+mod damage;
+pub use self::damage::*;
+
+mod killed;
+pub use self::killed::*;
@@ -1,5 +1,6 @@
 pub mod components;
 pub mod functions;
+pub mod messages;
 pub mod systems;
 
 mod actors_plugin;
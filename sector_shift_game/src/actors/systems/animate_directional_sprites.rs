@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use sector_shift_core::{enemies::data::EnemySpriteSheet, prelude::*};
+
+use crate::actors::components::{DirectionalSprite, EnemyAnimationState};
+
+/// Advances the animation of sprite sheet enemies and shows the frame for the angle the camera
+/// sees them from. Reactions go back to `Idle` once their frames have played
+pub fn animate_directional_sprites(
+    time: Res<Time>,
+    images: Res<Assets<Image>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    q_camera: Single<&GlobalTransform, With<Camera3d>>,
+    mut q_sprites: Query<(
+        &mut DirectionalSprite,
+        &mut EnemyAnimationState,
+        &GlobalTransform,
+    )>,
+    q_billboards: Query<&MeshMaterial3d<StandardMaterial>>,
+) {
+    let camera_position = q_camera.translation();
+
+    for (mut sprite, mut state, transform) in q_sprites.iter_mut() {
+        state.elapsed += time.delta_secs();
+        if state.state.is_reaction()
+            && sprite
+                .sheet
+                .animation(state.state)
+                .is_none_or(|animation| state.elapsed >= animation.duration())
+        {
+            state.play(EnemyAnimation::Idle);
+        }
+        let Some(animation) = sprite.sheet.animation(state.state) else {
+            continue;
+        };
+        let row = animation.row + animation.frame_at(state.elapsed);
+
+        // The camera in the enemy's own frame, forward is -Z and right is +X
+        let viewer = transform.rotation().inverse() * (camera_position - transform.translation());
+        let view = EnemySpriteSheet::view_direction(Vec2::new(viewer.x, -viewer.z));
+        let column = EnemySpriteSheet::column(view);
+
+        if sprite.shown == Some((column, row)) {
+            continue;
+        }
+        let Some(image) = images.get(&sprite.image) else {
+            continue;
+        };
+        let Ok(material_handle) = q_billboards.get(sprite.billboard) else {
+            continue;
+        };
+        let Some(material) = materials.get_mut(material_handle) else {
+            continue;
+        };
+
+        material.uv_transform = SpriteSheet::frame_uv_transform(
+            sprite.sheet.frame_size,
+            UVec2::new(column, row),
+            image.size(),
+        );
+        sprite.shown = Some((column, row));
+    }
+}
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::actors::components::{EnemyAnimationState, EnemyModel};
+
+/// Plays the clip of the animation state of model enemies, on repeat except for reactions and
+/// death. Reactions go back to `Idle` once their clip has played, or right away without one
+pub fn animate_enemy_models(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_models: Query<(Entity, &mut EnemyModel, &mut EnemyAnimationState)>,
+    q_children: Query<&Children>,
+    mut q_players: Query<&mut AnimationPlayer>,
+) {
+    for (entity, mut model, mut state) in q_models.iter_mut() {
+        state.elapsed += time.delta_secs();
+
+        // The scene, and with it the player, spawns a few frames after the enemy
+        let player_entity = match model.player {
+            Some(player_entity) => player_entity,
+            None => {
+                let Some(player_entity) =
+                    q_children.iter_descendants(entity).find(|child| q_players.contains(*child))
+                else {
+                    continue;
+                };
+                commands.entity(player_entity).insert(AnimationGraphHandle(model.graph.clone()));
+                model.player = Some(player_entity);
+                player_entity
+            },
+        };
+        let Ok(mut player) = q_players.get_mut(player_entity) else {
+            continue;
+        };
+
+        let clip = state.state.resolve(&model.animations).copied();
+        if state.state.is_reaction()
+            && (clip.is_none() || model.shown == Some(state.state) && player.all_finished())
+        {
+            state.play(EnemyAnimation::Idle);
+        }
+        if model.shown == Some(state.state) {
+            continue;
+        }
+
+        // States without a clip keep the current one
+        if let Some(node) = state.state.resolve(&model.animations).copied() {
+            player.stop_all();
+            let animation = player.play(node);
+            if !state.state.is_reaction() && state.state != EnemyAnimation::Death {
+                animation.repeat();
+            }
+            model.shown = Some(state.state);
+        }
+    }
+}
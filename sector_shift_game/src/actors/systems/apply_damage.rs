@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    QUAD_DAMAGE_MULTIPLIER,
+    actors::{
+        components::{Health, Player, PowerUps},
+        messages::{Damage, Killed},
+    },
+};
+
+/// Applies [`Damage`] to actors, scaling damage taken by the player with the [`Difficulty`] and
+/// following the power-ups of the target and the source
+pub fn apply_damage(
+    mut damage_reader: MessageReader<Damage>,
+    mut killed_writer: MessageWriter<Killed>,
+    difficulty: Res<Difficulty>,
+    mut q_health: Query<(&mut Health, Has<Player>)>,
+    q_power_ups: Query<&PowerUps>,
+) {
+    let is_active = |entity: Option<Entity>, power_up: PowerUp| {
+        entity
+            .and_then(|entity| q_power_ups.get(entity).ok())
+            .is_some_and(|power_ups| power_ups.is_active(power_up))
+    };
+
+    for damage in damage_reader.read() {
+        if is_active(Some(damage.target), PowerUp::Invulnerability) {
+            continue;
+        }
+        let Ok((mut health, is_player)) = q_health.get_mut(damage.target) else {
+            continue;
+        };
+        if !health.is_alive() {
+            continue;
+        }
+
+        let mut amount = if is_player { difficulty.scale_damage(damage.amount) } else { damage.amount };
+        if is_active(damage.source, PowerUp::QuadDamage) {
+            amount *= QUAD_DAMAGE_MULTIPLIER;
+        }
+        health.take_damage(amount);
+
+        if !health.is_alive() {
+            killed_writer.write(Killed {
+                target: damage.target,
+                source: damage.source,
+            });
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::{OVERHEAL_DECAY_RATE, actors::components::Health};
+
+/// Lowers health above the maximum back down at [`OVERHEAL_DECAY_RATE`]
+pub fn decay_overheal(time: Res<Time>, mut decay: Local<f32>, mut q_health: Query<&mut Health>) {
+    // Health is whole points, so the decay is collected until there is a point to take
+    *decay += OVERHEAL_DECAY_RATE * time.delta_secs();
+    let amount = decay.floor();
+    if amount < 1.0 {
+        return;
+    }
+    *decay -= amount;
+
+    for mut health in q_health.iter_mut() {
+        if health.current > health.max {
+            health.decay_overheal(amount as i32);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    actors::{
+        components::{AttackCooldown, EnemyAnimationState, Health, Player},
+        messages::Damage,
+    },
+    maps::functions::translation_to_map,
+    noise::resources::AreaConnections,
+    perception::{components::Perception, data::Awareness, functions::line_of_sight},
+    projectiles::messages::FireProjectile,
+};
+
+/// Alerted enemies attack the player whenever they see them in range, firing their projectile or
+/// hitting instantly when they have none
+pub fn enemy_attacks(
+    time: Res<Time>,
+    connections: Res<AreaConnections>,
+    mut fire_writer: MessageWriter<FireProjectile>,
+    mut damage_writer: MessageWriter<Damage>,
+    q_player: Single<(Entity, &GlobalTransform), With<Player>>,
+    mut q_enemies: Query<(
+        Entity,
+        &GlobalTransform,
+        &EnemyStats,
+        &Health,
+        &Perception,
+        &mut AttackCooldown,
+        &mut EnemyAnimationState,
+    )>,
+) {
+    let (player, player_transform) = *q_player;
+    let target = player_transform.translation();
+
+    for (entity, transform, stats, health, perception, mut cooldown, mut animation) in q_enemies.iter_mut() {
+        cooldown.0 = (cooldown.0 - time.delta_secs()).max(0.0);
+        if cooldown.0 > 0.0 || !health.is_alive() || perception.awareness != Awareness::Alerted {
+            continue;
+        }
+
+        let eye = transform.translation();
+        let Ok(direction) = Dir3::new(target - eye) else {
+            continue;
+        };
+        if eye.distance(target) > stats.attack_range
+            || !line_of_sight(
+                translation_to_map(eye),
+                translation_to_map(target),
+                |cell| connections.blocks_sight(cell),
+            )
+        {
+            continue;
+        }
+
+        cooldown.0 = stats.attack_interval;
+        animation.play(EnemyAnimation::Attack);
+        if let Some(projectile) = &stats.projectile {
+            fire_writer.write(FireProjectile {
+                projectile: projectile.clone(),
+                shooter: Some(entity),
+                // Just outside of the enemy's capsule
+                origin: eye + direction * 0.6,
+                direction,
+            });
+        } else {
+            damage_writer.write(Damage {
+                target: player,
+                amount: stats.damage,
+                source: Some(entity),
+            });
+        }
+    }
+}
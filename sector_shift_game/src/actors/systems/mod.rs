@@ -1 +1,26 @@
+mod animate_directional_sprites;
+pub use self::animate_directional_sprites::*;
 
+mod animate_enemy_models;
+pub use self::animate_enemy_models::*;
+
+mod apply_damage;
+pub use self::apply_damage::*;
+
+mod decay_overheal;
+pub use self::decay_overheal::*;
+
+mod enemy_attacks;
+pub use self::enemy_attacks::*;
+
+mod patrol_enemies;
+pub use self::patrol_enemies::*;
+
+mod play_enemy_reactions;
+pub use self::play_enemy_reactions::*;
+
+mod revive_player;
+pub use self::revive_player::*;
+
+mod tick_power_ups;
+pub use self::tick_power_ups::*;
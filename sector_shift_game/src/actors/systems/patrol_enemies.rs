@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    actors::components::{EnemyAnimationState, Health, Patrol},
+    maps::functions::position_to_transform,
+    perception::{components::Perception, data::Awareness},
+};
+
+/// Walks patrolling enemies from cell to cell along their route until they are alerted. Enemies at
+/// the end of a route that isn't a loop stand guard there
+pub fn patrol_enemies(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_patrols: Query<(
+        Entity,
+        &mut Patrol,
+        &mut Transform,
+        &EnemyStats,
+        &Health,
+        &Perception,
+        &mut EnemyAnimationState,
+    )>,
+) {
+    for (entity, mut patrol, mut transform, stats, health, perception, mut animation) in q_patrols.iter_mut()
+    {
+        // Alerted enemies leave their route to turn towards the player and attack
+        if !health.is_alive() || perception.awareness == Awareness::Alerted {
+            continue;
+        }
+        let Some(&cell) = patrol.route.cells.get(patrol.target) else {
+            commands.entity(entity).remove::<Patrol>();
+            continue;
+        };
+
+        let mut target = position_to_transform(cell).translation;
+        target.y = transform.translation.y;
+        let offset = target - transform.translation;
+        let step = stats.speed * time.delta_secs();
+
+        if offset.length() <= step {
+            transform.translation = target;
+            match patrol.route.next(patrol.target) {
+                Some(next) => patrol.target = next,
+                None => {
+                    commands.entity(entity).remove::<Patrol>();
+                    animation.play_after_reaction(EnemyAnimation::Idle);
+                    continue;
+                },
+            }
+        } else {
+            transform.translation += offset.normalize() * step;
+            transform.look_to(offset.normalize(), Vec3::Y);
+        }
+        animation.play_after_reaction(EnemyAnimation::Walk);
+    }
+}
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::actors::{
+    components::{EnemyAnimationState, Health},
+    messages::{Damage, Killed},
+};
+
+/// Plays the pain animation of enemies that survive damage and the death animation of killed ones
+pub fn play_enemy_reactions(
+    mut damage_reader: MessageReader<Damage>,
+    mut killed_reader: MessageReader<Killed>,
+    mut q_enemies: Query<(&Health, &mut EnemyAnimationState)>,
+) {
+    for damage in damage_reader.read() {
+        if let Ok((health, mut animation)) = q_enemies.get_mut(damage.target)
+            && health.is_alive()
+        {
+            animation.play(EnemyAnimation::Pain);
+        }
+    }
+    for killed in killed_reader.read() {
+        if let Ok((_, mut animation)) = q_enemies.get_mut(killed.target) {
+            animation.play(EnemyAnimation::Death);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    REVIVE_INVULNERABILITY,
+    actors::{
+        components::{Health, Player, PowerUps},
+        messages::Killed,
+    },
+    items::resources::Inventory,
+};
+
+/// Spends an extra life when the player dies, bringing them back where they fell with full health
+/// and a moment of invulnerability to get away
+pub fn revive_player(
+    mut killed_reader: MessageReader<Killed>,
+    mut inventory: ResMut<Inventory>,
+    mut q_player: Query<(&mut Health, &mut PowerUps), With<Player>>,
+) {
+    for killed in killed_reader.read() {
+        let Ok((mut health, mut power_ups)) = q_player.get_mut(killed.target) else {
+            continue;
+        };
+        if inventory.lives == 0 {
+            continue;
+        }
+        inventory.lives -= 1;
+        health.current = health.max;
+        health.armor = 0;
+        let remaining = power_ups.remaining(PowerUp::Invulnerability).max(REVIVE_INVULNERABILITY);
+        power_ups.remaining.insert(PowerUp::Invulnerability, remaining);
+    }
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+use crate::actors::components::PowerUps;
+
+/// Counts down the power-ups and drops the ones that run out
+pub fn tick_power_ups(time: Res<Time>, mut q_power_ups: Query<&mut PowerUps>) {
+    for mut power_ups in q_power_ups.iter_mut() {
+        if !power_ups.remaining.is_empty() {
+            power_ups.tick(time.delta_secs());
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+use crate::{
+    audio::{
+        messages::{PlayMusic, PlaySound},
+        systems::{
+            door_sounds, enemy_sounds, fire_sounds, footsteps, pickup_sounds, play_music, play_sounds,
+            player_fire_sounds, stop_music,
+        },
+    },
+    states::{states::GameState, system_sets::GameSet},
+};
+
+pub struct AudioPlugin;
+impl Plugin for AudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<PlaySound>().add_message::<PlayMusic>();
+
+        app.add_systems(OnExit(GameState::Intermission), stop_music);
+        app.add_systems(
+            Update,
+            (
+                (
+                    door_sounds,
+                    pickup_sounds,
+                    enemy_sounds,
+                    fire_sounds,
+                    player_fire_sounds,
+                    footsteps,
+                )
+                    .in_set(GameSet::Running),
+                (play_sounds, play_music),
+            )
+                .chain(),
+        );
+    }
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+/// The music track looping while a level is played
+#[derive(Component, Reflect, Debug, Default)]
+#[reflect(Component)]
+pub struct LevelMusic;
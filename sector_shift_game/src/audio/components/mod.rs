@@ -0,0 +1,5 @@
+mod level_music;
+pub use self::level_music::*;
+
+mod sound_instance;
+pub use self::sound_instance::*;
@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+/// A playing sound, counted to keep each sound under its maximum number of instances
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct SoundInstance {
+    /// The id of the sound in the [`SoundLibrary`](sector_shift_core::prelude::SoundLibrary)
+    pub sound: String,
+}
@@ -0,0 +1,35 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use sector_shift_core::sounds::data::{SoundCue, SoundOutput};
+
+use crate::audio::{components::SoundInstance, functions::spawn_sound};
+
+/// Plays sounds through bevy's audio by spawning an entity for each of them
+pub struct AudioOutput<'a, 'w, 's> {
+    pub commands: &'a mut Commands<'w, 's>,
+    /// How many instances of each sound are playing, including the ones spawned this frame
+    pub playing: HashMap<String, usize>,
+}
+
+impl<'a, 'w, 's> AudioOutput<'a, 'w, 's> {
+    pub fn new<'i>(
+        commands: &'a mut Commands<'w, 's>,
+        instances: impl Iterator<Item = &'i SoundInstance>,
+    ) -> Self {
+        let mut playing = HashMap::<String, usize>::new();
+        for instance in instances {
+            *playing.entry(instance.sound.clone()).or_default() += 1;
+        }
+        Self { commands, playing }
+    }
+}
+
+impl SoundOutput for AudioOutput<'_, '_, '_> {
+    fn playing(&self, sound: &str) -> usize {
+        self.playing.get(sound).copied().unwrap_or_default()
+    }
+
+    fn play(&mut self, cue: SoundCue) {
+        *self.playing.entry(cue.sound.clone()).or_default() += 1;
+        spawn_sound(self.commands, cue);
+    }
+}
@@ -0,0 +1,2 @@
+mod audio_output;
+pub use self::audio_output::*;
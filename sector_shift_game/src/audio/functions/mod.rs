@@ -0,0 +1,2 @@
+mod spawn_sound;
+pub use self::spawn_sound::*;
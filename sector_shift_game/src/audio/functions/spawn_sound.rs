@@ -0,0 +1,27 @@
+use bevy::{audio::Volume, prelude::*};
+use sector_shift_core::sounds::data::SoundCue;
+
+use crate::audio::components::SoundInstance;
+
+/// Spawns a playing sound with the following components:
+/// - Name
+/// - SoundInstance
+/// - AudioPlayer
+/// - PlaybackSettings, spatial when the cue has a position
+/// - Transform
+///
+/// Sounds that don't loop despawn once they finish.
+pub fn spawn_sound(commands: &mut Commands, cue: SoundCue) -> Entity {
+    let mode = if cue.looping { PlaybackSettings::LOOP } else { PlaybackSettings::DESPAWN };
+    commands
+        .spawn((
+            Name::new(cue.sound.clone()),
+            SoundInstance { sound: cue.sound },
+            AudioPlayer(cue.clip),
+            mode.with_volume(Volume::Linear(cue.volume))
+                .with_speed(cue.speed)
+                .with_spatial(cue.position.is_some()),
+            Transform::from_translation(cue.position.unwrap_or_default()),
+        ))
+        .id()
+}
@@ -0,0 +1,5 @@
+mod play_music;
+pub use self::play_music::*;
+
+mod play_sound;
+pub use self::play_sound::*;
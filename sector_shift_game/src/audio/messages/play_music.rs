@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Replaces the music of the level
+#[derive(Message, Debug, Clone)]
+pub struct PlayMusic {
+    /// The id of the sound to loop, `None` for silence
+    pub sound: Option<String>,
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+/// Requests the sound of a gameplay event
+#[derive(Message, Debug, Clone, Copy)]
+pub struct PlaySound {
+    pub event: SoundEvent,
+    /// Where it happened, `None` for the player's own sounds which play at the listener
+    pub position: Option<Vec3>,
+    /// The entity making the noise, its [`SoundOverrides`] replace the default sound
+    pub source: Option<Entity>,
+}
@@ -0,0 +1,8 @@
+pub mod components;
+pub mod data;
+pub mod functions;
+pub mod messages;
+pub mod systems;
+
+mod audio_plugin;
+pub use self::audio_plugin::*;
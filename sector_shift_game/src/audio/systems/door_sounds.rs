@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{audio::messages::PlaySound, maps::components::Door};
+
+/// Plays the door sounds where doors open and close
+pub fn door_sounds(
+    mut sound_writer: MessageWriter<PlaySound>,
+    q_doors: Query<(Entity, Ref<Door>, &GlobalTransform)>,
+) {
+    for (entity, door, transform) in q_doors.iter() {
+        // Doors are spawned closed, that's not a sound
+        if !door.is_changed() || door.is_added() {
+            continue;
+        }
+        sound_writer.write(PlaySound {
+            event: if door.open { SoundEvent::DoorOpen } else { SoundEvent::DoorClose },
+            position: Some(transform.translation()),
+            source: Some(entity),
+        });
+    }
+}
@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    actors::messages::{Damage, Killed},
+    audio::messages::PlaySound,
+};
+
+/// Plays the pain and death sounds of enemies where they are
+pub fn enemy_sounds(
+    mut damage_reader: MessageReader<Damage>,
+    mut killed_reader: MessageReader<Killed>,
+    mut sound_writer: MessageWriter<PlaySound>,
+    q_enemies: Query<&GlobalTransform, With<Enemy>>,
+) {
+    let killed: Vec<_> = killed_reader.read().map(|killed| killed.target).collect();
+
+    for damage in damage_reader.read() {
+        // A killing blow only makes the death sound
+        if killed.contains(&damage.target) {
+            continue;
+        }
+        if let Ok(transform) = q_enemies.get(damage.target) {
+            sound_writer.write(PlaySound {
+                event: SoundEvent::EnemyPain,
+                position: Some(transform.translation()),
+                source: Some(damage.target),
+            });
+        }
+    }
+    for target in killed {
+        if let Ok(transform) = q_enemies.get(target) {
+            sound_writer.write(PlaySound {
+                event: SoundEvent::EnemyDeath,
+                position: Some(transform.translation()),
+                source: Some(target),
+            });
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{audio::messages::PlaySound, projectiles::messages::FireProjectile};
+
+/// Plays the weapon sound of every projectile fired, at the shooter
+pub fn fire_sounds(
+    mut fire_reader: MessageReader<FireProjectile>,
+    mut sound_writer: MessageWriter<PlaySound>,
+) {
+    for fire in fire_reader.read() {
+        sound_writer.write(PlaySound {
+            event: SoundEvent::WeaponFire,
+            position: Some(fire.origin),
+            source: fire.shooter,
+        });
+    }
+}
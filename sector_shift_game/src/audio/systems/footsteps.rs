@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{FOOTSTEP_DISTANCE, actors::components::Player, audio::messages::PlaySound};
+
+/// Plays a footstep each time the player walked [`FOOTSTEP_DISTANCE`]
+pub fn footsteps(
+    mut walked: Local<(Option<Vec3>, f32)>,
+    mut sound_writer: MessageWriter<PlaySound>,
+    q_player: Single<&Transform, With<Player>>,
+) {
+    let (last_position, distance) = &mut *walked;
+    let position = q_player.translation;
+    // Teleports like the level start aren't steps
+    if let Some(last) = last_position.replace(position) {
+        let step = (position - last).xz().length();
+        if step < FOOTSTEP_DISTANCE {
+            *distance += step;
+        }
+    }
+    if *distance >= FOOTSTEP_DISTANCE {
+        *distance -= FOOTSTEP_DISTANCE;
+        sound_writer.write(PlaySound {
+            event: SoundEvent::Footstep,
+            position: None,
+            source: None,
+        });
+    }
+}
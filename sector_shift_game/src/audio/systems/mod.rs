@@ -0,0 +1,26 @@
+mod door_sounds;
+pub use self::door_sounds::*;
+
+mod enemy_sounds;
+pub use self::enemy_sounds::*;
+
+mod fire_sounds;
+pub use self::fire_sounds::*;
+
+mod footsteps;
+pub use self::footsteps::*;
+
+mod pickup_sounds;
+pub use self::pickup_sounds::*;
+
+mod play_music;
+pub use self::play_music::*;
+
+mod play_sounds;
+pub use self::play_sounds::*;
+
+mod player_fire_sounds;
+pub use self::player_fire_sounds::*;
+
+mod stop_music;
+pub use self::stop_music::*;
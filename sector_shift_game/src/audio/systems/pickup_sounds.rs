@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{audio::messages::PlaySound, items::messages::ItemPickedUp};
+
+/// Plays the pickup sound for every item the player picked up
+pub fn pickup_sounds(
+    mut picked_up_reader: MessageReader<ItemPickedUp>,
+    mut sound_writer: MessageWriter<PlaySound>,
+) {
+    for _ in picked_up_reader.read() {
+        sound_writer.write(PlaySound {
+            event: SoundEvent::Pickup,
+            position: None,
+            source: None,
+        });
+    }
+}
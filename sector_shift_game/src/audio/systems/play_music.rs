@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    audio::{components::LevelMusic, functions::spawn_sound, messages::PlayMusic},
+    states::states::GameState,
+};
+
+/// Replaces the current music with the last track requested this frame
+pub fn play_music(
+    mut commands: Commands,
+    mut music_reader: MessageReader<PlayMusic>,
+    sound_library: Res<SoundLibrary>,
+    q_music: Query<Entity, With<LevelMusic>>,
+) {
+    let Some(music) = music_reader.read().last() else {
+        return;
+    };
+    for entity in q_music.iter() {
+        commands.entity(entity).despawn();
+    }
+    let Some(sound) = &music.sound else {
+        return;
+    };
+
+    let Some(cue) = sound_library.get(sound).and_then(|definition| definition.cue(None, true, fastrand::f32))
+    else {
+        warn!("Music '{sound}' does not exist or has no clips");
+        return;
+    };
+    // Not part of the level, so quitting to the main menu has to stop it
+    let entity = spawn_sound(&mut commands, cue);
+    commands.entity(entity).insert((LevelMusic, DespawnOnEnter(GameState::MainMenu)));
+}
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::audio::{components::SoundInstance, data::AudioOutput, messages::PlaySound};
+
+/// Plays the sounds of the gameplay events requested this frame
+pub fn play_sounds(
+    mut commands: Commands,
+    mut sound_reader: MessageReader<PlaySound>,
+    sound_library: Res<SoundLibrary>,
+    q_instances: Query<&SoundInstance>,
+    q_overrides: Query<&SoundOverrides>,
+) {
+    if sound_reader.is_empty() {
+        return;
+    }
+    let mut output = AudioOutput::new(&mut commands, q_instances.iter());
+
+    for sound in sound_reader.read() {
+        let overrides = sound.source.and_then(|source| q_overrides.get(source).ok());
+        sound_library.play_event(
+            &mut output,
+            sound.event,
+            overrides,
+            sound.position,
+            fastrand::f32,
+        );
+    }
+}
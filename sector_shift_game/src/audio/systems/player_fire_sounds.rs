@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{audio::messages::PlaySound, inputs::data::GameAction};
+
+/// Plays the weapon sound whenever the player pulls the trigger
+pub fn player_fire_sounds(actions: Res<ActionState<GameAction>>, mut sound_writer: MessageWriter<PlaySound>) {
+    if actions.just_pressed(GameAction::Fire) {
+        sound_writer.write(PlaySound {
+            event: SoundEvent::WeaponFire,
+            position: None,
+            source: None,
+        });
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+use crate::audio::components::LevelMusic;
+
+/// Stops the music of the level that was left
+pub fn stop_music(mut commands: Commands, q_music: Query<Entity, With<LevelMusic>>) {
+    for entity in q_music.iter() {
+        commands.entity(entity).despawn();
+    }
+}
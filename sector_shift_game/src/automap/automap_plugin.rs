@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+use crate::{
+    automap::{
+        resources::ExploredAreas,
+        systems::{explore_areas, toggle_automap, update_automap_player},
+    },
+    states::system_sets::GameSet,
+};
+
+pub struct AutomapPlugin;
+impl Plugin for AutomapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ExploredAreas>();
+
+        app.add_systems(
+            Update,
+            (explore_areas, toggle_automap, update_automap_player).chain().in_set(GameSet::Running),
+        );
+    }
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+/// The marker showing the player's position on the automap
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct AutomapPlayer;
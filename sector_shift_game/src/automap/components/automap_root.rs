@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+/// The automap overlay, despawned to close it
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct AutomapRoot;
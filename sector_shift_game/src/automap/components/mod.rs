@@ -0,0 +1,5 @@
+mod automap_player;
+pub use self::automap_player::*;
+
+mod automap_root;
+pub use self::automap_root::*;
@@ -0,0 +1,2 @@
+mod spawn_automap;
+pub use self::spawn_automap::*;
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+
+use crate::{
+    AUTOMAP_CELL_SIZE,
+    automap::{
+        components::{AutomapPlayer, AutomapRoot},
+        resources::ExploredAreas,
+    },
+    noise::resources::AreaConnections,
+    states::states::GameState,
+};
+
+const FLOOR_COLOR: Color = Color::srgb(0.15, 0.15, 0.2);
+const WALL_COLOR: Color = Color::srgb(0.6, 0.6, 0.6);
+const DOOR_COLOR: Color = Color::srgb(0.8, 0.5, 0.1);
+const PLAYER_COLOR: Color = Color::srgb(0.9, 0.1, 0.1);
+
+/// Spawns the automap overlay with every known cell of the level, north is up
+pub fn spawn_automap(
+    commands: &mut Commands,
+    connections: &AreaConnections,
+    explored: &ExploredAreas,
+    revealed: bool,
+) -> Entity {
+    let areas = &connections.areas;
+    let (width, height) = areas.cells.size();
+    let map_entity = commands
+        .spawn(Node {
+            width: Val::Px(width as f32 * AUTOMAP_CELL_SIZE),
+            height: Val::Px(height as f32 * AUTOMAP_CELL_SIZE),
+            ..Default::default()
+        })
+        .id();
+
+    for (position, area) in areas.cells.enumerate() {
+        if !explored.is_known(areas, position, revealed) {
+            continue;
+        }
+        let color = match area {
+            Some(_) => FLOOR_COLOR,
+            None if areas.doors.contains_key(&position) => DOOR_COLOR,
+            None => WALL_COLOR,
+        };
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(position.0 as f32 * AUTOMAP_CELL_SIZE),
+                bottom: Val::Px(position.1 as f32 * AUTOMAP_CELL_SIZE),
+                width: Val::Px(AUTOMAP_CELL_SIZE),
+                height: Val::Px(AUTOMAP_CELL_SIZE),
+                ..Default::default()
+            },
+            BackgroundColor(color),
+            ChildOf(map_entity),
+        ));
+    }
+    // Placed by update_automap_player
+    commands.spawn((
+        AutomapPlayer,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(AUTOMAP_CELL_SIZE / 2.0),
+            height: Val::Px(AUTOMAP_CELL_SIZE / 2.0),
+            ..Default::default()
+        },
+        BackgroundColor(PLAYER_COLOR),
+        ChildOf(map_entity),
+    ));
+
+    commands
+        .spawn((
+            Name::new("Automap"),
+            AutomapRoot,
+            DespawnOnExit(GameState::Running),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        ))
+        .add_child(map_entity)
+        .id()
+}
@@ -0,0 +1,7 @@
+pub mod components;
+pub mod functions;
+pub mod resources;
+pub mod systems;
+
+mod automap_plugin;
+pub use self::automap_plugin::*;
@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+
+use crate::noise::data::AreaMap;
+
+/// The areas of the level the player has walked into, indexed like the [`AreaMap`]
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ExploredAreas {
+    pub explored: Vec<bool>,
+}
+
+impl ExploredAreas {
+    /// Marks the areas of the cell the player stands on as explored. Returns whether any of them
+    /// weren't already
+    pub fn explore(&mut self, areas: &AreaMap, position: (i32, i32)) -> bool {
+        self.explored.resize(areas.count, false);
+        let mut changed = false;
+        for area in areas.areas_at(position) {
+            changed |= !std::mem::replace(&mut self.explored[area], true);
+        }
+        changed
+    }
+
+    /// Whether the automap shows a cell: the floor of explored areas and the walls and doors around
+    /// it, or every cell next to a floor when the map is revealed
+    pub fn is_known(&self, areas: &AreaMap, position: (i32, i32), revealed: bool) -> bool {
+        let is_explored_floor = |cell: (i32, i32)| {
+            areas
+                .cells
+                .get(cell)
+                .copied()
+                .flatten()
+                .is_some_and(|area| revealed || self.explored.get(area).copied().unwrap_or_default())
+        };
+        (-1..=1).any(|dx| (-1..=1).any(|dy| is_explored_floor((position.0 + dx, position.1 + dy))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sector_shift_core::prelude::*;
+
+    use super::ExploredAreas;
+    use crate::noise::data::AreaMap;
+
+    #[test]
+    fn test_explored_areas() {
+        // Two rooms along y = 1 split by a door at x = 3, walled in on every side
+        let mut level = Level::new("test", (7, 3));
+        for x in 1..6 {
+            let tile = if x == 3 { TileType::Door(DoorAxis::Horizontal) } else { TileType::Floor };
+            level.set_tile((x, 1), tile);
+        }
+        let areas = AreaMap::new(&level.tiles);
+        let mut explored = ExploredAreas::default();
+
+        assert!(explored.explore(&areas, (1, 1)));
+        assert!(!explored.explore(&areas, (2, 1)));
+        assert!(explored.is_known(&areas, (1, 1), false));
+        // The walls around the room and its door are known, the room behind the door isn't
+        assert!(explored.is_known(&areas, (0, 0), false));
+        assert!(explored.is_known(&areas, (3, 1), false));
+        assert!(!explored.is_known(&areas, (4, 1), false));
+        assert!(!explored.is_known(&areas, (6, 2), false));
+        assert!(explored.is_known(&areas, (6, 2), true));
+
+        // Standing in the doorway explores both sides
+        assert!(explored.explore(&areas, (3, 1)));
+        assert!(explored.is_known(&areas, (6, 2), false));
+    }
+}
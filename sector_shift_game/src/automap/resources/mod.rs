@@ -0,0 +1,2 @@
+mod explored_areas;
+pub use self::explored_areas::*;
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::{
+    actors::components::Player, automap::resources::ExploredAreas, maps::functions::translation_to_position,
+    noise::resources::AreaConnections,
+};
+
+/// Remembers the areas the player walks into, starting over on every level with the fresh
+/// [`ExploredAreas`] of [`insert_level_resources`](crate::maps::functions::insert_level_resources)
+pub fn explore_areas(
+    connections: Res<AreaConnections>,
+    mut explored: ResMut<ExploredAreas>,
+    q_player: Single<&GlobalTransform, With<Player>>,
+) {
+    let position = translation_to_position(q_player.translation());
+    explored.explore(&connections.areas, position);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use sector_shift_core::prelude::*;
+
+    use super::explore_areas;
+    use crate::{
+        actors::components::Player,
+        automap::resources::ExploredAreas,
+        maps::functions::{insert_level_resources, position_to_transform},
+        noise::resources::AreaConnections,
+    };
+
+    /// Starts a level the way `start_game` does and lets the player stand on a cell of it
+    fn play_level(app: &mut App, level: &Level, position: (i32, i32)) {
+        let world = app.world_mut();
+        insert_level_resources(&mut world.commands(), level, Difficulty::default());
+        world.flush();
+        let mut q_player = world.query_filtered::<&mut GlobalTransform, With<Player>>();
+        *q_player.single_mut(world).unwrap() = GlobalTransform::from(position_to_transform(position));
+        app.update();
+    }
+
+    #[test]
+    fn test_explored_areas_start_over_on_every_level() {
+        // Two rooms along y = 1 split by a door at x = 3, walled in on every side
+        let mut level = Level::new("test", (7, 3));
+        for x in 1..6 {
+            let tile = if x == 3 { TileType::Door(DoorAxis::Horizontal) } else { TileType::Floor };
+            level.set_tile((x, 1), tile);
+        }
+
+        let mut app = App::new();
+        app.add_systems(Update, explore_areas);
+        app.world_mut().spawn((Player, GlobalTransform::default()));
+
+        play_level(&mut app, &level, (1, 1));
+        play_level(&mut app, &level, (5, 1));
+
+        let areas = &app.world().resource::<AreaConnections>().areas;
+        let explored = app.world().resource::<ExploredAreas>();
+        assert!(explored.is_known(areas, (5, 1), false));
+        assert!(!explored.is_known(areas, (1, 1), false));
+    }
+}
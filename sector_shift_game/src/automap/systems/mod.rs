@@ -0,0 +1,8 @@
+mod explore_areas;
+pub use self::explore_areas::*;
+
+mod toggle_automap;
+pub use self::toggle_automap::*;
+
+mod update_automap_player;
+pub use self::update_automap_player::*;
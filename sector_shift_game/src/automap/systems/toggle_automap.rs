@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    actors::components::{Player, PowerUps},
+    automap::{components::AutomapRoot, functions::spawn_automap, resources::ExploredAreas},
+    inputs::data::GameAction,
+    noise::resources::AreaConnections,
+};
+
+/// Opens and closes the automap, [`PowerUp::MapReveal`] shows the parts of the level that haven't
+/// been explored yet
+pub fn toggle_automap(
+    mut commands: Commands,
+    actions: Res<ActionState<GameAction>>,
+    connections: Res<AreaConnections>,
+    explored: Res<ExploredAreas>,
+    q_power_ups: Single<&PowerUps, With<Player>>,
+    q_automap: Query<Entity, With<AutomapRoot>>,
+) {
+    if !actions.just_pressed(GameAction::Automap) {
+        return;
+    }
+    if q_automap.is_empty() {
+        spawn_automap(
+            &mut commands,
+            &connections,
+            &explored,
+            q_power_ups.is_active(PowerUp::MapReveal),
+        );
+    }
+    for entity in q_automap.iter() {
+        commands.entity(entity).despawn();
+    }
+}
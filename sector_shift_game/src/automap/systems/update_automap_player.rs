@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+use crate::{
+    AUTOMAP_CELL_SIZE, actors::components::Player, automap::components::AutomapPlayer,
+    maps::functions::translation_to_map,
+};
+
+/// Moves the player's marker on the open automap
+pub fn update_automap_player(
+    q_player: Single<&GlobalTransform, With<Player>>,
+    mut q_markers: Query<&mut Node, With<AutomapPlayer>>,
+) {
+    let position = translation_to_map(q_player.translation());
+    for mut node in q_markers.iter_mut() {
+        node.left = Val::Px((position.x - 0.25) * AUTOMAP_CELL_SIZE);
+        node.bottom = Val::Px((position.y - 0.25) * AUTOMAP_CELL_SIZE);
+    }
+}
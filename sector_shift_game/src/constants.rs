@@ -5,4 +5,37 @@ pub const MAP_CELL_CEILING: f32 = 3.0;
 
 pub const PLAYER_HEALTH: i32 = 100;
 pub const PLAYER_SPEED: f32 = 10.0;
-pub const MOUSE_SENSITIVITY: f32 = 0.002;
+pub const PLAYER_TURN_SPEED: f32 = 2.5;
+
+/// How close the player has to get to an item to pick it up
+pub const ITEM_PICKUP_RADIUS: f32 = 1.5;
+
+/// How far in front of the player a door can be opened or closed from
+pub const USE_DISTANCE: f32 = 0.6 * MAP_SCALE;
+
+/// Width of the collider of blocking decorations, they take up the middle of their cell
+pub const ENV_OBJECT_SIZE: f32 = 0.5 * MAP_SCALE;
+
+/// Height of the billboard of sprite decorations, its width follows the sprite's aspect ratio
+pub const ENV_SPRITE_HEIGHT: f32 = 2.5;
+
+/// How fast alerted enemies turn towards the player, in radians per second
+pub const ENEMY_TURN_SPEED: f32 = 4.0;
+
+/// Height of the billboard of sprite enemies, its width follows the frame's aspect ratio
+pub const ENEMY_SPRITE_HEIGHT: f32 = 2.0;
+
+/// Size of a cell on the automap, in pixels
+pub const AUTOMAP_CELL_SIZE: f32 = 8.0;
+
+/// Share of the damage taken that armor absorbs while it lasts
+pub const ARMOR_ABSORPTION: f32 = 1.0 / 3.0;
+/// Seconds of invulnerability after an extra life brings the player back
+pub const REVIVE_INVULNERABILITY: f32 = 3.0;
+/// Health lost per second while above the maximum
+pub const OVERHEAL_DECAY_RATE: f32 = 1.0;
+/// Damage dealt is multiplied by this while [`PowerUp::QuadDamage`](sector_shift_core::prelude::PowerUp) is active
+pub const QUAD_DAMAGE_MULTIPLIER: i32 = 4;
+
+/// Distance the player walks between two footsteps
+pub const FOOTSTEP_DISTANCE: f32 = 2.5;
@@ -0,0 +1,162 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Everything the player can do, bound to physical inputs through the [`InputMap`]
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameAction {
+    MoveForward,
+    MoveBackward,
+    StrafeLeft,
+    StrafeRight,
+    /// While held, turning strafes instead
+    Strafe,
+    TurnLeft,
+    TurnRight,
+    LookUp,
+    LookDown,
+    Fire,
+    Use,
+    NextWeapon,
+    PreviousWeapon,
+    Automap,
+    Pause,
+}
+
+impl GameAction {
+    /// All actions in the order they are shown on the controls screen
+    pub const ALL: [Self; 15] = [
+        Self::MoveForward,
+        Self::MoveBackward,
+        Self::StrafeLeft,
+        Self::StrafeRight,
+        Self::Strafe,
+        Self::TurnLeft,
+        Self::TurnRight,
+        Self::LookUp,
+        Self::LookDown,
+        Self::Fire,
+        Self::Use,
+        Self::NextWeapon,
+        Self::PreviousWeapon,
+        Self::Automap,
+        Self::Pause,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GameAction::MoveForward => "Move Forward",
+            GameAction::MoveBackward => "Move Backward",
+            GameAction::StrafeLeft => "Strafe Left",
+            GameAction::StrafeRight => "Strafe Right",
+            GameAction::Strafe => "Strafe (hold)",
+            GameAction::TurnLeft => "Turn Left",
+            GameAction::TurnRight => "Turn Right",
+            GameAction::LookUp => "Look Up",
+            GameAction::LookDown => "Look Down",
+            GameAction::Fire => "Fire",
+            GameAction::Use => "Use",
+            GameAction::NextWeapon => "Next Weapon",
+            GameAction::PreviousWeapon => "Previous Weapon",
+            GameAction::Automap => "Automap",
+            GameAction::Pause => "Pause",
+        }
+    }
+}
+
+impl InputAction for GameAction {
+    const BINDINGS_PATH: &'static str = "./config/bindings.ron";
+
+    fn default_bindings() -> Vec<(Self, Vec<InputBinding>)> {
+        use AxisDirection::{Negative, Positive};
+        use InputBinding::{GamepadAxis as Axis, GamepadButton as Button, Key, Mouse};
+
+        vec![
+            (
+                GameAction::MoveForward,
+                vec![
+                    Key(KeyCode::KeyW),
+                    Key(KeyCode::ArrowUp),
+                    Axis(GamepadAxis::LeftStickY, Positive),
+                ],
+            ),
+            (
+                GameAction::MoveBackward,
+                vec![
+                    Key(KeyCode::KeyS),
+                    Key(KeyCode::ArrowDown),
+                    Axis(GamepadAxis::LeftStickY, Negative),
+                ],
+            ),
+            (
+                GameAction::StrafeLeft,
+                vec![Key(KeyCode::KeyA), Axis(GamepadAxis::LeftStickX, Negative)],
+            ),
+            (
+                GameAction::StrafeRight,
+                vec![Key(KeyCode::KeyD), Axis(GamepadAxis::LeftStickX, Positive)],
+            ),
+            (GameAction::Strafe, vec![Key(KeyCode::AltLeft)]),
+            (
+                GameAction::TurnLeft,
+                vec![
+                    Key(KeyCode::ArrowLeft),
+                    Axis(GamepadAxis::RightStickX, Negative),
+                ],
+            ),
+            (
+                GameAction::TurnRight,
+                vec![
+                    Key(KeyCode::ArrowRight),
+                    Axis(GamepadAxis::RightStickX, Positive),
+                ],
+            ),
+            (
+                GameAction::LookUp,
+                vec![Axis(GamepadAxis::RightStickY, Positive)],
+            ),
+            (
+                GameAction::LookDown,
+                vec![Axis(GamepadAxis::RightStickY, Negative)],
+            ),
+            (
+                GameAction::Fire,
+                vec![
+                    Mouse(MouseButton::Left),
+                    Key(KeyCode::ControlLeft),
+                    Button(GamepadButton::RightTrigger2),
+                ],
+            ),
+            (
+                GameAction::Use,
+                vec![
+                    Key(KeyCode::KeyE),
+                    Key(KeyCode::Space),
+                    Button(GamepadButton::South),
+                ],
+            ),
+            (
+                GameAction::NextWeapon,
+                vec![
+                    Key(KeyCode::BracketRight),
+                    Button(GamepadButton::RightTrigger),
+                ],
+            ),
+            (
+                GameAction::PreviousWeapon,
+                vec![
+                    Key(KeyCode::BracketLeft),
+                    Button(GamepadButton::LeftTrigger),
+                ],
+            ),
+            (
+                GameAction::Automap,
+                vec![Key(KeyCode::Tab), Button(GamepadButton::Select)],
+            ),
+            (
+                GameAction::Pause,
+                vec![Key(KeyCode::Escape), Button(GamepadButton::Start)],
+            ),
+        ]
+    }
+}
@@ -0,0 +1,2 @@
+mod game_action;
+pub use self::game_action::*;
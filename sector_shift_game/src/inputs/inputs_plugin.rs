@@ -1,20 +1,45 @@
 use bevy::prelude::*;
+use sector_shift_core::prelude::*;
 
 #[cfg(feature = "dev")]
 use crate::states::states::DebugHudState;
 use crate::{
-    inputs::systems::{player_look, player_movement},
-    states::system_sets::GameSet,
+    inputs::{
+        data::GameAction,
+        systems::{grab_cursor, player_look, player_movement, release_cursor, save_bindings, toggle_pause},
+    },
+    states::{
+        states::{GameState, MenuState},
+        system_sets::GameSet,
+    },
 };
 
 pub struct InputsPlugin;
 impl Plugin for InputsPlugin {
     fn build(&self, app: &mut App) {
+        app.add_plugins(InputMapPlugin::<GameAction>::default());
+
         #[cfg(feature = "dev")]
         let player_look = player_look.run_if(in_state(DebugHudState::Disabled));
         app.add_systems(
             Update,
             (player_look, player_movement).in_set(GameSet::Running),
         );
+
+        // The controls screen captures every input while rebinding
+        app.add_systems(
+            Update,
+            toggle_pause.run_if(
+                in_state(GameState::Running)
+                    .or(in_state(GameState::Paused))
+                    .and(not(in_state(MenuState::Controls))),
+            ),
+        );
+
+        app.add_systems(OnEnter(GameState::Running), grab_cursor)
+            .add_systems(OnEnter(GameState::Paused), release_cursor)
+            .add_systems(OnEnter(GameState::MainMenu), release_cursor)
+            .add_systems(OnEnter(GameState::Intermission), release_cursor)
+            .add_systems(OnExit(MenuState::Controls), save_bindings);
     }
 }
@@ -0,0 +1,10 @@
+use bevy::{
+    prelude::*,
+    window::{CursorGrabMode, CursorOptions},
+};
+
+/// Locks and hides the cursor so mouse motion can be used to look around
+pub fn grab_cursor(mut cursor_options: Single<&mut CursorOptions>) {
+    cursor_options.grab_mode = CursorGrabMode::Locked;
+    cursor_options.visible = false;
+}
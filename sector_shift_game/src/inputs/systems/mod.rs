@@ -1,5 +1,17 @@
+mod grab_cursor;
+pub use self::grab_cursor::*;
+
 mod player_look;
 pub use self::player_look::*;
 
 mod player_movement;
 pub use self::player_movement::*;
+
+mod release_cursor;
+pub use self::release_cursor::*;
+
+mod save_bindings;
+pub use self::save_bindings::*;
+
+mod toggle_pause;
+pub use self::toggle_pause::*;
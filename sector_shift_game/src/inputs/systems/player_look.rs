@@ -1,26 +1,36 @@
-use bevy::{input::mouse::MouseMotion, prelude::*};
+use bevy::{input::mouse::AccumulatedMouseMotion, prelude::*};
+use sector_shift_core::prelude::*;
 
-use crate::{MOUSE_SENSITIVITY, actors::components::Player};
+use crate::{
+    PLAYER_TURN_SPEED, actors::components::Player, inputs::data::GameAction, settings::resources::Settings,
+};
 
 pub fn player_look(
-    mut mouse_motion: MessageReader<MouseMotion>,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    actions: Res<ActionState<GameAction>>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
     mut player_transform: Single<&mut Transform, With<Player>>,
     mut camera_transform: Single<&mut Transform, (With<Camera3d>, Without<Player>)>,
 ) {
-    let mut rotation_delta = Vec2::ZERO;
+    let mut yaw_delta = -mouse_motion.delta.x * settings.mouse_sensitivity;
+    let mut pitch_delta = -mouse_motion.delta.y * settings.mouse_sensitivity;
 
-    for event in mouse_motion.read() {
-        rotation_delta += event.delta;
+    // Classic keyboard / gamepad turning, the strafe modifier turns these into strafing instead
+    let turn_delta = PLAYER_TURN_SPEED * time.delta_secs();
+    if !actions.pressed(GameAction::Strafe) {
+        yaw_delta += actions.axis(GameAction::TurnRight, GameAction::TurnLeft) * turn_delta;
     }
+    pitch_delta += actions.axis(GameAction::LookDown, GameAction::LookUp) * turn_delta;
 
-    if rotation_delta == Vec2::ZERO {
+    if yaw_delta == 0.0 && pitch_delta == 0.0 {
         return;
     }
 
-    player_transform.rotate_y(-rotation_delta.x * MOUSE_SENSITIVITY);
+    player_transform.rotate_y(yaw_delta);
 
     let (yaw, mut pitch, roll) = camera_transform.rotation.to_euler(EulerRot::YXZ);
-    pitch -= rotation_delta.y * MOUSE_SENSITIVITY;
+    pitch += pitch_delta;
     pitch = pitch.clamp(-1.54, 1.54);
 
     camera_transform.rotation = Quat::from_euler(EulerRot::YXZ, yaw, pitch, roll);
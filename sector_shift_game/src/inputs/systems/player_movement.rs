@@ -1,36 +1,32 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
+use sector_shift_core::prelude::*;
 
-use crate::{PLAYER_SPEED, actors::components::Player};
+use crate::{PLAYER_SPEED, actors::components::Player, inputs::data::GameAction};
 
 pub fn player_movement(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    actions: Res<ActionState<GameAction>>,
     q_player: Single<(&Transform, &mut LinearVelocity), With<Player>>,
 ) {
     let (player_transform, mut linear_velocity) = q_player.into_inner();
-    let mut direction = Vec3::ZERO;
 
     // Walk
-    if keyboard_input.pressed(KeyCode::KeyW) {
-        direction -= player_transform.local_z() * 1.0;
-    }
-    if keyboard_input.pressed(KeyCode::KeyS) {
-        direction += player_transform.local_z() * 1.0;
-    }
+    let forward = actions.axis(GameAction::MoveBackward, GameAction::MoveForward);
 
-    // Strafe
-    if keyboard_input.pressed(KeyCode::KeyA) {
-        direction -= player_transform.local_x() * 1.0;
-    }
-    if keyboard_input.pressed(KeyCode::KeyD) {
-        direction += player_transform.local_x() * 1.0;
+    // Strafe, turning strafes while the strafe modifier is held
+    let mut strafe = actions.axis(GameAction::StrafeLeft, GameAction::StrafeRight);
+    if actions.pressed(GameAction::Strafe) {
+        strafe += actions.axis(GameAction::TurnLeft, GameAction::TurnRight);
     }
 
+    let mut direction = -player_transform.local_z() * forward + player_transform.local_x() * strafe;
+
     // Flatten to the XZ plane
     direction.y = 0.0;
 
     if direction.length_squared() > 0.0 {
-        direction = direction.normalize();
+        // Keep analog sticks proportional but never exceed full speed
+        direction = direction.clamp_length_max(1.0);
         linear_velocity.x = direction.x * PLAYER_SPEED;
         linear_velocity.z = direction.z * PLAYER_SPEED;
     } else {
@@ -0,0 +1,10 @@
+use bevy::{
+    prelude::*,
+    window::{CursorGrabMode, CursorOptions},
+};
+
+/// Frees and shows the cursor so menus can be used
+pub fn release_cursor(mut cursor_options: Single<&mut CursorOptions>) {
+    cursor_options.grab_mode = CursorGrabMode::None;
+    cursor_options.visible = true;
+}
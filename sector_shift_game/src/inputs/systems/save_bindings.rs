@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::inputs::data::GameAction;
+
+pub fn save_bindings(input_map: Res<InputMap<GameAction>>) {
+    if let Err(e) = input_map.save() {
+        warn!("Failed to save input bindings: {e}");
+    }
+}
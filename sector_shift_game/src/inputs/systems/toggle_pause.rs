@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{inputs::data::GameAction, states::states::GameState};
+
+pub fn toggle_pause(
+    actions: Res<ActionState<GameAction>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if actions.just_pressed(GameAction::Pause) {
+        match state.get() {
+            GameState::Running => next_state.set(GameState::Paused),
+            GameState::Paused => next_state.set(GameState::Running),
+            _ => {},
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+/// Marks an item dropped by a killed enemy rather than placed on the map
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct DroppedItem;
@@ -0,0 +1,2 @@
+mod dropped_item;
+pub use self::dropped_item::*;
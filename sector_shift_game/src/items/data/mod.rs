@@ -0,0 +1,2 @@
+mod player_item_target;
+pub use self::player_item_target::*;
@@ -0,0 +1,63 @@
+use sector_shift_core::prelude::*;
+
+use crate::{
+    actors::components::{Health, PowerUps},
+    items::resources::Inventory,
+};
+
+/// The player as seen by item effects, the parts live on the player entity and in the [`Inventory`]
+pub struct PlayerItemTarget<'a> {
+    pub health: &'a mut Health,
+    pub power_ups: &'a mut PowerUps,
+    pub inventory: &'a mut Inventory,
+}
+
+impl ItemTarget for PlayerItemTarget<'_> {
+    fn health(&self) -> i32 {
+        self.health.current
+    }
+
+    fn max_health(&self) -> i32 {
+        self.health.max
+    }
+
+    fn set_health(&mut self, health: i32) {
+        self.health.current = health;
+    }
+
+    fn armor(&self) -> i32 {
+        self.health.armor
+    }
+
+    fn set_armor(&mut self, armor: i32) {
+        self.health.armor = armor;
+    }
+
+    fn has_weapon(&self, weapon_id: &str) -> bool {
+        self.inventory.weapons.iter().any(|weapon| weapon == weapon_id)
+    }
+
+    fn give_weapon(&mut self, weapon_id: &str) {
+        self.inventory.give(&ItemEffect::Weapon(weapon_id.to_string()));
+    }
+
+    fn give_ammo(&mut self, weapon_id: &str, amount: u32) {
+        self.inventory.give(&ItemEffect::Ammo(weapon_id.to_string(), amount));
+    }
+
+    fn add_score(&mut self, amount: u32) {
+        self.inventory.score += amount;
+    }
+
+    fn add_life(&mut self) {
+        self.inventory.lives += 1;
+    }
+
+    fn power_up_remaining(&self, power_up: PowerUp) -> f32 {
+        self.power_ups.remaining(power_up)
+    }
+
+    fn set_power_up_remaining(&mut self, power_up: PowerUp, seconds: f32) {
+        self.power_ups.remaining.insert(power_up, seconds);
+    }
+}
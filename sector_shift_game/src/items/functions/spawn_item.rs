@@ -7,7 +7,8 @@ use sector_shift_core::prelude::*;
 /// - Item
 /// - Sprite
 /// - Transform
-/// - Billboard
+/// - Billboard (Y axis)
+/// - AnimatedBillboard, if the sprite is animated
 /// - Collider (cylinder)
 /// - Sensor
 ///
@@ -18,28 +19,36 @@ pub fn spawn_item(
     transform: Transform,
     item_mesh: Handle<Mesh>,
     materials: &mut Assets<StandardMaterial>,
+    difficulty: &Difficulty,
 ) -> Option<Entity> {
     if let Some(item_definition) = item_library.get(item_id) {
         // TODO: Move this out so it is only created once?
         let material = materials.add(StandardMaterial {
             base_color_texture: Some(item_definition.sprite.clone()),
-            cull_mode: None, // TODO: Rectangle has texture on -z? Is there a better way?
+            cull_mode: None,
             ..Default::default()
         });
+        // Ammo pickups are scaled by the difficulty
+        let effect = item_definition.effect.with_ammo_scaled(difficulty);
+
         let entity = commands
             .spawn((
                 Name::new(item_definition.id.clone()),
                 Item {
-                    effect: item_definition.effect.clone(),
+                    effect,
+                    conditions: item_definition.conditions.clone(),
                 },
                 Mesh3d(item_mesh),
                 MeshMaterial3d(material),
                 transform,
-                Billboard,
+                Billboard::YAxis,
                 Collider::cylinder(0.5, 1.0), // Match sprite size
                 Sensor,
             ))
             .id();
+        if let Some(animation) = &item_definition.animation {
+            commands.entity(entity).insert(AnimatedBillboard::new(animation.clone()));
+        }
         Some(entity)
     } else {
         warn!("Item ID '{}' not found in ItemLibrary.", item_id);
@@ -1,6 +1,23 @@
 use bevy::prelude::*;
 
+use crate::{
+    items::{
+        messages::ItemPickedUp,
+        resources::Inventory,
+        systems::{pickup_items, reward_enemy_kills},
+    },
+    states::system_sets::GameSet,
+};
+
 pub struct ItemsPlugin;
 impl Plugin for ItemsPlugin {
-    fn build(&self, app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Inventory>();
+        app.add_message::<ItemPickedUp>();
+
+        app.add_systems(
+            Update,
+            (pickup_items, reward_enemy_kills).in_set(GameSet::Running),
+        );
+    }
 }
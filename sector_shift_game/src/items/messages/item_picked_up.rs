@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+/// Sent when the player picks up an item
+#[derive(Message, Debug, Clone)]
+pub struct ItemPickedUp {
+    /// The effect that was applied to the player
+    pub effect: ItemEffect,
+    /// Whether an enemy dropped the item, those don't count towards the level's items
+    pub dropped: bool,
+}
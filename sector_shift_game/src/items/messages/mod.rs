@@ -0,0 +1,2 @@
+mod item_picked_up;
+pub use self::item_picked_up::*;
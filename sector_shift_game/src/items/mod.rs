@@ -1,4 +1,9 @@
+pub mod components;
+pub mod data;
 pub mod functions;
+pub mod messages;
+pub mod resources;
+pub mod systems;
 
 mod items_plugin;
 pub use self::items_plugin::*;
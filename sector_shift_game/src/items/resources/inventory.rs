@@ -0,0 +1,54 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use sector_shift_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// What the player carries from level to level, stored in the [`SaveGame`](crate::maps::resources::SaveGame)
+#[derive(Resource, Reflect, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct Inventory {
+    /// The ids of the weapons the player owns
+    pub weapons: Vec<String>,
+    /// Ammo keyed by weapon id
+    pub ammo: HashMap<String, u32>,
+    pub score: u32,
+    /// Extra lives on top of the current one
+    pub lives: u32,
+}
+
+impl Inventory {
+    /// Creates the inventory a new game starts with
+    pub fn from_effects(effects: &[ItemEffect]) -> Self {
+        let mut inventory = Self::default();
+        for effect in effects {
+            inventory.give(effect);
+        }
+        inventory
+    }
+
+    /// Adds whatever the effect grants to the inventory. Effects on the player's health, armor and
+    /// power-ups are ignored.
+    pub fn give(&mut self, effect: &ItemEffect) {
+        match effect {
+            ItemEffect::Health(_)
+            | ItemEffect::Overheal(..)
+            | ItemEffect::Armor(..)
+            | ItemEffect::PowerUp(..) => {},
+            ItemEffect::Ammo(weapon_id, amount) => {
+                *self.ammo.entry(weapon_id.clone()).or_default() += amount;
+            },
+            ItemEffect::Weapon(weapon_id) => {
+                if !self.weapons.contains(weapon_id) {
+                    self.weapons.push(weapon_id.clone());
+                }
+            },
+            ItemEffect::Score(amount) => self.score += amount,
+            ItemEffect::ExtraLife => self.lives += 1,
+            ItemEffect::List(effects) => {
+                for effect in effects {
+                    self.give(effect);
+                }
+            },
+        }
+    }
+}
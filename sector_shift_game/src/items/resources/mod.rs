@@ -0,0 +1,2 @@
+mod inventory;
+pub use self::inventory::*;
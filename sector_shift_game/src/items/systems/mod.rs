@@ -0,0 +1,5 @@
+mod pickup_items;
+pub use self::pickup_items::*;
+
+mod reward_enemy_kills;
+pub use self::reward_enemy_kills::*;
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    ITEM_PICKUP_RADIUS,
+    actors::components::{Health, Player, PowerUps},
+    items::{components::DroppedItem, data::PlayerItemTarget, messages::ItemPickedUp, resources::Inventory},
+};
+
+/// Picks up items the player walks over when their conditions are met and applies their effect
+pub fn pickup_items(
+    mut commands: Commands,
+    mut picked_up_writer: MessageWriter<ItemPickedUp>,
+    mut inventory: ResMut<Inventory>,
+    q_player: Single<(&Transform, &mut Health, &mut PowerUps), With<Player>>,
+    q_items: Query<(Entity, &Transform, &Item, Has<DroppedItem>), Without<Player>>,
+) {
+    let (player_transform, mut health, mut power_ups) = q_player.into_inner();
+
+    for (entity, transform, item, dropped) in &q_items {
+        let offset = (transform.translation - player_transform.translation).xz();
+        if offset.length() > ITEM_PICKUP_RADIUS {
+            continue;
+        }
+        // Only borrowed once an item is close, so the player isn't marked as changed every frame
+        let mut target = PlayerItemTarget {
+            health: &mut health,
+            power_ups: &mut power_ups,
+            inventory: &mut inventory,
+        };
+        // Items that would be wasted stay where they are
+        if !ItemCondition::all_met(&item.conditions, &target) {
+            continue;
+        }
+
+        item.effect.apply(&mut target);
+
+        picked_up_writer.write(ItemPickedUp {
+            effect: item.effect.clone(),
+            dropped,
+        });
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    actors::{components::Player, messages::Killed},
+    items::{components::DroppedItem, functions::spawn_item, resources::Inventory},
+    maps::components::LevelRoot,
+};
+
+/// Adds the score of enemies killed by the player and spawns the item every killed enemy drops
+/// where it died
+pub fn reward_enemy_kills(
+    mut commands: Commands,
+    mut killed_reader: MessageReader<Killed>,
+    mut inventory: ResMut<Inventory>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    item_library: Res<ItemLibrary>,
+    difficulty: Res<Difficulty>,
+    q_enemies: Query<(&EnemyStats, &GlobalTransform)>,
+    q_level: Single<Entity, With<LevelRoot>>,
+    q_player: Query<(), With<Player>>,
+) {
+    for killed in killed_reader.read() {
+        let Ok((stats, transform)) = q_enemies.get(killed.target) else {
+            continue;
+        };
+        // Enemies killed by other enemies don't score
+        if killed.source.is_some_and(|source| q_player.contains(source)) {
+            inventory.score += stats.score_value;
+        }
+
+        let Some(item_id) = &stats.drop_item else {
+            continue;
+        };
+        let item_mesh = meshes.add(bevy::prelude::Rectangle::new(2.0, 2.0));
+        if let Some(entity) = spawn_item(
+            &mut commands,
+            &item_library,
+            item_id,
+            Transform::from_translation(transform.translation()),
+            item_mesh,
+            &mut materials,
+            &difficulty,
+        ) {
+            commands.entity(entity).insert((DroppedItem, ChildOf(*q_level)));
+        }
+    }
+}
@@ -2,13 +2,20 @@ use bevy::prelude::*;
 
 use sector_shift_core::{prelude::*, utils::asset_plugin_with_fixed_path};
 
-use crate::states::states::GameState;
+use crate::{maps::resources::LaunchOptions, states::states::GameState};
 
 pub mod actors;
+pub mod audio;
+pub mod automap;
 pub mod inputs;
 pub mod items;
 pub mod maps;
+pub mod noise;
+pub mod perception;
+pub mod projectiles;
+pub mod settings;
 pub mod states;
+pub mod stats;
 pub mod ui;
 
 mod constants;
@@ -16,6 +23,8 @@ pub use self::constants::*;
 
 fn main() {
     let mut app = App::new();
+    app.insert_resource(LaunchOptions::from_env());
+
     app.add_plugins(
         DefaultPlugins
             .set(WindowPlugin {
@@ -35,9 +44,16 @@ fn main() {
     app.add_plugins(SectorShiftCorePlugin::new(GameState::LoadAssets));
 
     app.add_plugins(actors::ActorsPlugin);
+    app.add_plugins(audio::AudioPlugin);
+    app.add_plugins(automap::AutomapPlugin);
     app.add_plugins(inputs::InputsPlugin);
     app.add_plugins(items::ItemsPlugin);
     app.add_plugins(maps::MapsPlugin);
+    app.add_plugins(noise::NoisePlugin);
+    app.add_plugins(perception::PerceptionPlugin);
+    app.add_plugins(projectiles::ProjectilesPlugin);
+    app.add_plugins(settings::SettingsPlugin);
+    app.add_plugins(stats::StatsPlugin);
     app.add_plugins(ui::UiPlugin);
 
     app.run();
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+/// A door cell, opened and closed by the player. Its collider is a sensor that players and enemies
+/// walk through, but projectiles treat closed doors as solid
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct Door {
+    /// The cell the door is placed on
+    pub position: (i32, i32),
+    /// Open doors connect the areas on their sides, letting noise through
+    pub open: bool,
+}
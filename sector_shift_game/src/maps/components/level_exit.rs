@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+/// Ends the level when the player reaches this cell
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct LevelExit {
+    /// The cell the exit is placed on
+    pub position: (i32, i32),
+    /// The id of the level to load next
+    pub level_id: String,
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+/// Marks the entity every part of the current level is parented to
+#[derive(Component, Reflect, Debug, Default)]
+#[reflect(Component)]
+pub struct LevelRoot;
@@ -0,0 +1,11 @@
+mod door;
+pub use self::door::*;
+
+mod level_exit;
+pub use self::level_exit::*;
+
+mod level_root;
+pub use self::level_root::*;
+
+mod secret_area;
+pub use self::secret_area::*;
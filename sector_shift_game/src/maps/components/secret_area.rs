@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+/// A secret area that counts as found once the player steps on its cell
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct SecretArea {
+    /// The cell the secret is placed on
+    pub position: (i32, i32),
+}
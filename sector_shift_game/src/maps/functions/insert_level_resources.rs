@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    automap::resources::ExploredAreas, noise::resources::AreaConnections, stats::resources::LevelStats,
+};
+
+/// Replaces the resources describing the level being played, so nothing carries over from the
+/// previous one
+pub fn insert_level_resources(commands: &mut Commands, level: &Level, difficulty: Difficulty) {
+    commands.insert_resource(LevelStats::new(level, &difficulty));
+    commands.insert_resource(AreaConnections::new(&level.tiles));
+    commands.insert_resource(ExploredAreas::default());
+    commands.insert_resource(difficulty);
+}
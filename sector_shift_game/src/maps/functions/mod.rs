@@ -1,4 +1,11 @@
+mod insert_level_resources;
+pub use self::insert_level_resources::*;
+
 mod position_to_transform;
+pub use self::position_to_transform::*;
+
+mod spawn_env_object;
+pub use self::spawn_env_object::*;
 
 mod spawn_level;
 pub use self::spawn_level::*;
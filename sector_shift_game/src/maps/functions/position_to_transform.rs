@@ -1,4 +1,4 @@
-use bevy::transform::components::Transform;
+use bevy::prelude::*;
 
 use crate::{MAP_CELL_CEILING, MAP_CELL_HEIGHT, MAP_CELL_WIDTH};
 
@@ -9,3 +9,19 @@ pub fn position_to_transform(position: (i32, i32)) -> Transform {
         MAP_CELL_HEIGHT * (position.1 as f32 + 0.5),
     )
 }
+
+/// Returns a world position in map cells, keeping where inside of its cell it is
+pub fn translation_to_map(translation: Vec3) -> Vec2 {
+    Vec2::new(
+        translation.x / MAP_CELL_WIDTH,
+        translation.z / MAP_CELL_HEIGHT,
+    )
+}
+
+/// Returns the map cell containing a world position
+pub fn translation_to_position(translation: Vec3) -> (i32, i32) {
+    (
+        (translation.x / MAP_CELL_WIDTH).floor() as i32,
+        (translation.z / MAP_CELL_HEIGHT).floor() as i32,
+    )
+}
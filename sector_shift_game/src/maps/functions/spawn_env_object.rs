@@ -0,0 +1,75 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use sector_shift_core::{environment::data::EnvObjAppearance, prelude::*};
+
+use crate::{ENV_OBJECT_SIZE, ENV_SPRITE_HEIGHT, MAP_CELL_CEILING};
+
+/// Spawns a placeable decoration with the following components:
+/// - Name
+/// - Transform
+/// - Collider (cuboid) and RigidBody, unless flagged [`ObjectFlag::NonBlocking`]
+///
+/// and as a child standing on the floor either its scene, or an upright billboard of its sprite
+/// with an [`AnimatedBillboard`] if the sprite is animated.
+///
+/// Objects that aren't placeable, like the skybox, are refused.
+pub fn spawn_env_object(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    env_library: &EnvObjLibrary,
+    env_id: &str,
+    transform: Transform,
+    properties: &ObjectProperties,
+) -> Option<Entity> {
+    let Some(definition) = env_library.get(env_id).filter(|definition| definition.placeable) else {
+        warn!(
+            "Placeable environment object ID '{}' not found in EnvObjLibrary.",
+            env_id
+        );
+        return None;
+    };
+
+    let entity = commands.spawn((Name::new(definition.id.clone()), transform)).id();
+    match &definition.appearance {
+        EnvObjAppearance::Model { scene, .. } => {
+            commands.spawn((
+                SceneRoot(scene.clone()),
+                // The transform is at the middle of the cell's height
+                Transform::from_xyz(0.0, -MAP_CELL_CEILING / 2.0, 0.0),
+                ChildOf(entity),
+            ));
+        },
+        EnvObjAppearance::Sprite {
+            image,
+            size,
+            animation,
+        } => {
+            let width = ENV_SPRITE_HEIGHT * size.x as f32 / size.y.max(1) as f32;
+            let billboard = commands
+                .spawn((
+                    Mesh3d(meshes.add(bevy::prelude::Rectangle::new(width, ENV_SPRITE_HEIGHT))),
+                    MeshMaterial3d(materials.add(StandardMaterial {
+                        base_color_texture: Some(image.clone()),
+                        alpha_mode: AlphaMode::Mask(0.5),
+                        cull_mode: None,
+                        ..Default::default()
+                    })),
+                    Transform::from_xyz(0.0, (ENV_SPRITE_HEIGHT - MAP_CELL_CEILING) / 2.0, 0.0),
+                    Billboard::YAxis,
+                    ChildOf(entity),
+                ))
+                .id();
+            if let Some(animation) = animation {
+                commands.entity(billboard).insert(AnimatedBillboard::new(animation.clone()));
+            }
+        },
+    }
+    if !properties.has_flag(ObjectFlag::NonBlocking) {
+        commands.entity(entity).insert((
+            Collider::cuboid(ENV_OBJECT_SIZE, MAP_CELL_CEILING, ENV_OBJECT_SIZE),
+            RigidBody::Static,
+        ));
+    }
+    Some(entity)
+}
@@ -5,11 +5,16 @@ use sector_shift_core::{environment::components::Skybox, prelude::*};
 use crate::{
     MAP_CELL_CEILING, MAP_CELL_HEIGHT, MAP_CELL_WIDTH, PLAYER_HEALTH,
     actors::{
-        components::Player,
+        components::{Patrol, Player, PowerUps},
         functions::{spawn_actor, spawn_enemy},
     },
     items::functions::spawn_item,
-    maps::functions::position_to_transform::position_to_transform,
+    maps::{
+        components::{Door, LevelExit, LevelRoot, SecretArea},
+        functions::{position_to_transform, spawn_env_object},
+    },
+    noise::components::Ambush,
+    states::states::GameState,
 };
 
 pub fn spawn_level(
@@ -20,6 +25,7 @@ pub fn spawn_level(
     environment_library: &EnvObjLibrary,
     enemy_library: &EnemyLibrary,
     item_library: &ItemLibrary,
+    difficulty: &Difficulty,
 ) -> Entity {
     let map_width = level.width() as f32 * MAP_CELL_WIDTH;
     let map_height = level.height() as f32 * MAP_CELL_HEIGHT;
@@ -53,8 +59,15 @@ pub fn spawn_level(
         ..Default::default()
     });
 
-    // Spawn container for the level
-    let level_entity = commands.spawn((Name::new(level.id.clone()), Transform::default())).id();
+    // Spawn container for the level, torn down when returning to the main menu
+    let level_entity = commands
+        .spawn((
+            Name::new(level.id.clone()),
+            LevelRoot,
+            Transform::default(),
+            DespawnOnEnter(GameState::MainMenu),
+        ))
+        .id();
 
     // Spawn floor
     let floor_entity = commands
@@ -119,7 +132,12 @@ pub fn spawn_level(
                         Mesh3d(door_mesh.clone()),
                         MeshMaterial3d(door_material.clone()),
                         transform,
-                        //Collider::cuboid(MAP_CELL_WIDTH, MAP_CELL_CEILING, MAP_CELL_HEIGHT),
+                        Door {
+                            position,
+                            open: false,
+                        },
+                        Collider::cuboid(MAP_CELL_WIDTH, MAP_CELL_CEILING, MAP_CELL_HEIGHT),
+                        Sensor,
                         RigidBody::Static,
                     ))
                     .id();
@@ -148,9 +166,15 @@ pub fn spawn_level(
     .looking_to(direction, Vec3::Y);
 
     let player_entity = spawn_actor(commands, player_transform, PLAYER_HEALTH);
-    commands.entity(player_entity).insert((Player,));
+    commands.entity(player_entity).insert((Player, PowerUps::default()));
 
-    let camera_entity = commands.spawn((Camera3d::default(), Transform::from_xyz(0.0, 1.7, 0.0))).id();
+    let camera_entity = commands
+        .spawn((
+            Camera3d::default(),
+            SpatialListener::default(),
+            Transform::from_xyz(0.0, 1.7, 0.0),
+        ))
+        .id();
 
     commands.entity(player_entity).add_child(camera_entity);
 
@@ -175,17 +199,48 @@ pub fn spawn_level(
         ))
         .id();
 
+    // Spawn container for the decorations
+    let decorations_entity = commands
+        .spawn((
+            Name::new("Decorations"),
+            Transform::default(),
+            ChildOf(objects_entity),
+        ))
+        .id();
+
     // Spawn the [`MapObject`]s
     for (position, object) in &level.objects {
-        let transform = position_to_transform(*position);
+        if !object.spawns_on(difficulty.level) {
+            continue;
+        }
+
+        let mut transform = position_to_transform(*position);
+        if let Some(facing) = object.properties().and_then(|properties| properties.facing) {
+            // Map north is +Z, the same as the player start
+            let (x, z) = facing.coord();
+            transform.look_to(Vec3::new(x as f32, 0.0, z as f32), Vec3::Y);
+        }
         match object {
-            MapObject::Enemy(enemy_id) => {
-                if let Some(entity) = spawn_enemy(commands, enemy_library, enemy_id, transform) {
+            MapObject::Enemy(enemy_id, properties) => {
+                if let Some(entity) = spawn_enemy(
+                    commands,
+                    meshes,
+                    materials,
+                    enemy_library,
+                    enemy_id,
+                    transform,
+                ) {
+                    if properties.has_flag(ObjectFlag::Ambush) {
+                        commands.entity(entity).insert(Ambush);
+                    }
+                    if let Some(route) = PatrolRoute::for_enemy(level, *position) {
+                        commands.entity(entity).insert(Patrol::new(route));
+                    }
                     commands.entity(enemies_entity).add_child(entity);
                 }
             },
             // TODO
-            MapObject::Item(item_id) => {
+            MapObject::Item(item_id, _properties) => {
                 if let Some(entity) = spawn_item(
                     commands,
                     item_library,
@@ -193,12 +248,47 @@ pub fn spawn_level(
                     transform,
                     item_mesh.clone(),
                     materials,
+                    difficulty,
                 ) {
                     commands.entity(items_entity).add_child(entity);
                 }
             },
-            // TODO
-            MapObject::Exit(level_id) => (),
+            MapObject::Exit(level_id) => {
+                commands.spawn((
+                    Name::new(format!("Exit to {level_id}")),
+                    LevelExit {
+                        position: *position,
+                        level_id: level_id.clone(),
+                    },
+                    transform,
+                    ChildOf(objects_entity),
+                ));
+            },
+            MapObject::Env(env_id, properties) => {
+                if let Some(entity) = spawn_env_object(
+                    commands,
+                    meshes,
+                    materials,
+                    environment_library,
+                    env_id,
+                    transform,
+                    properties,
+                ) {
+                    commands.entity(decorations_entity).add_child(entity);
+                }
+            },
+            MapObject::Secret => {
+                commands.spawn((
+                    Name::new("Secret"),
+                    SecretArea {
+                        position: *position,
+                    },
+                    transform,
+                    ChildOf(objects_entity),
+                ));
+            },
+            // Turn markers are only read when the patrol routes are built
+            MapObject::PatrolTurn(_) => {},
         }
     }
 
@@ -224,15 +314,17 @@ pub fn spawn_level(
         ChildOf(env_entity),
     ));
 
-    // Spawn the skybox
-    if let Some(skybox_definition) = environment_library.get("skybox") {
+    // Spawn the skybox, it is global so never placed as a decoration
+    if let Some(scene) =
+        environment_library.get("skybox").and_then(|definition| definition.appearance.scene())
+    {
         commands.spawn((
             Name::new("Skybox"),
             Skybox {
                 rotation_speed: 0.5,
                 rotation_axis: Vec3::new(1., 0., 1.).normalize(),
             },
-            SceneRoot(skybox_definition.scene.clone()),
+            SceneRoot(scene.clone()),
             Transform::from_scale(Vec3::splat(1000.)),
             ChildOf(env_entity),
         ));
@@ -242,6 +334,7 @@ pub fn spawn_level(
     commands.entity(level_entity).add_child(floor_entity);
     commands.entity(level_entity).add_child(cells_entity);
     commands.entity(level_entity).add_child(objects_entity);
+    commands.entity(level_entity).add_child(player_entity);
 
     // Return level entity
     level_entity
@@ -1,14 +1,23 @@
 use bevy::prelude::*;
 
 use crate::{
-    maps::systems::{rotate_skybox, start_game},
+    maps::{
+        resources::{CurrentEpisode, CurrentLevel},
+        systems::{check_level_exit, despawn_level, rotate_skybox, start_game, use_doors},
+    },
     states::{states::GameState, system_sets::GameSet},
 };
 
 pub struct MapsPlugin;
 impl Plugin for MapsPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<CurrentLevel>().init_resource::<CurrentEpisode>();
+
         app.add_systems(OnEnter(GameState::SetupGame), start_game)
-            .add_systems(Update, rotate_skybox.in_set(GameSet::Running));
+            .add_systems(OnExit(GameState::Intermission), despawn_level)
+            .add_systems(
+                Update,
+                (rotate_skybox, use_doors, check_level_exit).in_set(GameSet::Running),
+            );
     }
 }
@@ -1,4 +1,6 @@
+pub mod components;
 pub mod functions;
+pub mod resources;
 pub mod systems;
 
 mod maps_plugin;
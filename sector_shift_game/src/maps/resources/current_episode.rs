@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+/// The episode being played, if the game was started from one
+#[derive(Resource, Reflect, Debug, Clone, Default)]
+#[reflect(Resource)]
+pub struct CurrentEpisode {
+    /// The id of the episode, see [`EpisodeLibrary`](sector_shift_core::prelude::EpisodeLibrary)
+    pub id: Option<String>,
+}
@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+/// The level that is spawned when entering [`GameState::SetupGame`](crate::states::states::GameState)
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct CurrentLevel {
+    /// The id of the level, see [`Level::load`](sector_shift_core::prelude::Level::load)
+    pub id: String,
+}
+
+impl Default for CurrentLevel {
+    fn default() -> Self {
+        Self {
+            id: "level_1".to_string(),
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum LaunchOptionsError {
+    #[error("Missing value after {0}")]
+    MissingValue(String),
+    #[error("Expected a position like 3,4 but got {0}")]
+    InvalidPosition(String),
+    #[error("Expected north, south, east or west but got {0}")]
+    InvalidFacing(String),
+    #[error("Unknown argument {0}")]
+    UnknownArgument(String),
+}
+
+/// Options given on the command line, used by the editor to playtest a level
+///
+/// `--level <id>` skips the main menu and starts the level, `--start <x>,<y>` and
+/// `--facing <north|south|east|west>` replace its player start and `--playtest` keeps
+/// the save game and stats untouched.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct LaunchOptions {
+    /// The level started right after loading
+    pub level: Option<String>,
+    /// Replaces the player start cell of the first started level
+    pub start: Option<(i32, i32)>,
+    /// Replaces the player start direction of the first started level
+    pub facing: Option<Direction>,
+    /// Whether progress should not be written
+    pub playtest: bool,
+}
+
+impl LaunchOptions {
+    pub const USAGE: &'static str =
+        "sector_shift_game [--level <id>] [--start <x>,<y>] [--facing <north|south|east|west>] [--playtest]";
+
+    /// Reads the options from the arguments of the process, warning about and ignoring invalid ones
+    pub fn from_env() -> Self {
+        Self::parse(std::env::args().skip(1)).unwrap_or_else(|e| {
+            warn!("{e}, usage: {}", Self::USAGE);
+            Self::default()
+        })
+    }
+
+    /// Reads the options from a list of arguments, without the program name
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, LaunchOptionsError> {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| LaunchOptionsError::MissingValue(arg.clone()));
+            match arg.as_str() {
+                "--level" => options.level = Some(value()?),
+                "--start" => {
+                    let value = value()?;
+                    let position = value
+                        .split_once(',')
+                        .and_then(|(x, y)| Some((x.trim().parse().ok()?, y.trim().parse().ok()?)))
+                        .ok_or(LaunchOptionsError::InvalidPosition(value))?;
+                    options.start = Some(position);
+                },
+                "--facing" => {
+                    let value = value()?;
+                    let facing = match value.to_lowercase().as_str() {
+                        "north" => Direction::NORTH,
+                        "south" => Direction::SOUTH,
+                        "east" => Direction::EAST,
+                        "west" => Direction::WEST,
+                        _ => return Err(LaunchOptionsError::InvalidFacing(value)),
+                    };
+                    options.facing = Some(facing);
+                },
+                "--playtest" => options.playtest = true,
+                _ => return Err(LaunchOptionsError::UnknownArgument(arg)),
+            }
+        }
+        Ok(options)
+    }
+
+    /// Applies the start override to the level, only the first call has an effect
+    pub fn take_start_override(&mut self, level: &mut Level) {
+        if let Some(position) = self.start.take() {
+            // Make sure the player doesn't spawn inside a wall
+            level.set_player_start(position, level.player_start.1);
+        }
+        if let Some(facing) = self.facing.take() {
+            level.player_start.1 = facing;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sector_shift_core::prelude::*;
+
+    use super::{LaunchOptions, LaunchOptionsError};
+
+    fn parse(args: &[&str]) -> Result<LaunchOptions, LaunchOptionsError> {
+        LaunchOptions::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_level_only() {
+        let mut options = parse(&["--level", "level_2"]).unwrap();
+        assert_eq!(
+            options,
+            LaunchOptions {
+                level: Some("level_2".to_string()),
+                ..Default::default()
+            }
+        );
+
+        // Without overrides the level keeps its own player start
+        let mut level = Level::new("level_2", (4, 4));
+        level.set_player_start((1, 2), Direction::EAST);
+        options.take_start_override(&mut level);
+        assert_eq!(level.player_start, ((1, 2), Direction::EAST));
+    }
+
+    #[test]
+    fn test_parse_playtest() {
+        let mut options = parse(&[
+            "--playtest",
+            "--level",
+            "playtest",
+            "--start",
+            "3, 4",
+            "--facing",
+            "North",
+        ])
+        .unwrap();
+        assert!(options.playtest);
+        assert_eq!(options.level.as_deref(), Some("playtest"));
+
+        let mut level = Level::new("playtest", (6, 6));
+        level.set_player_start((1, 1), Direction::EAST);
+        options.take_start_override(&mut level);
+        assert_eq!(level.player_start, ((3, 4), Direction::NORTH));
+        assert_eq!(level.tiles.get((3, 4)), Some(&TileType::Floor));
+
+        // Only the first started level is overridden
+        let mut next = Level::new("level_2", (6, 6));
+        next.set_player_start((1, 1), Direction::EAST);
+        options.take_start_override(&mut next);
+        assert_eq!(next.player_start, ((1, 1), Direction::EAST));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        // The level id needs its flag
+        assert_eq!(
+            parse(&["level_2"]),
+            Err(LaunchOptionsError::UnknownArgument("level_2".to_string()))
+        );
+        assert_eq!(
+            parse(&["--level", "level_2", "--fullscreen"]),
+            Err(LaunchOptionsError::UnknownArgument(
+                "--fullscreen".to_string()
+            ))
+        );
+        assert_eq!(
+            parse(&["--level"]),
+            Err(LaunchOptionsError::MissingValue("--level".to_string()))
+        );
+        assert_eq!(
+            parse(&["--start", "3;4"]),
+            Err(LaunchOptionsError::InvalidPosition("3;4".to_string()))
+        );
+        assert_eq!(
+            parse(&["--facing", "up"]),
+            Err(LaunchOptionsError::InvalidFacing("up".to_string()))
+        );
+    }
+}
@@ -0,0 +1,11 @@
+mod current_episode;
+pub use self::current_episode::*;
+
+mod current_level;
+pub use self::current_level::*;
+
+mod launch_options;
+pub use self::launch_options::*;
+
+mod save_game;
+pub use self::save_game::*;
@@ -0,0 +1,80 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::items::resources::Inventory;
+
+#[derive(Debug, Error)]
+pub enum SaveGameError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("RON error: {0}")]
+    Ron(#[from] ron::error::Error),
+    #[error("RON spanned error: {0}")]
+    RonSpanned(#[from] ron::error::SpannedError),
+}
+
+/// The progress of the player, written every time a level is started
+#[derive(Resource, Reflect, Serialize, Deserialize, Debug, Clone)]
+#[reflect(Resource)]
+pub struct SaveGame {
+    /// The id of the level the player last entered
+    pub level_id: String,
+    /// The id of the episode being played, if any
+    #[serde(default)]
+    pub episode_id: Option<String>,
+    /// What the player carried into that level
+    #[serde(default)]
+    pub inventory: Inventory,
+}
+
+// Constructor + Serialization methods
+impl SaveGame {
+    fn get_path() -> PathBuf {
+        "./saves/savegame.ron".into()
+    }
+
+    pub fn try_load() -> Result<Self, SaveGameError> {
+        let data = std::fs::read_to_string(Self::get_path())?;
+        let save_game: Self = ron::de::from_str(&data)?;
+        Ok(save_game)
+    }
+
+    pub fn save(&self) -> Result<(), SaveGameError> {
+        let data = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        let path = Self::get_path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SaveGame;
+
+    #[test]
+    fn test_save_game_inventory() {
+        // Saves from before the inventory was stored start with an empty one
+        let save_game: SaveGame = ron::de::from_str(r#"(level_id: "e1m2")"#).unwrap();
+        assert_eq!(save_game.inventory.score, 0);
+
+        let mut save_game = SaveGame {
+            episode_id: Some("episode_1".to_string()),
+            ..save_game
+        };
+        save_game.inventory.weapons.push("shotgun".to_string());
+        save_game.inventory.ammo.insert("shotgun".to_string(), 12);
+        save_game.inventory.score = 1500;
+        save_game.inventory.lives = 2;
+
+        let data = ron::ser::to_string(&save_game).unwrap();
+        let loaded: SaveGame = ron::de::from_str(&data).unwrap();
+        assert_eq!(loaded.inventory, save_game.inventory);
+        assert_eq!(loaded.episode_id, save_game.episode_id);
+    }
+}
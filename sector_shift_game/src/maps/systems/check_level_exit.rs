@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    actors::components::Player,
+    maps::{
+        components::LevelExit,
+        functions::translation_to_position,
+        resources::{CurrentEpisode, CurrentLevel},
+    },
+    states::states::GameState,
+    stats::resources::LevelStats,
+};
+
+/// Finishes the level once the player steps onto an exit. Leaving the last level of the episode
+/// finishes the episode, so there is no next level
+pub fn check_level_exit(
+    q_player: Single<&Transform, With<Player>>,
+    q_exits: Query<&LevelExit>,
+    current_level: Res<CurrentLevel>,
+    current_episode: Res<CurrentEpisode>,
+    episode_library: Res<EpisodeLibrary>,
+    mut level_stats: ResMut<LevelStats>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let position = translation_to_position(q_player.translation);
+    if let Some(exit) = q_exits.iter().find(|exit| exit.position == position) {
+        let episode_finished = current_episode
+            .id
+            .as_deref()
+            .and_then(|id| episode_library.get(id))
+            .is_some_and(|episode| episode.is_last_level(&current_level.id));
+        level_stats.next_level = (!episode_finished).then(|| exit.level_id.clone());
+        next_state.set(GameState::Intermission);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{platform::collections::HashMap, prelude::*, state::app::StatesPlugin};
+    use sector_shift_core::prelude::*;
+
+    use super::check_level_exit;
+    use crate::{
+        actors::components::Player,
+        maps::{
+            components::LevelExit,
+            functions::position_to_transform,
+            resources::{CurrentEpisode, CurrentLevel},
+        },
+        states::states::GameState,
+        stats::resources::LevelStats,
+    };
+
+    /// Steps onto the exit of the level in an episode of two levels and returns the next level
+    fn leave_level(level_id: &str) -> Option<String> {
+        let mut episode_library = EpisodeLibrary::default();
+        episode_library.add(EpisodeDefinition {
+            id: "episode_1".to_string(),
+            name: "Episode 1".to_string(),
+            levels: vec!["level_1".to_string(), "level_2".to_string()],
+            secret_levels: Vec::new(),
+            starting_inventory: Vec::new(),
+            music: HashMap::new(),
+        });
+
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .insert_resource(episode_library)
+            .insert_resource(CurrentLevel {
+                id: level_id.to_string(),
+            })
+            .insert_resource(CurrentEpisode {
+                id: Some("episode_1".to_string()),
+            })
+            .init_resource::<LevelStats>()
+            .add_systems(Update, check_level_exit);
+        app.world_mut().spawn((Player, position_to_transform((1, 1))));
+        app.world_mut().spawn(LevelExit {
+            position: (1, 1),
+            level_id: "level_2".to_string(),
+        });
+        app.update();
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Intermission
+        );
+        app.world().resource::<LevelStats>().next_level.clone()
+    }
+
+    #[test]
+    fn test_level_exit_leads_to_next_level() {
+        assert_eq!(leave_level("level_1"), Some("level_2".to_string()));
+    }
+
+    #[test]
+    fn test_last_level_exit_finishes_episode() {
+        assert_eq!(leave_level("level_2"), None);
+    }
+}
@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+use crate::maps::components::LevelRoot;
+
+pub fn despawn_level(mut commands: Commands, q_levels: Query<Entity, With<LevelRoot>>) {
+    for entity in &q_levels {
+        commands.entity(entity).despawn();
+    }
+}
@@ -1,5 +1,14 @@
+mod check_level_exit;
+pub use self::check_level_exit::*;
+
+mod despawn_level;
+pub use self::despawn_level::*;
+
 mod start_game;
 pub use self::start_game::*;
 
 mod rotate_skybox;
 pub use self::rotate_skybox::*;
+
+mod use_doors;
+pub use self::use_doors::*;
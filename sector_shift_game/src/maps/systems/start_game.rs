@@ -1,21 +1,36 @@
-use bevy::{
-    prelude::*,
-    window::{CursorGrabMode, CursorOptions},
-};
+use bevy::prelude::*;
 use sector_shift_core::prelude::*;
 
-use crate::maps::functions::spawn_level;
+use crate::{
+    audio::messages::PlayMusic,
+    items::resources::Inventory,
+    maps::{
+        functions::{insert_level_resources, spawn_level},
+        resources::{CurrentEpisode, CurrentLevel, LaunchOptions, SaveGame},
+    },
+    settings::resources::Settings,
+};
 
 pub fn start_game(
     mut commands: Commands,
+    current_level: Res<CurrentLevel>,
+    current_episode: Res<CurrentEpisode>,
+    inventory: Res<Inventory>,
+    settings: Res<Settings>,
+    mut launch_options: ResMut<LaunchOptions>,
+    mut music_writer: MessageWriter<PlayMusic>,
+    episode_library: Res<EpisodeLibrary>,
+    difficulty_library: Res<DifficultyLibrary>,
     environment_library: Res<EnvObjLibrary>,
     enemy_library: Res<EnemyLibrary>,
     item_library: Res<ItemLibrary>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    mut cursor_options: Single<&mut CursorOptions>,
 ) {
-    let level = Level::load("level_1");
+    let difficulty = difficulty_library.get(settings.difficulty);
+
+    let mut level = Level::load(&current_level.id);
+    launch_options.take_start_override(&mut level);
     spawn_level(
         &mut commands,
         &mut meshes,
@@ -24,8 +39,28 @@ pub fn start_game(
         &environment_library,
         &enemy_library,
         &item_library,
+        &difficulty,
     );
+    // The episode's choice of music wins over the level's own
+    let episode_music = current_episode
+        .id
+        .as_deref()
+        .and_then(|id| episode_library.get(id))
+        .and_then(|episode| episode.music_for(&level.id));
+    music_writer.write(PlayMusic {
+        sound: episode_music.map(str::to_string).or_else(|| level.music.clone()),
+    });
+    insert_level_resources(&mut commands, &level, difficulty);
 
-    cursor_options.grab_mode = CursorGrabMode::Locked;
-    cursor_options.visible = false;
+    if launch_options.playtest {
+        return;
+    }
+    let save_game = SaveGame {
+        level_id: current_level.id.clone(),
+        episode_id: current_episode.id.clone(),
+        inventory: inventory.clone(),
+    };
+    if let Err(e) = save_game.save() {
+        warn!("Failed to save game: {e}");
+    }
 }
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    USE_DISTANCE,
+    actors::components::Player,
+    inputs::data::GameAction,
+    maps::{components::Door, functions::translation_to_position},
+};
+
+/// Opens or closes the door the player is facing when they press use, open doors are hidden
+pub fn use_doors(
+    actions: Res<ActionState<GameAction>>,
+    q_player: Single<&GlobalTransform, With<Player>>,
+    mut q_doors: Query<(&mut Door, &mut Visibility)>,
+) {
+    if !actions.just_pressed(GameAction::Use) {
+        return;
+    }
+    let mut forward = *q_player.forward();
+    forward.y = 0.0;
+    let target = translation_to_position(q_player.translation() + forward.normalize_or_zero() * USE_DISTANCE);
+
+    for (mut door, mut visibility) in q_doors.iter_mut() {
+        if door.position != target {
+            continue;
+        }
+        door.open = !door.open;
+        *visibility = if door.open { Visibility::Hidden } else { Visibility::Inherited };
+    }
+}
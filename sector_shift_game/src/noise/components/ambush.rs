@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+/// An enemy placed with [`ObjectFlag::Ambush`](sector_shift_core::prelude::ObjectFlag), it ignores
+/// noise and only reacts once it sees the player
+#[derive(Component, Reflect, Debug, Default)]
+#[reflect(Component)]
+pub struct Ambush;
@@ -0,0 +1,2 @@
+mod ambush;
+pub use self::ambush::*;
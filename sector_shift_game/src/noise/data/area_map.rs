@@ -0,0 +1,126 @@
+use bevy::platform::collections::{HashMap, HashSet};
+use sector_shift_core::{prelude::*, utils::grid::Grid};
+
+/// The floor of a level split into areas, the regions that can be walked without passing a door
+#[derive(Debug, Clone)]
+pub struct AreaMap {
+    /// The area of every floor cell, `None` for walls and doors
+    pub cells: Grid<Option<usize>>,
+    /// The number of areas
+    pub count: usize,
+    /// The areas bordering each door, sorted and without duplicates
+    pub doors: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl AreaMap {
+    /// Labels the floor cells connected through their four neighbours with the same area, in row
+    /// major order of their first cell
+    pub fn new(tiles: &Grid<TileType>) -> Self {
+        let mut cells = Grid::new_copy((tiles.width(), tiles.height()), None);
+        let mut count = 0;
+
+        let mut visited = HashSet::new();
+        for (start, _) in tiles.enumerate() {
+            let area = tiles.flood(start, &mut visited, |tile| *tile == TileType::Floor);
+            if area.is_empty() {
+                continue;
+            }
+            for position in area {
+                cells.replace(position, Some(count));
+            }
+            count += 1;
+        }
+
+        let mut doors = HashMap::new();
+        for (position, tile) in tiles.enumerate() {
+            if !matches!(tile, TileType::Door(_)) {
+                continue;
+            }
+            let mut areas: Vec<_> =
+                Self::adjacent(position).filter_map(|next| cells.get(next).copied().flatten()).collect();
+            areas.sort();
+            areas.dedup();
+            doors.insert(position, areas);
+        }
+
+        Self {
+            cells,
+            count,
+            doors,
+        }
+    }
+
+    /// The areas a cell belongs to, the ones on both sides for doors and none for walls
+    pub fn areas_at(&self, position: (i32, i32)) -> Vec<usize> {
+        if let Some(areas) = self.doors.get(&position) {
+            return areas.clone();
+        }
+        self.cells.get(position).copied().flatten().into_iter().collect()
+    }
+
+    fn adjacent(position: (i32, i32)) -> impl Iterator<Item = (i32, i32)> {
+        [(0, 1), (1, 0), (0, -1), (-1, 0)]
+            .into_iter()
+            .map(move |(dx, dy)| (position.0 + dx, position.1 + dy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sector_shift_core::prelude::*;
+
+    use super::AreaMap;
+
+    #[test]
+    fn test_areas_split_by_doors_and_walls() {
+        // Two rooms joined by a door at (3, 1), the right one runs down through (4, 3) into the bottom
+        // right corner, and a walled off room in the bottom left corner
+        let mut level = Level::new("test", (7, 5));
+        for position in [
+            (1, 1),
+            (2, 1),
+            (1, 2),
+            (2, 2),
+            (4, 1),
+            (5, 1),
+            (4, 2),
+            (5, 2),
+            (4, 3),
+            (4, 4),
+            (5, 4),
+            (1, 4),
+            (2, 4),
+        ] {
+            level.set_tile(position, TileType::Floor);
+        }
+        level.set_tile((3, 1), TileType::Door(DoorAxis::Horizontal));
+        let areas = AreaMap::new(&level.tiles);
+
+        assert_eq!(areas.count, 3);
+        assert_eq!(areas.areas_at((1, 1)), vec![0]);
+        assert_eq!(areas.areas_at((2, 2)), vec![0]);
+        assert_eq!(areas.areas_at((5, 1)), vec![1]);
+        // Floor below the right room is part of it, the bottom left room is walled off
+        assert_eq!(areas.areas_at((4, 3)), vec![1]);
+        assert_eq!(areas.areas_at((5, 4)), vec![1]);
+        assert_eq!(areas.areas_at((1, 4)), vec![2]);
+        // The door borders both rooms, walls and cells outside of the level belong to none
+        assert_eq!(areas.areas_at((3, 1)), vec![0, 1]);
+        assert_eq!(areas.areas_at((0, 0)), Vec::<usize>::new());
+        assert_eq!(areas.areas_at((-1, 9)), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_door_inside_one_area() {
+        // A door in a room whose floor goes around it
+        let mut level = Level::new("test", (5, 3));
+        for position in [(1, 1), (3, 1), (1, 2), (2, 2), (3, 2)] {
+            level.set_tile(position, TileType::Floor);
+        }
+        level.set_tile((2, 1), TileType::Door(DoorAxis::Horizontal));
+        let areas = AreaMap::new(&level.tiles);
+
+        assert_eq!(areas.count, 1);
+        assert_eq!(areas.doors.get(&(2, 1)), Some(&vec![0]));
+    }
+}
@@ -0,0 +1,2 @@
+mod area_map;
+pub use self::area_map::*;
@@ -0,0 +1,2 @@
+mod noise;
+pub use self::noise::*;
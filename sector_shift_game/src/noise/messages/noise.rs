@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+/// A loud noise, heard by every enemy in an area connected to where it happened
+#[derive(Message, Debug, Clone, Copy)]
+pub struct Noise {
+    pub position: Vec3,
+    /// The actor making the noise, it doesn't alert itself
+    pub source: Option<Entity>,
+}
@@ -0,0 +1,8 @@
+pub mod components;
+pub mod data;
+pub mod messages;
+pub mod resources;
+pub mod systems;
+
+mod noise_plugin;
+pub use self::noise_plugin::*;
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use crate::{
+    noise::{
+        messages::Noise,
+        systems::{fire_noises, player_fire_noises, propagate_noise, update_area_connections},
+    },
+    states::system_sets::GameSet,
+};
+
+pub struct NoisePlugin;
+impl Plugin for NoisePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<Noise>();
+
+        app.add_systems(
+            Update,
+            (
+                update_area_connections,
+                fire_noises,
+                player_fire_noises,
+                propagate_noise,
+            )
+                .chain()
+                .in_set(GameSet::Running),
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use bevy::{platform::collections::HashSet, prelude::*};
+use sector_shift_core::{prelude::*, utils::grid::Grid};
+
+use crate::noise::data::AreaMap;
+
+/// The areas of the level being played and which of them are connected through open doors
+#[derive(Resource, Debug, Clone)]
+pub struct AreaConnections {
+    pub areas: AreaMap,
+    /// How many open doors connect each pair of areas, indexed by `a * areas.count + b` both ways
+    connections: Vec<u32>,
+    open_doors: HashSet<(i32, i32)>,
+}
+
+impl AreaConnections {
+    /// Labels the areas of the level, all doors start closed
+    pub fn new(tiles: &Grid<TileType>) -> Self {
+        let areas = AreaMap::new(tiles);
+        Self {
+            connections: vec![0; areas.count * areas.count],
+            areas,
+            open_doors: HashSet::new(),
+        }
+    }
+
+    /// Opens or closes the door on a cell, connecting or disconnecting the areas on its sides.
+    /// Returns whether anything changed
+    pub fn set_door_open(&mut self, position: (i32, i32), open: bool) -> bool {
+        let Some(areas) = self.areas.doors.get(&position) else {
+            return false;
+        };
+        let changed = if open { self.open_doors.insert(position) } else { self.open_doors.remove(&position) };
+        if !changed {
+            return false;
+        }
+
+        let count = self.areas.count;
+        for &a in areas {
+            for &b in areas.iter().filter(|&&b| b != a) {
+                let connection = &mut self.connections[a * count + b];
+                *connection = if open { *connection + 1 } else { *connection - 1 };
+            }
+        }
+        true
+    }
+
+    /// Whether two areas share an open door
+    pub fn is_connected(&self, a: usize, b: usize) -> bool {
+        a == b || self.connections.get(a * self.areas.count + b).is_some_and(|&doors| doors > 0)
+    }
+
+    /// The areas a noise made on a cell travels to, following open doors through any number of
+    /// areas
+    pub fn reachable_from(&self, position: (i32, i32)) -> Vec<bool> {
+        let mut reached = vec![false; self.areas.count];
+        let mut stack = self.areas.areas_at(position);
+        for &area in &stack {
+            reached[area] = true;
+        }
+        while let Some(area) = stack.pop() {
+            for (next, next_reached) in reached.iter_mut().enumerate() {
+                if !*next_reached && self.is_connected(area, next) {
+                    *next_reached = true;
+                    stack.push(next);
+                }
+            }
+        }
+        reached
+    }
+
+    /// Whether a cell blocks sight, walls and closed doors do and so does everything outside of the
+    /// level
+    pub fn blocks_sight(&self, position: (i32, i32)) -> bool {
+        match self.areas.cells.get(position) {
+            None => true,
+            Some(Some(_)) => false,
+            Some(None) => !self.open_doors.contains(&position),
+        }
+    }
+
+    /// Whether a noise made on one cell is heard on another
+    pub fn hears(&self, source: (i32, i32), listener: (i32, i32)) -> bool {
+        let reached = self.reachable_from(source);
+        self.areas.areas_at(listener).into_iter().any(|area| reached[area])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sector_shift_core::prelude::*;
+
+    use super::AreaConnections;
+
+    #[test]
+    fn test_closed_doors_block_noise() {
+        // A corridor along y = 0 split into three rooms by doors at x = 2 and x = 5
+        let mut level = Level::new("test", (8, 1));
+        for x in 0..8 {
+            let tile = if x == 2 || x == 5 { TileType::Door(DoorAxis::Horizontal) } else { TileType::Floor };
+            level.set_tile((x, 0), tile);
+        }
+        let connections = AreaConnections::new(&level.tiles);
+        assert_eq!(connections.areas.count, 3);
+
+        assert!(connections.hears((0, 0), (1, 0)));
+        assert!(!connections.hears((0, 0), (3, 0)));
+        assert_eq!(connections.reachable_from((4, 0)), vec![false, true, false]);
+        // Standing in a closed doorway is heard on both sides
+        assert_eq!(connections.reachable_from((2, 0)), vec![true, true, false]);
+    }
+
+    #[test]
+    fn test_open_doors_carry_noise_through_areas() {
+        // A corridor along y = 0 split into three rooms by doors at x = 2 and x = 5
+        let mut level = Level::new("test", (8, 1));
+        for x in 0..8 {
+            let tile = if x == 2 || x == 5 { TileType::Door(DoorAxis::Horizontal) } else { TileType::Floor };
+            level.set_tile((x, 0), tile);
+        }
+        let mut connections = AreaConnections::new(&level.tiles);
+
+        assert!(connections.set_door_open((2, 0), true));
+        assert!(connections.hears((0, 0), (4, 0)));
+        assert!(!connections.hears((0, 0), (7, 0)));
+
+        assert!(connections.set_door_open((5, 0), true));
+        assert_eq!(connections.reachable_from((0, 0)), vec![true, true, true]);
+        assert!(connections.hears((7, 0), (1, 0)));
+
+        // Opening twice doesn't need two closes
+        assert!(!connections.set_door_open((2, 0), true));
+        assert!(connections.set_door_open((2, 0), false));
+        assert!(!connections.is_connected(0, 1));
+        assert!(connections.is_connected(1, 2));
+        assert_eq!(connections.reachable_from((7, 0)), vec![false, true, true]);
+
+        // Only doors can be opened, and only closed ones block sight
+        assert!(!connections.set_door_open((0, 0), true));
+        assert!(connections.blocks_sight((2, 0)));
+        assert!(!connections.blocks_sight((5, 0)));
+        assert!(!connections.blocks_sight((0, 0)));
+        assert!(connections.blocks_sight((8, 0)));
+    }
+}
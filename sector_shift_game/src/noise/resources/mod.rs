@@ -0,0 +1,2 @@
+mod area_connections;
+pub use self::area_connections::*;
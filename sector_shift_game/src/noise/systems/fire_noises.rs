@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+use crate::{noise::messages::Noise, projectiles::messages::FireProjectile};
+
+/// Makes a noise wherever anything fires, so an enemy attacking the player wakes the enemies around
+/// it
+pub fn fire_noises(mut fire_reader: MessageReader<FireProjectile>, mut noise_writer: MessageWriter<Noise>) {
+    for fire in fire_reader.read() {
+        noise_writer.write(Noise {
+            position: fire.origin,
+            source: fire.shooter,
+        });
+    }
+}
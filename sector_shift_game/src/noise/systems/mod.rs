@@ -0,0 +1,11 @@
+mod fire_noises;
+pub use self::fire_noises::*;
+
+mod player_fire_noises;
+pub use self::player_fire_noises::*;
+
+mod propagate_noise;
+pub use self::propagate_noise::*;
+
+mod update_area_connections;
+pub use self::update_area_connections::*;
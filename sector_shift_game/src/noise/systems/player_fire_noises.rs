@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{actors::components::Player, inputs::data::GameAction, noise::messages::Noise};
+
+/// Makes a noise where the player stands whenever they pull the trigger
+pub fn player_fire_noises(
+    actions: Res<ActionState<GameAction>>,
+    mut noise_writer: MessageWriter<Noise>,
+    q_player: Single<(Entity, &GlobalTransform), With<Player>>,
+) {
+    if !actions.just_pressed(GameAction::Fire) {
+        return;
+    }
+    let (player, transform) = *q_player;
+    noise_writer.write(Noise {
+        position: transform.translation(),
+        source: Some(player),
+    });
+}
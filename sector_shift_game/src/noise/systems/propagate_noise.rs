@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    actors::components::Health,
+    audio::messages::PlaySound,
+    maps::functions::translation_to_position,
+    noise::{components::Ambush, messages::Noise, resources::AreaConnections},
+    perception::components::Perception,
+};
+
+/// Alerts the enemies in every area a noise reaches through open doors, ambushers keep waiting
+pub fn propagate_noise(
+    mut noise_reader: MessageReader<Noise>,
+    mut sound_writer: MessageWriter<PlaySound>,
+    connections: Res<AreaConnections>,
+    mut q_enemies: Query<
+        (Entity, &GlobalTransform, &Health, &mut Perception),
+        (With<Enemy>, Without<Ambush>),
+    >,
+) {
+    for noise in noise_reader.read() {
+        let reached = connections.reachable_from(translation_to_position(noise.position));
+
+        for (entity, transform, health, mut perception) in q_enemies.iter_mut() {
+            if Some(entity) == noise.source || !health.is_alive() {
+                continue;
+            }
+            let areas = connections.areas.areas_at(translation_to_position(transform.translation()));
+            if !areas.into_iter().any(|area| reached[area]) {
+                continue;
+            }
+            if perception.alert(noise.position) {
+                sound_writer.write(PlaySound {
+                    event: SoundEvent::EnemyAlert,
+                    position: Some(transform.translation()),
+                    source: Some(entity),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use sector_shift_core::prelude::*;
+
+    use super::propagate_noise;
+    use crate::{
+        actors::components::Health,
+        audio::messages::PlaySound,
+        maps::{components::Door, functions::position_to_transform},
+        noise::{messages::Noise, resources::AreaConnections, systems::update_area_connections},
+        perception::{components::Perception, data::Awareness},
+    };
+
+    /// Two rooms along y = 0 split by a door at x = 2, with an enemy in the second room
+    fn app(door_open: bool) -> (App, Entity) {
+        let mut level = Level::new("test", (5, 1));
+        for x in 0..5 {
+            let tile = if x == 2 { TileType::Door(DoorAxis::Horizontal) } else { TileType::Floor };
+            level.set_tile((x, 0), tile);
+        }
+
+        let mut app = App::new();
+        app.add_message::<Noise>()
+            .add_message::<PlaySound>()
+            .insert_resource(AreaConnections::new(&level.tiles))
+            .add_systems(Update, (update_area_connections, propagate_noise).chain());
+        app.world_mut().spawn(Door {
+            position: (2, 0),
+            open: door_open,
+        });
+        let enemy = app
+            .world_mut()
+            .spawn((
+                Enemy,
+                Health::new(100),
+                Perception::new(&EnemyStats::default()),
+                GlobalTransform::from(position_to_transform((4, 0))),
+            ))
+            .id();
+        (app, enemy)
+    }
+
+    /// Makes a noise in the first room and returns how aware the enemy is afterwards
+    fn make_noise(app: &mut App, enemy: Entity) -> Awareness {
+        app.world_mut().write_message(Noise {
+            position: position_to_transform((0, 0)).translation,
+            source: None,
+        });
+        app.update();
+        app.world().get::<Perception>(enemy).unwrap().awareness
+    }
+
+    #[test]
+    fn test_noise_alerts_through_open_doors() {
+        let (mut app, enemy) = app(true);
+        assert_eq!(make_noise(&mut app, enemy), Awareness::Alerted);
+        let perception = app.world().get::<Perception>(enemy).unwrap();
+        assert_eq!(
+            perception.last_known_position,
+            Some(position_to_transform((0, 0)).translation)
+        );
+    }
+
+    #[test]
+    fn test_closed_doors_keep_enemies_unaware() {
+        let (mut app, enemy) = app(false);
+        assert_eq!(make_noise(&mut app, enemy), Awareness::Unaware);
+
+        // Opening the door lets the next shot through
+        let world = app.world_mut();
+        world.query::<&mut Door>().single_mut(world).unwrap().open = true;
+        assert_eq!(make_noise(&mut app, enemy), Awareness::Alerted);
+    }
+}
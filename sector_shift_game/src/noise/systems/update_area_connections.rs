@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+use crate::{maps::components::Door, noise::resources::AreaConnections};
+
+/// Connects and disconnects areas as their doors open and close
+pub fn update_area_connections(
+    mut connections: ResMut<AreaConnections>,
+    q_doors: Query<&Door, Changed<Door>>,
+) {
+    for door in q_doors.iter() {
+        connections.set_door_open(door.position, door.open);
+    }
+}
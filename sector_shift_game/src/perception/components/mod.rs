@@ -0,0 +1,2 @@
+mod perception;
+pub use self::perception::*;
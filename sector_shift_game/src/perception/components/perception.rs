@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::perception::data::Awareness;
+
+/// What an enemy sees and remembers of the player
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct Perception {
+    /// How wide the sight cone is, in radians
+    pub field_of_view: f32,
+    /// How far the enemy can see, in units
+    pub sight_distance: f32,
+    /// Seconds the player has to stay in sight before the enemy is alerted
+    pub reaction_time: f32,
+    /// Seconds without seeing the player before the enemy drops back one level of awareness
+    pub give_up_time: f32,
+    pub awareness: Awareness,
+    /// Where the player was last seen or heard from
+    pub last_known_position: Option<Vec3>,
+    /// Seconds the player has been in sight since the enemy was last unaware
+    pub noticed: f32,
+    /// Seconds since the player was last seen or heard
+    pub unseen: f32,
+}
+
+impl Perception {
+    pub fn new(stats: &EnemyStats) -> Self {
+        Self {
+            field_of_view: stats.field_of_view.to_radians(),
+            sight_distance: stats.sight_range,
+            reaction_time: stats.reaction_time,
+            give_up_time: stats.give_up_time,
+            awareness: Awareness::Unaware,
+            last_known_position: None,
+            noticed: 0.0,
+            unseen: 0.0,
+        }
+    }
+
+    /// Whether a point is close enough and inside the sight cone around `forward`, ignoring height
+    /// and anything in the way
+    pub fn in_view(&self, eye: Vec3, forward: Vec3, target: Vec3) -> bool {
+        let offset = (target - eye).xz();
+        if offset.length() > self.sight_distance {
+            return false;
+        }
+        let Ok(forward) = Dir2::new(forward.xz()) else {
+            return false;
+        };
+        offset.length() <= f32::EPSILON || forward.angle_to(offset).abs() <= self.field_of_view / 2.0
+    }
+
+    /// Advances the timers with where the player is seen this frame, `None` when out of sight.
+    /// Returns whether the enemy just became alerted
+    pub fn update(&mut self, seen: Option<Vec3>, delta: f32) -> bool {
+        if let Some(position) = seen {
+            self.last_known_position = Some(position);
+            self.unseen = 0.0;
+            self.noticed += delta;
+            if self.awareness == Awareness::Unaware {
+                self.awareness = Awareness::Suspicious;
+            }
+            if self.awareness == Awareness::Suspicious && self.noticed >= self.reaction_time {
+                self.awareness = Awareness::Alerted;
+                return true;
+            }
+            return false;
+        }
+
+        self.unseen += delta;
+        if self.unseen < self.give_up_time {
+            return false;
+        }
+        self.unseen = 0.0;
+        match self.awareness {
+            // Goes looking around the last known position for a while before forgetting it
+            Awareness::Alerted => self.awareness = Awareness::Suspicious,
+            Awareness::Suspicious => {
+                self.awareness = Awareness::Unaware;
+                self.last_known_position = None;
+                self.noticed = 0.0;
+            },
+            Awareness::Unaware => {},
+        }
+        false
+    }
+
+    /// Alerts the enemy to a noise it heard at `position`, skipping the reaction time. Returns
+    /// whether it wasn't alerted already
+    pub fn alert(&mut self, position: Vec3) -> bool {
+        self.last_known_position = Some(position);
+        self.unseen = 0.0;
+        let was_alerted = self.awareness == Awareness::Alerted;
+        self.awareness = Awareness::Alerted;
+        !was_alerted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use sector_shift_core::prelude::*;
+
+    use super::Perception;
+    use crate::perception::data::Awareness;
+
+    fn perception() -> Perception {
+        Perception::new(&EnemyStats {
+            sight_range: 20.0,
+            field_of_view: 90.0,
+            reaction_time: 0.5,
+            give_up_time: 3.0,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_in_view() {
+        let perception = perception();
+        let eye = Vec3::new(0.0, 1.5, 0.0);
+
+        assert!(perception.in_view(eye, Vec3::NEG_Z, Vec3::new(0.0, 0.0, -10.0)));
+        // 45 degrees off is the edge of a 90 degree cone, 60 degrees is outside of it
+        assert!(perception.in_view(eye, Vec3::NEG_Z, Vec3::new(9.9, 0.0, -10.0)));
+        assert!(!perception.in_view(eye, Vec3::NEG_Z, Vec3::new(10.0, 0.0, -5.8)));
+        assert!(!perception.in_view(eye, Vec3::NEG_Z, Vec3::new(0.0, 0.0, 10.0)));
+        assert!(!perception.in_view(eye, Vec3::NEG_Z, Vec3::new(0.0, 0.0, -25.0)));
+    }
+
+    #[test]
+    fn test_reaction_time() {
+        let mut perception = perception();
+        let player = Vec3::new(1.0, 0.0, -5.0);
+
+        assert!(!perception.update(Some(player), 0.3));
+        assert_eq!(perception.awareness, Awareness::Suspicious);
+        assert_eq!(perception.last_known_position, Some(player));
+
+        // Ducking out of sight for a moment doesn't reset the reaction
+        assert!(!perception.update(None, 1.0));
+        assert_eq!(perception.awareness, Awareness::Suspicious);
+        assert!(perception.update(Some(player), 0.3));
+        assert_eq!(perception.awareness, Awareness::Alerted);
+        assert!(!perception.update(Some(player), 0.3));
+    }
+
+    #[test]
+    fn test_give_up() {
+        let mut perception = perception();
+        let player = Vec3::new(1.0, 0.0, -5.0);
+        assert!(perception.alert(player));
+        assert!(!perception.alert(player));
+
+        // Alerted enemies hunt the last known position, then stay suspicious before forgetting it
+        assert!(!perception.update(None, 2.0));
+        assert_eq!(perception.awareness, Awareness::Alerted);
+        perception.update(None, 1.5);
+        assert_eq!(perception.awareness, Awareness::Suspicious);
+        assert_eq!(perception.last_known_position, Some(player));
+        perception.update(None, 3.0);
+        assert_eq!(perception.awareness, Awareness::Unaware);
+        assert_eq!(perception.last_known_position, None);
+
+        // Back to unaware the full reaction time applies again
+        assert!(!perception.update(Some(player), 0.3));
+        assert_eq!(perception.awareness, Awareness::Suspicious);
+    }
+}
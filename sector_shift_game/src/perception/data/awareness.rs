@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+/// How much an enemy knows about the player, for behaviour code to react to
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Awareness {
+    /// Standing guard or patrolling
+    #[default]
+    Unaware,
+    /// Caught a glimpse of the player and is about to react, or lost them and is still looking
+    Suspicious,
+    /// Knows where the player is, or was last seen
+    Alerted,
+}
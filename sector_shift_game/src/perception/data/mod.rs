@@ -0,0 +1,2 @@
+mod awareness;
+pub use self::awareness::*;
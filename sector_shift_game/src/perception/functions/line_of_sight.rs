@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+
+/// Whether nothing blocks the view between two points given in map cells, walking every cell the
+/// line crosses. The cells the points are in never block, so enemies standing in doorways can see
+/// out of them
+pub fn line_of_sight(from: Vec2, to: Vec2, blocks: impl Fn((i32, i32)) -> bool) -> bool {
+    let mut cell = (from.x.floor() as i32, from.y.floor() as i32);
+    let end = (to.x.floor() as i32, to.y.floor() as i32);
+    let delta = to - from;
+
+    // How far along the line each step to the next column or row is, and where the first ones are
+    let step = (sign(delta.x), sign(delta.y));
+    let t_delta = Vec2::new(1.0 / delta.x.abs(), 1.0 / delta.y.abs());
+    let mut t_max = Vec2::new(
+        match step.0 {
+            1 => (cell.0 as f32 + 1.0 - from.x) * t_delta.x,
+            -1 => (from.x - cell.0 as f32) * t_delta.x,
+            _ => f32::INFINITY,
+        },
+        match step.1 {
+            1 => (cell.1 as f32 + 1.0 - from.y) * t_delta.y,
+            -1 => (from.y - cell.1 as f32) * t_delta.y,
+            _ => f32::INFINITY,
+        },
+    );
+
+    let steps = (end.0 - cell.0).abs() + (end.1 - cell.1).abs();
+    for _ in 1..steps {
+        if t_max.x < t_max.y {
+            cell.0 += step.0;
+            t_max.x += t_delta.x;
+        } else {
+            cell.1 += step.1;
+            t_max.y += t_delta.y;
+        }
+        if blocks(cell) {
+            return false;
+        }
+    }
+    true
+}
+
+fn sign(value: f32) -> i32 {
+    if value > 0.0 {
+        1
+    } else if value < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::line_of_sight;
+
+    /// A wall column at x = 2 with an opening at y = 2
+    fn blocks(cell: (i32, i32)) -> bool {
+        cell.0 == 2 && cell.1 != 2
+    }
+
+    #[test]
+    fn test_walls_block_sight() {
+        assert!(line_of_sight(
+            Vec2::new(0.5, 0.5),
+            Vec2::new(1.5, 3.5),
+            blocks
+        ));
+        assert!(!line_of_sight(
+            Vec2::new(0.5, 0.5),
+            Vec2::new(4.5, 0.5),
+            blocks
+        ));
+        assert!(!line_of_sight(
+            Vec2::new(4.5, 3.5),
+            Vec2::new(0.5, 0.5),
+            blocks
+        ));
+        // Straight through the opening, and at a slant through it
+        assert!(line_of_sight(
+            Vec2::new(0.5, 2.5),
+            Vec2::new(4.5, 2.5),
+            blocks
+        ));
+        assert!(line_of_sight(
+            Vec2::new(1.5, 2.2),
+            Vec2::new(3.5, 2.8),
+            blocks
+        ));
+        // Aiming past the opening
+        assert!(!line_of_sight(
+            Vec2::new(0.5, 2.5),
+            Vec2::new(4.5, 4.5),
+            blocks
+        ));
+    }
+
+    #[test]
+    fn test_end_cells_never_block() {
+        assert!(line_of_sight(
+            Vec2::new(2.5, 0.5),
+            Vec2::new(3.5, 0.5),
+            blocks
+        ));
+        assert!(line_of_sight(
+            Vec2::new(2.5, 0.5),
+            Vec2::new(2.5, 0.9),
+            blocks
+        ));
+        assert!(!line_of_sight(
+            Vec2::new(2.5, 0.5),
+            Vec2::new(2.5, 4.5),
+            blocks
+        ));
+    }
+}
@@ -0,0 +1,2 @@
+mod line_of_sight;
+pub use self::line_of_sight::*;
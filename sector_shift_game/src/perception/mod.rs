@@ -0,0 +1,7 @@
+pub mod components;
+pub mod data;
+pub mod functions;
+pub mod systems;
+
+mod perception_plugin;
+pub use self::perception_plugin::*;
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "dev")]
+use crate::perception::systems::draw_sight_cones;
+use crate::{
+    perception::systems::{face_last_known_position, perceive_player},
+    states::system_sets::GameSet,
+};
+
+pub struct PerceptionPlugin;
+impl Plugin for PerceptionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (perceive_player, face_last_known_position).chain().in_set(GameSet::Running),
+        );
+
+        #[cfg(feature = "dev")]
+        app.add_systems(Update, draw_sight_cones.in_set(GameSet::Running));
+    }
+}
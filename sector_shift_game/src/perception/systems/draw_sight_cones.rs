@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+use crate::{
+    actors::components::Health,
+    perception::{components::Perception, data::Awareness},
+};
+
+/// How many segments the far edge of a sight cone is drawn with
+const CONE_SEGMENTS: usize = 12;
+
+/// Draws the sight cone of every living enemy coloured by its awareness, and a marker where it
+/// last knew the player to be
+pub fn draw_sight_cones(mut gizmos: Gizmos, q_enemies: Query<(&GlobalTransform, &Health, &Perception)>) {
+    for (transform, health, perception) in q_enemies.iter() {
+        if !health.is_alive() {
+            continue;
+        }
+        let color = match perception.awareness {
+            Awareness::Unaware => Color::srgb(0.0, 1.0, 0.0),
+            Awareness::Suspicious => Color::srgb(1.0, 1.0, 0.0),
+            Awareness::Alerted => Color::srgb(1.0, 0.0, 0.0),
+        };
+
+        let eye = transform.translation();
+        let forward = transform.forward().with_y(0.0).normalize_or_zero() * perception.sight_distance;
+        let edge = (0..=CONE_SEGMENTS).map(|i| {
+            let angle = perception.field_of_view * (i as f32 / CONE_SEGMENTS as f32 - 0.5);
+            eye + Quat::from_rotation_y(angle) * forward
+        });
+        gizmos.linestrip(
+            std::iter::once(eye).chain(edge).chain(std::iter::once(eye)),
+            color,
+        );
+
+        if let Some(position) = perception.last_known_position {
+            gizmos.sphere(Isometry3d::from_translation(position), 0.3, color);
+        }
+    }
+}
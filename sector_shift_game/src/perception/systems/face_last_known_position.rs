@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use crate::{
+    ENEMY_TURN_SPEED,
+    actors::components::Health,
+    perception::{components::Perception, data::Awareness},
+};
+
+/// Turns alerted enemies towards where they last saw or heard the player
+pub fn face_last_known_position(
+    time: Res<Time>,
+    mut q_enemies: Query<(&mut Transform, &Health, &Perception)>,
+) {
+    for (mut transform, health, perception) in q_enemies.iter_mut() {
+        if !health.is_alive() || perception.awareness != Awareness::Alerted {
+            continue;
+        }
+        let Some(position) = perception.last_known_position else {
+            continue;
+        };
+        let mut offset = position - transform.translation;
+        offset.y = 0.0;
+        let Ok(direction) = Dir3::new(offset) else {
+            continue;
+        };
+        let target = Transform::default().looking_to(direction, Vec3::Y).rotation;
+        transform.rotation = transform.rotation.rotate_towards(target, ENEMY_TURN_SPEED * time.delta_secs());
+    }
+}
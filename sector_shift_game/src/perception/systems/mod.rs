@@ -0,0 +1,10 @@
+#[cfg(feature = "dev")]
+mod draw_sight_cones;
+#[cfg(feature = "dev")]
+pub use self::draw_sight_cones::*;
+
+mod face_last_known_position;
+pub use self::face_last_known_position::*;
+
+mod perceive_player;
+pub use self::perceive_player::*;
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    actors::components::{Health, Player},
+    audio::messages::PlaySound,
+    maps::functions::translation_to_map,
+    noise::resources::AreaConnections,
+    perception::{components::Perception, functions::line_of_sight},
+};
+
+/// Looks for the player from every enemy, walls and closed doors in the way hide them
+pub fn perceive_player(
+    time: Res<Time>,
+    connections: Res<AreaConnections>,
+    mut sound_writer: MessageWriter<PlaySound>,
+    q_player: Single<&GlobalTransform, With<Player>>,
+    mut q_enemies: Query<(Entity, &GlobalTransform, &Health, &mut Perception)>,
+) {
+    let player = q_player.translation();
+
+    for (entity, transform, health, mut perception) in q_enemies.iter_mut() {
+        if !health.is_alive() {
+            continue;
+        }
+        let eye = transform.translation();
+        let sees = perception.in_view(eye, *transform.forward(), player)
+            && line_of_sight(
+                translation_to_map(eye),
+                translation_to_map(player),
+                |cell| connections.blocks_sight(cell),
+            );
+
+        if perception.update(sees.then_some(player), time.delta_secs()) {
+            sound_writer.write(PlaySound {
+                event: SoundEvent::EnemyAlert,
+                position: Some(eye),
+                source: Some(entity),
+            });
+        }
+    }
+}
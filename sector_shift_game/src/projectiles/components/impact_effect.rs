@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+/// The sprite shown where a projectile hit, despawned once its time is up
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct ImpactEffect {
+    /// Seconds before it disappears
+    pub remaining: f32,
+}
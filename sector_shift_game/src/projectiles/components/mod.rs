@@ -0,0 +1,5 @@
+mod impact_effect;
+pub use self::impact_effect::*;
+
+mod projectile;
+pub use self::projectile::*;
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+/// A projectile in flight, the rest of its values are read from its definition in the
+/// [`ProjectileLibrary`](sector_shift_core::prelude::ProjectileLibrary)
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct Projectile {
+    /// The id of the projectile's definition
+    pub id: String,
+    /// Units per second
+    pub velocity: Vec3,
+    /// The actor that fired it, credited with its damage and never hit by it directly
+    pub shooter: Option<Entity>,
+    /// Seconds before it disappears without hitting anything
+    pub remaining: f32,
+}
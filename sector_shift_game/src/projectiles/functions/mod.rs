@@ -0,0 +1,5 @@
+mod spawn_impact_effect;
+pub use self::spawn_impact_effect::*;
+
+mod spawn_projectile;
+pub use self::spawn_projectile::*;
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use sector_shift_core::{prelude::*, projectiles::resources::ProjectileDefinition};
+
+use crate::projectiles::components::ImpactEffect;
+
+/// Spawns the impact sprite of a projectile at `position`, `None` if it has none
+pub fn spawn_impact_effect(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    definition: &ProjectileDefinition,
+    position: Vec3,
+) -> Option<Entity> {
+    let (impact, image) = definition.impact.as_ref().zip(definition.impact_sprite.as_ref())?;
+    let aspect = impact
+        .animation
+        .as_ref()
+        .map(|animation| animation.frame_size.0 as f32 / animation.frame_size.1.max(1) as f32)
+        .unwrap_or(1.0);
+
+    let entity = commands
+        .spawn((
+            Name::new(format!("{} impact", definition.id)),
+            ImpactEffect {
+                remaining: impact.duration(),
+            },
+            Mesh3d(meshes.add(bevy::prelude::Rectangle::new(
+                impact.size * aspect,
+                impact.size,
+            ))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color_texture: Some(image.clone()),
+                alpha_mode: AlphaMode::Mask(0.5),
+                cull_mode: None,
+                unlit: true,
+                ..Default::default()
+            })),
+            Transform::from_translation(position),
+            Billboard::Full,
+        ))
+        .id();
+    if let Some(animation) = &impact.animation {
+        commands.entity(entity).insert(AnimatedBillboard::new(animation.clone()));
+    }
+    Some(entity)
+}
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+use sector_shift_core::{
+    prelude::*,
+    projectiles::{data::ProjectileAppearance, resources::ProjectileDefinition},
+};
+
+use crate::projectiles::components::Projectile;
+
+/// Spawns a projectile with the following components:
+/// - Name
+/// - Projectile
+/// - Transform, facing the way it flies
+///
+/// and as a child either its scene, or a billboard of its sprite with an [`AnimatedBillboard`] if
+/// the sprite is animated.
+///
+/// Projectiles have no collider, they find what they hit by casting their shape as they move.
+pub fn spawn_projectile(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    definition: &ProjectileDefinition,
+    shooter: Option<Entity>,
+    origin: Vec3,
+    direction: Dir3,
+) -> Entity {
+    let entity = commands
+        .spawn((
+            Name::new(definition.id.clone()),
+            Projectile {
+                id: definition.id.clone(),
+                velocity: direction * definition.speed,
+                shooter,
+                remaining: definition.lifetime,
+            },
+            Transform::from_translation(origin).looking_to(direction, Vec3::Y),
+        ))
+        .id();
+
+    match &definition.appearance {
+        ProjectileAppearance::Model { scene, .. } => {
+            commands.spawn((
+                SceneRoot(scene.clone()),
+                Transform::default(),
+                ChildOf(entity),
+            ));
+        },
+        ProjectileAppearance::Sprite {
+            image,
+            size,
+            animation,
+        } => {
+            let width = definition.size * size.x as f32 / size.y.max(1) as f32;
+            let billboard = commands
+                .spawn((
+                    Mesh3d(meshes.add(bevy::prelude::Rectangle::new(width, definition.size))),
+                    MeshMaterial3d(materials.add(StandardMaterial {
+                        base_color_texture: Some(image.clone()),
+                        alpha_mode: AlphaMode::Mask(0.5),
+                        cull_mode: None,
+                        unlit: true,
+                        ..Default::default()
+                    })),
+                    Transform::default(),
+                    Billboard::Full,
+                    ChildOf(entity),
+                ))
+                .id();
+            if let Some(animation) = animation {
+                commands.entity(billboard).insert(AnimatedBillboard::new(animation.clone()));
+            }
+        },
+    }
+    entity
+}
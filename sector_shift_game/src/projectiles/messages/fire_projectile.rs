@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+/// Requests that a projectile is fired, sent by [`enemy_attacks`](crate::actors::systems::enemy_attacks)
+/// for enemies whose attacks have a projectile
+#[derive(Message, Debug, Clone)]
+pub struct FireProjectile {
+    /// The id of the projectile in the [`ProjectileLibrary`](sector_shift_core::prelude::ProjectileLibrary)
+    pub projectile: String,
+    /// The actor firing it
+    pub shooter: Option<Entity>,
+    /// Where it starts, outside of the shooter's collider
+    pub origin: Vec3,
+    pub direction: Dir3,
+}
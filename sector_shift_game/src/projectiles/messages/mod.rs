@@ -0,0 +1,2 @@
+mod fire_projectile;
+pub use self::fire_projectile::*;
@@ -0,0 +1,7 @@
+pub mod components;
+pub mod functions;
+pub mod messages;
+pub mod systems;
+
+mod projectiles_plugin;
+pub use self::projectiles_plugin::*;
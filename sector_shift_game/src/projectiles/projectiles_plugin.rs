@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+use crate::{
+    projectiles::{
+        messages::FireProjectile,
+        systems::{expire_impact_effects, fire_projectiles, move_projectiles},
+    },
+    states::system_sets::GameSet,
+};
+
+pub struct ProjectilesPlugin;
+impl Plugin for ProjectilesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<FireProjectile>();
+
+        app.add_systems(
+            Update,
+            (fire_projectiles, move_projectiles, expire_impact_effects).chain().in_set(GameSet::Running),
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+use crate::projectiles::components::ImpactEffect;
+
+/// Despawns impact sprites once they have been shown
+pub fn expire_impact_effects(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_effects: Query<(Entity, &mut ImpactEffect)>,
+) {
+    for (entity, mut effect) in q_effects.iter_mut() {
+        effect.remaining -= time.delta_secs();
+        if effect.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
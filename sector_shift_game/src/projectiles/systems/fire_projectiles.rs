@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    maps::components::LevelRoot,
+    projectiles::{functions::spawn_projectile, messages::FireProjectile},
+};
+
+/// Spawns the projectiles requested by [`FireProjectile`] as part of the level
+pub fn fire_projectiles(
+    mut commands: Commands,
+    mut fire_reader: MessageReader<FireProjectile>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    projectile_library: Res<ProjectileLibrary>,
+    q_level: Single<Entity, With<LevelRoot>>,
+) {
+    for fire in fire_reader.read() {
+        let Some(definition) = projectile_library.get(&fire.projectile) else {
+            warn!(
+                "Projectile ID '{}' not found in ProjectileLibrary.",
+                fire.projectile
+            );
+            continue;
+        };
+        let entity = spawn_projectile(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            definition,
+            fire.shooter,
+            fire.origin,
+            fire.direction,
+        );
+        commands.entity(entity).insert(ChildOf(*q_level));
+    }
+}
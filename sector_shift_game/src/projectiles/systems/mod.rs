@@ -0,0 +1,8 @@
+mod expire_impact_effects;
+pub use self::expire_impact_effects::*;
+
+mod fire_projectiles;
+pub use self::fire_projectiles::*;
+
+mod move_projectiles;
+pub use self::move_projectiles::*;
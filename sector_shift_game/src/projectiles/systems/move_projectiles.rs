@@ -0,0 +1,130 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    actors::{components::Health, messages::Damage},
+    audio::messages::PlaySound,
+    maps::components::{Door, LevelRoot},
+    projectiles::{components::Projectile, functions::spawn_impact_effect},
+};
+
+/// Moves projectiles along their arc and explodes them on the first wall, door or actor in their
+/// way, damaging what they hit directly and every actor in reach of their splash
+pub fn move_projectiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut damage_writer: MessageWriter<Damage>,
+    mut sound_writer: MessageWriter<PlaySound>,
+    projectile_library: Res<ProjectileLibrary>,
+    mut q_projectiles: Query<(Entity, &mut Projectile, &mut Transform)>,
+    q_actors: Query<(Entity, &GlobalTransform), With<Health>>,
+    q_sensors: Query<(), (With<Sensor>, Without<Door>)>,
+    q_doors: Query<&Door>,
+    q_bodies: Query<&RigidBody>,
+    q_level: Single<Entity, With<LevelRoot>>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut projectile, mut transform) in q_projectiles.iter_mut() {
+        let Some(definition) = projectile_library.get(&projectile.id) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        projectile.remaining -= delta;
+        if projectile.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        projectile.velocity.y -= definition.gravity * delta;
+        let Ok(direction) = Dir3::new(projectile.velocity) else {
+            continue;
+        };
+        let distance = projectile.velocity.length() * delta;
+
+        // Sensors like pickups and exits are flown through, except for closed doors
+        let filter = SpatialQueryFilter::default().with_excluded_entities(projectile.shooter);
+        let hit = spatial_query.cast_shape_predicate(
+            &Collider::sphere(definition.radius),
+            transform.translation,
+            Quat::IDENTITY,
+            direction,
+            &ShapeCastConfig::from_max_distance(distance),
+            &filter,
+            &|entity| !q_sensors.contains(entity) && !q_doors.get(entity).is_ok_and(|door| door.open),
+        );
+
+        let Some(hit) = hit else {
+            transform.translation += direction * distance;
+            transform.look_to(direction, Vec3::Y);
+            continue;
+        };
+
+        // The centre of the sphere when it touched, still outside of what it hit
+        let point = transform.translation + direction * hit.distance;
+        if q_actors.contains(hit.entity) {
+            damage_writer.write(Damage {
+                target: hit.entity,
+                amount: definition.damage,
+                source: projectile.shooter,
+            });
+        }
+        if let Some(splash) = definition.splash {
+            for (actor, actor_transform) in q_actors.iter() {
+                if actor == hit.entity {
+                    continue;
+                }
+                let offset = actor_transform.translation() - point;
+                let amount = splash.damage_at(offset.length());
+                if amount <= 0 {
+                    continue;
+                }
+                // Walls, closed doors and decorations shelter actors behind them
+                let sheltered = Dir3::new(offset).is_ok_and(|towards| {
+                    spatial_query
+                        .cast_ray_predicate(
+                            point,
+                            towards,
+                            offset.length(),
+                            true,
+                            &SpatialQueryFilter::default(),
+                            &|entity| {
+                                entity != actor
+                                    && q_bodies.get(entity).is_ok_and(|body| *body == RigidBody::Static)
+                                    && !q_sensors.contains(entity)
+                                    && !q_doors.get(entity).is_ok_and(|door| door.open)
+                            },
+                        )
+                        .is_some()
+                });
+                if !sheltered {
+                    damage_writer.write(Damage {
+                        target: actor,
+                        amount,
+                        source: projectile.shooter,
+                    });
+                }
+            }
+        }
+
+        sound_writer.write(PlaySound {
+            event: SoundEvent::ProjectileImpact,
+            position: Some(point),
+            source: projectile.shooter,
+        });
+        if let Some(effect) = spawn_impact_effect(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            definition,
+            point,
+        ) {
+            commands.entity(effect).insert(ChildOf(*q_level));
+        }
+        commands.entity(entity).despawn();
+    }
+}
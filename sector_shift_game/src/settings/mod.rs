@@ -0,0 +1,5 @@
+pub mod resources;
+pub mod systems;
+
+mod settings_plugin;
+pub use self::settings_plugin::*;
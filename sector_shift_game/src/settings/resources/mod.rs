@@ -0,0 +1,2 @@
+mod settings;
+pub use self::settings::*;
@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SettingsError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("RON error: {0}")]
+    Ron(#[from] ron::error::Error),
+    #[error("RON spanned error: {0}")]
+    RonSpanned(#[from] ron::error::SpannedError),
+}
+
+/// User configurable settings, persisted between runs.
+#[derive(Resource, Reflect, Serialize, Deserialize, Debug, Clone)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct Settings {
+    /// Radians turned per pixel of mouse movement
+    pub mouse_sensitivity: f32,
+    /// Vertical field of view of the player camera in degrees
+    pub fov: f32,
+    /// Global volume between `0.0` and `1.0`
+    pub volume: f32,
+    /// The difficulty new games are started with
+    pub difficulty: DifficultyLevel,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            mouse_sensitivity: 0.002,
+            fov: 70.0,
+            volume: 0.8,
+            difficulty: DifficultyLevel::default(),
+        }
+    }
+}
+
+// Constructor + Serialization methods
+impl Settings {
+    pub const MOUSE_SENSITIVITY_RANGE: (f32, f32) = (0.0005, 0.01);
+    pub const FOV_RANGE: (f32, f32) = (60.0, 120.0);
+    pub const VOLUME_RANGE: (f32, f32) = (0.0, 1.0);
+
+    fn get_path() -> PathBuf {
+        "./config/settings.ron".into()
+    }
+
+    pub fn load() -> Self {
+        Self::try_load().unwrap_or_default()
+    }
+
+    pub fn try_load() -> Result<Self, SettingsError> {
+        let data = std::fs::read_to_string(Self::get_path())?;
+        let settings: Self = ron::de::from_str(&data)?;
+        Ok(settings)
+    }
+
+    pub fn save(&self) -> Result<(), SettingsError> {
+        let data = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        let path = Self::get_path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use crate::{
+    settings::{
+        resources::Settings,
+        systems::{apply_audio_settings, apply_camera_settings, save_settings},
+    },
+    states::states::MenuState,
+};
+
+pub struct SettingsPlugin;
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Settings::load());
+
+        app.add_systems(
+            Update,
+            (
+                apply_audio_settings.run_if(resource_changed::<Settings>),
+                apply_camera_settings,
+            ),
+        );
+
+        app.add_systems(OnExit(MenuState::Options), save_settings);
+    }
+}
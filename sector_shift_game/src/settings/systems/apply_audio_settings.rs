@@ -0,0 +1,8 @@
+use bevy::{audio::Volume, prelude::*};
+
+use crate::settings::resources::Settings;
+
+/// Keeps the [`GlobalVolume`] in sync with the [`Settings`]
+pub fn apply_audio_settings(settings: Res<Settings>, mut global_volume: ResMut<GlobalVolume>) {
+    global_volume.volume = Volume::Linear(settings.volume);
+}
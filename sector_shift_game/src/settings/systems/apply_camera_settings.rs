@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+use crate::settings::resources::Settings;
+
+/// Applies the field of view from the [`Settings`] to newly spawned cameras and whenever the settings
+/// change
+pub fn apply_camera_settings(settings: Res<Settings>, q_cameras: Query<(&mut Projection, Ref<Camera3d>)>) {
+    for (mut projection, camera) in q_cameras {
+        if !settings.is_changed() && !camera.is_added() {
+            continue;
+        }
+
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            perspective.fov = settings.fov.to_radians();
+        }
+    }
+}
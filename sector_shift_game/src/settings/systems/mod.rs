@@ -0,0 +1,8 @@
+mod apply_audio_settings;
+pub use self::apply_audio_settings::*;
+
+mod apply_camera_settings;
+pub use self::apply_camera_settings::*;
+
+mod save_settings;
+pub use self::save_settings::*;
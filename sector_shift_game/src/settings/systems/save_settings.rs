@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+use crate::settings::resources::Settings;
+
+pub fn save_settings(settings: Res<Settings>) {
+    if let Err(e) = settings.save() {
+        warn!("Failed to save settings: {e}");
+    }
+}
@@ -7,4 +7,7 @@ pub enum GameState {
     MainMenu,
     SetupGame,
     Running,
+    Paused,
+    /// Shows the results of the finished level before the next one is loaded
+    Intermission,
 }
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+/// The menu screen currently shown, independent of the [`GameState`](super::GameState) so screens like the
+/// options can be reached from both the main menu and the pause menu
+#[derive(States, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MenuState {
+    #[default]
+    Disabled,
+    Main,
+    EpisodeSelect,
+    LevelSelect,
+    Options,
+    Controls,
+    Pause,
+    Intermission,
+}
@@ -1,6 +1,9 @@
 mod game_state;
 pub use self::game_state::*;
 
+mod menu_state;
+pub use self::menu_state::*;
+
 #[cfg(feature = "dev")]
 mod debug_hud_state;
 #[cfg(feature = "dev")]
@@ -4,15 +4,16 @@ use sector_shift_core::prelude::*;
 #[cfg(feature = "dev")]
 use crate::states::states::DebugHudState;
 use crate::states::{
-    states::GameState,
+    states::{GameState, MenuState},
     system_sets::GameSet,
-    systems::{set_game_state_running, set_game_state_setup_game},
+    systems::{pause_physics, set_game_state_main_menu, set_game_state_running, unpause_physics},
 };
 
 pub struct StatesPlugin;
 impl Plugin for StatesPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<GameState>();
+        app.init_state::<MenuState>();
         #[cfg(feature = "dev")]
         app.init_state::<DebugHudState>();
 
@@ -21,6 +22,11 @@ impl Plugin for StatesPlugin {
             GameSet::LoadAssets.run_if(in_state(GameState::LoadAssets)),
         );
 
+        app.configure_sets(
+            Update,
+            GameSet::MainMenu.run_if(in_state(GameState::MainMenu)),
+        );
+
         app.configure_sets(
             Update,
             GameSet::SetupGame.run_if(in_state(GameState::SetupGame)),
@@ -31,11 +37,23 @@ impl Plugin for StatesPlugin {
             GameSet::Running.run_if(in_state(GameState::Running)),
         );
 
+        app.configure_sets(Update, GameSet::Paused.run_if(in_state(GameState::Paused)));
+
+        app.configure_sets(
+            Update,
+            GameSet::Intermission.run_if(in_state(GameState::Intermission)),
+        );
+
         app.add_systems(
             Update,
-            set_game_state_setup_game.in_set(GameSet::LoadAssets).run_if(all_assets_loaded),
+            set_game_state_main_menu.in_set(GameSet::LoadAssets).run_if(all_assets_loaded),
         );
 
         app.add_systems(Update, set_game_state_running.in_set(GameSet::SetupGame));
+
+        app.add_systems(OnEnter(GameState::Paused), pause_physics)
+            .add_systems(OnExit(GameState::Paused), unpause_physics)
+            .add_systems(OnEnter(GameState::Intermission), pause_physics)
+            .add_systems(OnExit(GameState::Intermission), unpause_physics);
     }
 }
@@ -3,6 +3,9 @@ use bevy::prelude::*;
 #[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum GameSet {
     LoadAssets,
+    MainMenu,
     SetupGame,
     Running,
+    Paused,
+    Intermission,
 }
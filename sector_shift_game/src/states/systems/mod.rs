@@ -1,5 +1,11 @@
+mod pause_physics;
+pub use self::pause_physics::*;
+
+mod set_game_state_main_menu;
+pub use self::set_game_state_main_menu::*;
+
 mod set_game_state_running;
 pub use self::set_game_state_running::*;
 
-mod set_game_state_setup_game;
-pub use self::set_game_state_setup_game::*;
+mod unpause_physics;
+pub use self::unpause_physics::*;
@@ -0,0 +1,6 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+pub fn pause_physics(mut time: ResMut<Time<Physics>>) {
+    time.pause();
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+use crate::{
+    maps::resources::{CurrentLevel, LaunchOptions},
+    states::states::GameState,
+};
+
+/// Leaves the loading screen, straight into the level given on the command line if there is one
+pub fn set_game_state_main_menu(
+    mut next_state: ResMut<NextState<GameState>>,
+    mut current_level: ResMut<CurrentLevel>,
+    launch_options: Res<LaunchOptions>,
+) {
+    match &launch_options.level {
+        Some(level_id) => {
+            current_level.id = level_id.clone();
+            next_state.set(GameState::SetupGame);
+        },
+        None => next_state.set(GameState::MainMenu),
+    }
+}
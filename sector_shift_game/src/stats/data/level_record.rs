@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::stats::{functions::percentage, resources::LevelStats};
+
+/// The best results achieved on a level, each tracked separately
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, Default)]
+#[serde(default)]
+pub struct LevelRecord {
+    /// Best kill percentage
+    pub kills: u32,
+    /// Best secret percentage
+    pub secrets: u32,
+    /// Best item percentage
+    pub items: u32,
+    /// Fastest completion time in seconds
+    pub time: Option<f32>,
+}
+
+impl LevelRecord {
+    /// Keeps whichever of the record and the new stats is better for each value
+    pub fn merge(&mut self, stats: &LevelStats) {
+        self.kills = self.kills.max(percentage(stats.kills, stats.total_kills));
+        self.secrets = self.secrets.max(percentage(stats.secrets, stats.total_secrets));
+        self.items = self.items.max(percentage(stats.items, stats.total_items));
+        self.time = Some(self.time.map_or(stats.time, |time| time.min(stats.time)));
+    }
+}
@@ -0,0 +1,2 @@
+mod level_record;
+pub use self::level_record::*;
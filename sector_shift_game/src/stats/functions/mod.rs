@@ -0,0 +1,2 @@
+mod percentage;
+pub use self::percentage::*;
@@ -0,0 +1,4 @@
+/// Returns `count` as a whole percentage of `total`, counting an empty total as complete
+pub fn percentage(count: u32, total: u32) -> u32 {
+    (count * 100).checked_div(total).unwrap_or(100)
+}
@@ -0,0 +1,7 @@
+pub mod data;
+pub mod functions;
+pub mod resources;
+pub mod systems;
+
+mod stats_plugin;
+pub use self::stats_plugin::*;
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+/// Progress through the level currently being played
+#[derive(Resource, Reflect, Debug, Clone, Default)]
+#[reflect(Resource)]
+pub struct LevelStats {
+    /// The id of the level being played
+    pub level_id: String,
+    /// The level the player left through, set when an exit is reached. Stays `None` when the exit
+    /// finished the episode
+    pub next_level: Option<String>,
+    pub kills: u32,
+    pub total_kills: u32,
+    pub secrets: u32,
+    pub total_secrets: u32,
+    pub items: u32,
+    pub total_items: u32,
+    /// Seconds spent in the level, not counting pauses
+    pub time: f32,
+    /// The par time of the level in seconds
+    pub par_time: Option<f32>,
+}
+
+impl LevelStats {
+    /// Counts the secrets and items the level has on the given difficulty, the enemies are counted by
+    /// [`count_enemies`](crate::stats::systems::count_enemies)
+    pub fn new(level: &Level, difficulty: &Difficulty) -> Self {
+        let mut stats = Self {
+            level_id: level.id.clone(),
+            par_time: level.par_time,
+            ..Default::default()
+        };
+
+        for object in level.objects.values().filter(|object| object.spawns_on(difficulty.level)) {
+            match object {
+                MapObject::Item(..) => stats.total_items += 1,
+                MapObject::Secret => stats.total_secrets += 1,
+                // Enemies are counted as they spawn
+                MapObject::Enemy(..) => {},
+                MapObject::Exit(_) | MapObject::Env(..) | MapObject::PatrolTurn(_) => {},
+            }
+        }
+
+        stats
+    }
+}
@@ -0,0 +1,8 @@
+mod level_stats;
+pub use self::level_stats::*;
+
+mod previous_record;
+pub use self::previous_record::*;
+
+mod stats_records;
+pub use self::stats_records::*;
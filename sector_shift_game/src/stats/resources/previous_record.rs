@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+use crate::stats::data::LevelRecord;
+
+/// The record of the finished level as it was before its run was merged in, so the intermission
+/// compares against the best of the earlier runs
+#[derive(Resource, Reflect, Debug, Clone, Default)]
+#[reflect(Resource)]
+pub struct PreviousRecord(pub LevelRecord);
@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::stats::{data::LevelRecord, resources::LevelStats};
+
+#[derive(Debug, Error)]
+pub enum StatsRecordsError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("RON error: {0}")]
+    Ron(#[from] ron::error::Error),
+    #[error("RON spanned error: {0}")]
+    RonSpanned(#[from] ron::error::SpannedError),
+}
+
+/// The best results for every level played, kept between sessions
+#[derive(Resource, Reflect, Serialize, Deserialize, Debug, Clone, Default)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct StatsRecords {
+    /// Records keyed by level id
+    pub levels: HashMap<String, LevelRecord>,
+}
+
+// Constructor + Serialization methods
+impl StatsRecords {
+    fn get_path() -> PathBuf {
+        "./saves/stats.ron".into()
+    }
+
+    pub fn load() -> Self {
+        Self::try_load().unwrap_or_default()
+    }
+
+    pub fn try_load() -> Result<Self, StatsRecordsError> {
+        let data = std::fs::read_to_string(Self::get_path())?;
+        let records: Self = ron::de::from_str(&data)?;
+        Ok(records)
+    }
+
+    pub fn save(&self) -> Result<(), StatsRecordsError> {
+        let data = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        let path = Self::get_path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+}
+
+impl StatsRecords {
+    pub fn get(&self, level_id: &str) -> Option<&LevelRecord> {
+        self.levels.get(level_id)
+    }
+
+    /// Merges the stats of a finished level into its record
+    pub fn record(&mut self, stats: &LevelStats) {
+        self.levels.entry(stats.level_id.clone()).or_default().merge(stats);
+    }
+}
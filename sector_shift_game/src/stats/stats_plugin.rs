@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use crate::{
+    states::{states::GameState, system_sets::GameSet},
+    stats::{
+        resources::{LevelStats, PreviousRecord, StatsRecords},
+        systems::{
+            count_enemies, count_items, count_kills, discover_secrets, record_level_stats, tick_level_time,
+        },
+    },
+};
+
+pub struct StatsPlugin;
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelStats>().init_resource::<PreviousRecord>();
+        app.insert_resource(StatsRecords::load());
+
+        app.add_systems(
+            Update,
+            (
+                tick_level_time,
+                count_enemies,
+                count_kills,
+                count_items,
+                discover_secrets,
+            )
+                .in_set(GameSet::Running),
+        );
+
+        app.add_systems(OnEnter(GameState::Intermission), record_level_stats);
+    }
+}
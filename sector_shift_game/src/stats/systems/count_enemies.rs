@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::stats::resources::LevelStats;
+
+/// Adds the enemies that spawned to the kills of the level, so enemies the level names but the
+/// [`EnemyLibrary`] doesn't know are left out
+pub fn count_enemies(q_enemies: Query<(), Added<Enemy>>, mut level_stats: ResMut<LevelStats>) {
+    level_stats.total_kills += q_enemies.iter().count() as u32;
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use sector_shift_core::prelude::*;
+
+    use super::count_enemies;
+    use crate::stats::resources::LevelStats;
+
+    #[test]
+    fn test_count_enemies_once() {
+        let mut app = App::new();
+        app.init_resource::<LevelStats>().add_systems(Update, count_enemies);
+        app.world_mut().spawn_batch([Enemy, Enemy]);
+        app.update();
+        app.world_mut().spawn(Enemy);
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<LevelStats>().total_kills, 3);
+    }
+}
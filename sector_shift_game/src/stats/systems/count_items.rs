@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+use crate::{items::messages::ItemPickedUp, stats::resources::LevelStats};
+
+pub fn count_items(mut picked_up_reader: MessageReader<ItemPickedUp>, mut level_stats: ResMut<LevelStats>) {
+    level_stats.items += picked_up_reader.read().filter(|picked_up| !picked_up.dropped).count() as u32;
+}
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{actors::messages::Killed, stats::resources::LevelStats};
+
+pub fn count_kills(
+    mut killed_reader: MessageReader<Killed>,
+    q_enemies: Query<(), With<Enemy>>,
+    mut level_stats: ResMut<LevelStats>,
+) {
+    for killed in killed_reader.read() {
+        if q_enemies.contains(killed.target) {
+            level_stats.kills += 1;
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use crate::{
+    actors::components::Player,
+    maps::{components::SecretArea, functions::translation_to_position},
+    stats::resources::LevelStats,
+};
+
+/// Counts a secret as found the first time the player steps on it
+pub fn discover_secrets(
+    mut commands: Commands,
+    q_player: Single<&Transform, With<Player>>,
+    q_secrets: Query<(Entity, &SecretArea)>,
+    mut level_stats: ResMut<LevelStats>,
+) {
+    let position = translation_to_position(q_player.translation);
+    for (entity, secret) in &q_secrets {
+        if secret.position == position {
+            level_stats.secrets += 1;
+            commands.entity(entity).despawn();
+        }
+    }
+}
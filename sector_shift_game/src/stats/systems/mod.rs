@@ -0,0 +1,17 @@
+mod count_enemies;
+pub use self::count_enemies::*;
+
+mod count_items;
+pub use self::count_items::*;
+
+mod count_kills;
+pub use self::count_kills::*;
+
+mod discover_secrets;
+pub use self::discover_secrets::*;
+
+mod record_level_stats;
+pub use self::record_level_stats::*;
+
+mod tick_level_time;
+pub use self::tick_level_time::*;
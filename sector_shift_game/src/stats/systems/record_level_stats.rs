@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::{
+    maps::resources::LaunchOptions,
+    stats::resources::{LevelStats, PreviousRecord, StatsRecords},
+};
+
+pub fn record_level_stats(
+    level_stats: Res<LevelStats>,
+    mut stats_records: ResMut<StatsRecords>,
+    mut previous_record: ResMut<PreviousRecord>,
+    launch_options: Res<LaunchOptions>,
+) {
+    previous_record.0 = stats_records.get(&level_stats.level_id).cloned().unwrap_or_default();
+
+    // Playtests of unfinished levels would pollute the records
+    if launch_options.playtest {
+        return;
+    }
+    stats_records.record(&level_stats);
+    if let Err(e) = stats_records.save() {
+        warn!("Failed to save stats: {e}");
+    }
+}
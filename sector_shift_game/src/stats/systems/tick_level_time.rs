@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+use crate::stats::resources::LevelStats;
+
+pub fn tick_level_time(time: Res<Time>, mut level_stats: ResMut<LevelStats>) {
+    level_stats.time += time.delta_secs();
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+use crate::inputs::data::GameAction;
+
+/// Marks a button displaying the input bound to one slot of an action
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BindingText(pub GameAction, pub usize);
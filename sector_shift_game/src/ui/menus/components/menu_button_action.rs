@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use crate::{inputs::data::GameAction, ui::menus::data::SettingKind};
+
+/// The action performed when a menu button is pressed
+#[derive(Component, Debug, Clone)]
+pub enum MenuButtonAction {
+    /// Open the episode select screen
+    NewGame,
+    /// Start the first level of the episode with the given id
+    StartEpisode(String),
+    /// Open the level select screen
+    LevelSelect,
+    /// Start the level with the given id
+    StartLevel(String),
+    /// Continue from the level stored in the save game
+    LoadGame,
+    /// Open the options screen
+    Options,
+    /// Lower a setting by one step
+    DecreaseSetting(SettingKind),
+    /// Raise a setting by one step
+    IncreaseSetting(SettingKind),
+    /// Open the controls screen
+    Controls,
+    /// Wait for the next input and bind it to the given slot of the action
+    Rebind(GameAction, usize),
+    /// Restore the default bindings of every action
+    ResetBindings,
+    /// Return to the previous screen
+    Back,
+    /// Close the pause menu
+    Resume,
+    /// Leave the current level and return to the main menu
+    QuitToMainMenu,
+    /// Finish the intermission tally, or move on to the next level once it is done
+    Continue,
+    /// Exit the game
+    Quit,
+}
@@ -0,0 +1,11 @@
+mod binding_text;
+pub use self::binding_text::*;
+
+mod menu_button_action;
+pub use self::menu_button_action::*;
+
+mod setting_value_text;
+pub use self::setting_value_text::*;
+
+mod tally_text;
+pub use self::tally_text::*;
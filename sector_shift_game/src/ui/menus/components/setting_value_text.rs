@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+use crate::ui::menus::data::SettingKind;
+
+/// Marks a text displaying the current value of a setting
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SettingValueText(pub SettingKind);
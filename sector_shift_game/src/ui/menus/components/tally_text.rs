@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+use crate::ui::menus::data::TallyKind;
+
+/// Marks a text counting up one row of the intermission tally
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TallyText(pub TallyKind);
@@ -0,0 +1,12 @@
+use bevy::color::Color;
+
+pub const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+pub const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
+pub const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
+pub const PRESSED_BUTTON: Color = Color::srgb(0.35, 0.55, 0.35);
+
+/// How many inputs can be bound to each action on the controls screen
+pub const BINDING_SLOTS: usize = 3;
+
+pub const MAIN_MENU_BACKGROUND: Color = Color::srgb(0.05, 0.05, 0.08);
+pub const PAUSE_MENU_BACKGROUND: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
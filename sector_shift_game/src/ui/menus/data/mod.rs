@@ -0,0 +1,5 @@
+mod setting_kind;
+pub use self::setting_kind::*;
+
+mod tally_kind;
+pub use self::tally_kind::*;
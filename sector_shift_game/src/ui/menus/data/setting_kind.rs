@@ -0,0 +1,62 @@
+use crate::settings::resources::Settings;
+
+/// The settings that can be changed from the options screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    MouseSensitivity,
+    Fov,
+    Volume,
+    Difficulty,
+}
+
+impl SettingKind {
+    /// All settings in the order they are shown on the options screen
+    pub const ALL: [Self; 4] = [
+        Self::MouseSensitivity,
+        Self::Fov,
+        Self::Volume,
+        Self::Difficulty,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SettingKind::MouseSensitivity => "Mouse Sensitivity",
+            SettingKind::Fov => "Field of View",
+            SettingKind::Volume => "Volume",
+            SettingKind::Difficulty => "Difficulty",
+        }
+    }
+
+    /// Formats the current value of this setting for display
+    pub fn value(self, settings: &Settings) -> String {
+        match self {
+            SettingKind::MouseSensitivity => format!("{:.4}", settings.mouse_sensitivity),
+            SettingKind::Fov => format!("{:.0}", settings.fov),
+            SettingKind::Volume => format!("{:.0}%", settings.volume * 100.0),
+            SettingKind::Difficulty => settings.difficulty.name().to_string(),
+        }
+    }
+
+    /// Moves this setting up or down by one step, staying within its range
+    pub fn step(self, settings: &mut Settings, increase: bool) {
+        let sign = if increase { 1.0 } else { -1.0 };
+        match self {
+            SettingKind::MouseSensitivity => {
+                let (min, max) = Settings::MOUSE_SENSITIVITY_RANGE;
+                settings.mouse_sensitivity = (settings.mouse_sensitivity + sign * 0.0005).clamp(min, max);
+            },
+            SettingKind::Fov => {
+                let (min, max) = Settings::FOV_RANGE;
+                settings.fov = (settings.fov + sign * 5.0).clamp(min, max);
+            },
+            SettingKind::Volume => {
+                let (min, max) = Settings::VOLUME_RANGE;
+                settings.volume = (settings.volume + sign * 0.1).clamp(min, max);
+            },
+            SettingKind::Difficulty => {
+                settings.difficulty =
+                    if increase { settings.difficulty.harder() } else { settings.difficulty.easier() };
+            },
+        }
+    }
+}
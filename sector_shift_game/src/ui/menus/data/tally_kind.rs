@@ -0,0 +1,51 @@
+use crate::{
+    stats::{data::LevelRecord, functions::percentage, resources::LevelStats},
+    ui::menus::functions::format_time,
+};
+
+/// The rows tallied on the intermission screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TallyKind {
+    Kills,
+    Secrets,
+    Items,
+    Time,
+}
+
+impl TallyKind {
+    /// All rows in the order they are tallied
+    pub const ALL: [Self; 4] = [Self::Kills, Self::Secrets, Self::Items, Self::Time];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TallyKind::Kills => "Kills",
+            TallyKind::Secrets => "Secrets",
+            TallyKind::Items => "Items",
+            TallyKind::Time => "Time",
+        }
+    }
+
+    /// Formats the value of this row once `progress` (0 to 1) of the tally has been counted up
+    pub fn value(self, stats: &LevelStats, progress: f32) -> String {
+        let count_up = |value: u32| (value as f32 * progress).round() as u32;
+        match self {
+            TallyKind::Kills => format!("{}%", count_up(percentage(stats.kills, stats.total_kills))),
+            TallyKind::Secrets => format!(
+                "{}%",
+                count_up(percentage(stats.secrets, stats.total_secrets))
+            ),
+            TallyKind::Items => format!("{}%", count_up(percentage(stats.items, stats.total_items))),
+            TallyKind::Time => format_time(stats.time * progress),
+        }
+    }
+
+    /// Formats the best result recorded for this row
+    pub fn best(self, record: &LevelRecord) -> String {
+        match self {
+            TallyKind::Kills => format!("{}%", record.kills),
+            TallyKind::Secrets => format!("{}%", record.secrets),
+            TallyKind::Items => format!("{}%", record.items),
+            TallyKind::Time => record.time.map(format_time).unwrap_or_else(|| "--:--".to_string()),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use sector_shift_core::prelude::*;
+
+use crate::inputs::data::GameAction;
+
+/// The label of the input bound to one slot of an action
+pub fn binding_label(input_map: &InputMap<GameAction>, action: GameAction, slot: usize) -> String {
+    input_map.get(action).get(slot).map(InputBinding::label).unwrap_or_else(|| "Unbound".to_string())
+}
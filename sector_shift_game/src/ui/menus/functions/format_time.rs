@@ -0,0 +1,5 @@
+/// Formats seconds as `mm:ss`
+pub fn format_time(seconds: f32) -> String {
+    let seconds = seconds.max(0.0) as u32;
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
@@ -0,0 +1,11 @@
+mod binding_label;
+pub use self::binding_label::*;
+
+mod format_time;
+pub use self::format_time::*;
+
+mod spawn_menu_button;
+pub use self::spawn_menu_button::*;
+
+mod spawn_menu_screen;
+pub use self::spawn_menu_screen::*;
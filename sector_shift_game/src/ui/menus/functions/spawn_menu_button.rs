@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::ui::menus::{NORMAL_BUTTON, TEXT_COLOR, components::MenuButtonAction};
+
+/// Spawns a button as a child of `parent` which performs `action` when pressed
+pub fn spawn_menu_button(
+    commands: &mut Commands,
+    parent: Entity,
+    text: &str,
+    width: f32,
+    action: MenuButtonAction,
+) -> Entity {
+    commands
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(width),
+                height: Val::Px(48.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            action,
+            children![(
+                Text::new(text),
+                TextFont {
+                    font_size: 24.0,
+                    ..Default::default()
+                },
+                TextColor(TEXT_COLOR),
+            )],
+            ChildOf(parent),
+        ))
+        .id()
+}
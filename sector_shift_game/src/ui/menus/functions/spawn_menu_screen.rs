@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+use crate::{states::states::MenuState, ui::menus::TEXT_COLOR};
+
+/// Spawns a full screen menu that is despawned when leaving `menu_state`.
+///
+/// Returns the column entity that buttons should be added to.
+pub fn spawn_menu_screen(
+    commands: &mut Commands,
+    menu_state: MenuState,
+    title: &str,
+    background: Color,
+) -> Entity {
+    let column_entity = commands
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..Default::default()
+            },
+            children![(
+                Text::new(title),
+                TextFont {
+                    font_size: 48.0,
+                    ..Default::default()
+                },
+                TextColor(TEXT_COLOR),
+                Node {
+                    margin: UiRect::bottom(Val::Px(24.0)),
+                    ..Default::default()
+                },
+            )],
+        ))
+        .id();
+
+    commands
+        .spawn((
+            Name::new(format!("{menu_state:?}Menu")),
+            DespawnOnExit(menu_state),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..Default::default()
+            },
+            BackgroundColor(background),
+        ))
+        .add_child(column_entity);
+
+    column_entity
+}
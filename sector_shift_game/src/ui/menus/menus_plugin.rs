@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+use crate::{
+    states::states::{GameState, MenuState},
+    ui::menus::{
+        resources::{IntermissionTally, PendingRebind},
+        systems::{
+            capture_rebind, close_menus, handle_menu_buttons, open_intermission_menu, open_main_menu,
+            open_pause_menu, spawn_controls_menu, spawn_episode_select_menu, spawn_intermission_menu,
+            spawn_level_select_menu, spawn_main_menu, spawn_menu_camera, spawn_options_menu,
+            spawn_pause_menu, update_binding_texts, update_button_colors, update_setting_values,
+            update_tally_texts,
+        },
+    },
+};
+
+pub struct MenusPlugin;
+impl Plugin for MenusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PendingRebind>().init_resource::<IntermissionTally>();
+
+        app.add_systems(
+            OnEnter(GameState::MainMenu),
+            (spawn_menu_camera, open_main_menu),
+        )
+        .add_systems(OnExit(GameState::MainMenu), close_menus)
+        .add_systems(OnEnter(GameState::Paused), open_pause_menu)
+        .add_systems(OnExit(GameState::Paused), close_menus)
+        .add_systems(OnEnter(GameState::Intermission), open_intermission_menu)
+        .add_systems(OnExit(GameState::Intermission), close_menus);
+
+        app.add_systems(OnEnter(MenuState::Main), spawn_main_menu)
+            .add_systems(OnEnter(MenuState::EpisodeSelect), spawn_episode_select_menu)
+            .add_systems(OnEnter(MenuState::LevelSelect), spawn_level_select_menu)
+            .add_systems(OnEnter(MenuState::Options), spawn_options_menu)
+            .add_systems(OnEnter(MenuState::Controls), spawn_controls_menu)
+            .add_systems(OnEnter(MenuState::Pause), spawn_pause_menu)
+            .add_systems(OnEnter(MenuState::Intermission), spawn_intermission_menu);
+
+        app.add_systems(
+            Update,
+            (
+                handle_menu_buttons,
+                update_button_colors,
+                update_setting_values.run_if(in_state(MenuState::Options)),
+                update_tally_texts.run_if(in_state(MenuState::Intermission)),
+                (capture_rebind, update_binding_texts).chain().run_if(in_state(MenuState::Controls)),
+            )
+                .run_if(not(in_state(MenuState::Disabled))),
+        );
+    }
+}
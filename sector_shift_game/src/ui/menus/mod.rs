@@ -0,0 +1,11 @@
+pub mod components;
+pub mod data;
+pub mod functions;
+pub mod resources;
+pub mod systems;
+
+mod constants;
+pub use self::constants::*;
+
+mod menus_plugin;
+pub use self::menus_plugin::*;
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+use crate::ui::menus::data::TallyKind;
+
+/// Drives the count up animation of the intermission screen
+#[derive(Resource, Default, Debug)]
+pub struct IntermissionTally {
+    /// Seconds since the tally started
+    pub elapsed: f32,
+}
+
+impl IntermissionTally {
+    /// How long each row takes to count up
+    pub const SECONDS_PER_ROW: f32 = 0.75;
+
+    /// How far along the given row is, from 0 to 1
+    pub fn progress(&self, kind: TallyKind) -> f32 {
+        let index = TallyKind::ALL.iter().position(|k| *k == kind).unwrap_or_default();
+        ((self.elapsed - index as f32 * Self::SECONDS_PER_ROW) / Self::SECONDS_PER_ROW).clamp(0.0, 1.0)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= TallyKind::ALL.len() as f32 * Self::SECONDS_PER_ROW
+    }
+
+    /// Skips to the end of the animation
+    pub fn finish(&mut self) {
+        self.elapsed = TallyKind::ALL.len() as f32 * Self::SECONDS_PER_ROW;
+    }
+}
@@ -0,0 +1,5 @@
+mod intermission_tally;
+pub use self::intermission_tally::*;
+
+mod pending_rebind;
+pub use self::pending_rebind::*;
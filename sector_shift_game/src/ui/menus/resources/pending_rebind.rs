@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+use crate::inputs::data::GameAction;
+
+/// The action waiting for a new input on the controls screen
+#[derive(Resource, Default, Debug)]
+pub struct PendingRebind {
+    pub action: Option<GameAction>,
+    /// Which of the action's bindings is replaced
+    pub slot: usize,
+}
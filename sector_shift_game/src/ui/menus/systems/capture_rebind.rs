@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{inputs::data::GameAction, ui::menus::resources::PendingRebind};
+
+pub fn capture_rebind(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+    mut pending_rebind: ResMut<PendingRebind>,
+    mut input_map: ResMut<InputMap<GameAction>>,
+    // The axes pushed past the capture threshold last frame
+    mut held_axes: Local<Vec<InputBinding>>,
+) {
+    let previous_axes = std::mem::replace(&mut *held_axes, InputBinding::pushed_axes(gamepads));
+
+    // Skip the frame the rebind was requested in, otherwise the click on the button gets captured
+    if pending_rebind.is_changed() {
+        return;
+    }
+    let Some(action) = pending_rebind.action else {
+        return;
+    };
+    let Some(binding) = InputBinding::just_pressed(&keys, &mouse, gamepads, &previous_axes) else {
+        return;
+    };
+
+    if binding != InputBinding::Key(KeyCode::Escape) {
+        input_map.rebind(action, pending_rebind.slot, binding);
+    }
+    pending_rebind.action = None;
+}
@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    inputs::data::GameAction,
+    items::resources::Inventory,
+    maps::resources::{CurrentEpisode, CurrentLevel, SaveGame},
+    settings::resources::Settings,
+    states::states::{GameState, MenuState},
+    stats::resources::LevelStats,
+    ui::menus::{
+        components::MenuButtonAction,
+        resources::{IntermissionTally, PendingRebind},
+    },
+};
+
+pub fn handle_menu_buttons(
+    q_buttons: Query<(&Interaction, &MenuButtonAction), Changed<Interaction>>,
+    game_state: Res<State<GameState>>,
+    menu_state: Res<State<MenuState>>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    mut next_menu_state: ResMut<NextState<MenuState>>,
+    mut current_level: ResMut<CurrentLevel>,
+    mut current_episode: ResMut<CurrentEpisode>,
+    mut inventory: ResMut<Inventory>,
+    episode_library: Res<EpisodeLibrary>,
+    mut settings: ResMut<Settings>,
+    mut input_map: ResMut<InputMap<GameAction>>,
+    mut pending_rebind: ResMut<PendingRebind>,
+    mut tally: ResMut<IntermissionTally>,
+    level_stats: Res<LevelStats>,
+    mut app_exit: MessageWriter<AppExit>,
+) {
+    // Clicks are captured as the new binding while rebinding
+    if pending_rebind.action.is_some() {
+        return;
+    }
+
+    for (interaction, action) in q_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        match action {
+            MenuButtonAction::NewGame => next_menu_state.set(MenuState::EpisodeSelect),
+            MenuButtonAction::StartEpisode(episode_id) => {
+                let Some(episode) = episode_library.get(episode_id) else {
+                    continue;
+                };
+                let Some(level_id) = episode.first_level() else {
+                    warn!("Episode '{episode_id}' has no levels to start");
+                    continue;
+                };
+                current_episode.id = Some(episode_id.clone());
+                current_level.id = level_id.to_string();
+                *inventory = Inventory::from_effects(&episode.starting_inventory);
+                next_game_state.set(GameState::SetupGame);
+            },
+            MenuButtonAction::LevelSelect => next_menu_state.set(MenuState::LevelSelect),
+            MenuButtonAction::StartLevel(level_id) => {
+                current_episode.id = None;
+                current_level.id = level_id.clone();
+                *inventory = Inventory::default();
+                next_game_state.set(GameState::SetupGame);
+            },
+            MenuButtonAction::LoadGame => match SaveGame::try_load() {
+                Ok(save_game) => {
+                    current_episode.id = save_game.episode_id;
+                    current_level.id = save_game.level_id;
+                    *inventory = save_game.inventory;
+                    next_game_state.set(GameState::SetupGame);
+                },
+                Err(e) => warn!("Failed to load save game: {e}"),
+            },
+            MenuButtonAction::Options => next_menu_state.set(MenuState::Options),
+            MenuButtonAction::DecreaseSetting(kind) => kind.step(&mut settings, false),
+            MenuButtonAction::IncreaseSetting(kind) => kind.step(&mut settings, true),
+            MenuButtonAction::Controls => next_menu_state.set(MenuState::Controls),
+            MenuButtonAction::Rebind(game_action, slot) => {
+                pending_rebind.action = Some(*game_action);
+                pending_rebind.slot = *slot;
+            },
+            MenuButtonAction::ResetBindings => *input_map = InputMap::default(),
+            MenuButtonAction::Back => match menu_state.get() {
+                MenuState::Controls => next_menu_state.set(MenuState::Options),
+                _ if *game_state.get() == GameState::Paused => next_menu_state.set(MenuState::Pause),
+                _ => next_menu_state.set(MenuState::Main),
+            },
+            MenuButtonAction::Resume => next_game_state.set(GameState::Running),
+            MenuButtonAction::QuitToMainMenu => next_game_state.set(GameState::MainMenu),
+            MenuButtonAction::Continue if !tally.is_finished() => tally.finish(),
+            MenuButtonAction::Continue => match &level_stats.next_level {
+                Some(level_id) => {
+                    current_level.id = level_id.clone();
+                    next_game_state.set(GameState::SetupGame);
+                },
+                // The episode is finished
+                None => next_game_state.set(GameState::MainMenu),
+            },
+            MenuButtonAction::Quit => {
+                app_exit.write(AppExit::Success);
+            },
+        }
+    }
+}
@@ -0,0 +1,53 @@
+mod capture_rebind;
+pub use self::capture_rebind::*;
+
+mod close_menus;
+pub use self::close_menus::*;
+
+mod handle_menu_buttons;
+pub use self::handle_menu_buttons::*;
+
+mod open_intermission_menu;
+pub use self::open_intermission_menu::*;
+
+mod open_main_menu;
+pub use self::open_main_menu::*;
+
+mod open_pause_menu;
+pub use self::open_pause_menu::*;
+
+mod spawn_controls_menu;
+pub use self::spawn_controls_menu::*;
+
+mod spawn_episode_select_menu;
+pub use self::spawn_episode_select_menu::*;
+
+mod spawn_intermission_menu;
+pub use self::spawn_intermission_menu::*;
+
+mod spawn_level_select_menu;
+pub use self::spawn_level_select_menu::*;
+
+mod spawn_main_menu;
+pub use self::spawn_main_menu::*;
+
+mod spawn_menu_camera;
+pub use self::spawn_menu_camera::*;
+
+mod spawn_options_menu;
+pub use self::spawn_options_menu::*;
+
+mod spawn_pause_menu;
+pub use self::spawn_pause_menu::*;
+
+mod update_binding_texts;
+pub use self::update_binding_texts::*;
+
+mod update_button_colors;
+pub use self::update_button_colors::*;
+
+mod update_setting_values;
+pub use self::update_setting_values::*;
+
+mod update_tally_texts;
+pub use self::update_tally_texts::*;
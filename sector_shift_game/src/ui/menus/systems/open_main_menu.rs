@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+use crate::states::states::MenuState;
+
+pub fn open_main_menu(mut next_state: ResMut<NextState<MenuState>>) {
+    next_state.set(MenuState::Main);
+}
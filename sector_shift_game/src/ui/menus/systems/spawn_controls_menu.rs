@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    inputs::data::GameAction,
+    states::states::{GameState, MenuState},
+    ui::menus::{
+        BINDING_SLOTS, MAIN_MENU_BACKGROUND, PAUSE_MENU_BACKGROUND, TEXT_COLOR,
+        components::{BindingText, MenuButtonAction},
+        functions::{binding_label, spawn_menu_button, spawn_menu_screen},
+        resources::PendingRebind,
+    },
+};
+
+pub fn spawn_controls_menu(
+    mut commands: Commands,
+    input_map: Res<InputMap<GameAction>>,
+    mut pending_rebind: ResMut<PendingRebind>,
+    game_state: Res<State<GameState>>,
+) {
+    pending_rebind.action = None;
+
+    let background =
+        if *game_state.get() == GameState::Paused { PAUSE_MENU_BACKGROUND } else { MAIN_MENU_BACKGROUND };
+    let column = spawn_menu_screen(&mut commands, MenuState::Controls, "Controls", background);
+
+    let grid = commands
+        .spawn((
+            Node {
+                display: Display::Grid,
+                grid_template_columns: RepeatedGridTrack::auto(2),
+                column_gap: Val::Px(32.0),
+                row_gap: Val::Px(4.0),
+                margin: UiRect::bottom(Val::Px(16.0)),
+                ..Default::default()
+            },
+            ChildOf(column),
+        ))
+        .id();
+
+    for action in GameAction::ALL {
+        let row = commands
+            .spawn((
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    ..Default::default()
+                },
+                ChildOf(grid),
+            ))
+            .id();
+
+        commands.spawn((
+            Text::new(action.label()),
+            TextColor(TEXT_COLOR),
+            Node {
+                width: Val::Px(160.0),
+                ..Default::default()
+            },
+            ChildOf(row),
+        ));
+        // Each binding is its own button, pressing it replaces just that binding
+        for slot in 0..BINDING_SLOTS {
+            let button = spawn_menu_button(
+                &mut commands,
+                row,
+                &binding_label(&input_map, action, slot),
+                170.0,
+                MenuButtonAction::Rebind(action, slot),
+            );
+            commands.entity(button).insert(BindingText(action, slot));
+        }
+    }
+
+    commands.spawn((
+        Text::new("Select a binding and press the new input for it, Escape cancels"),
+        TextColor(TEXT_COLOR),
+        Node {
+            margin: UiRect::bottom(Val::Px(16.0)),
+            ..Default::default()
+        },
+        ChildOf(column),
+    ));
+    spawn_menu_button(
+        &mut commands,
+        column,
+        "Reset Defaults",
+        300.0,
+        MenuButtonAction::ResetBindings,
+    );
+    spawn_menu_button(&mut commands, column, "Back", 300.0, MenuButtonAction::Back);
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    states::states::MenuState,
+    ui::menus::{
+        MAIN_MENU_BACKGROUND, TEXT_COLOR,
+        components::MenuButtonAction,
+        functions::{spawn_menu_button, spawn_menu_screen},
+    },
+};
+
+pub fn spawn_episode_select_menu(mut commands: Commands, episode_library: Res<EpisodeLibrary>) {
+    let column = spawn_menu_screen(
+        &mut commands,
+        MenuState::EpisodeSelect,
+        "New Game",
+        MAIN_MENU_BACKGROUND,
+    );
+
+    let episodes = episode_library.sorted();
+    if episodes.is_empty() {
+        commands.spawn((
+            Text::new("No episodes found in assets/episodes"),
+            TextColor(TEXT_COLOR),
+            ChildOf(column),
+        ));
+    }
+
+    for episode in episodes {
+        spawn_menu_button(
+            &mut commands,
+            column,
+            &episode.name,
+            400.0,
+            MenuButtonAction::StartEpisode(episode.id.clone()),
+        );
+    }
+
+    spawn_menu_button(&mut commands, column, "Back", 300.0, MenuButtonAction::Back);
+}
@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+
+use crate::{
+    states::states::MenuState,
+    stats::resources::{LevelStats, PreviousRecord},
+    ui::menus::{
+        MAIN_MENU_BACKGROUND, TEXT_COLOR,
+        components::{MenuButtonAction, TallyText},
+        data::TallyKind,
+        functions::{format_time, spawn_menu_button, spawn_menu_screen},
+        resources::IntermissionTally,
+    },
+};
+
+pub fn spawn_intermission_menu(
+    mut commands: Commands,
+    level_stats: Res<LevelStats>,
+    previous_record: Res<PreviousRecord>,
+    mut tally: ResMut<IntermissionTally>,
+) {
+    *tally = IntermissionTally::default();
+
+    let title = match level_stats.next_level {
+        Some(_) => format!("Finished {}", level_stats.level_id),
+        None => "Episode Complete".to_string(),
+    };
+    let column = spawn_menu_screen(
+        &mut commands,
+        MenuState::Intermission,
+        &title,
+        MAIN_MENU_BACKGROUND,
+    );
+
+    let grid = commands
+        .spawn((
+            Node {
+                display: Display::Grid,
+                grid_template_columns: RepeatedGridTrack::px(3, 160.0),
+                row_gap: Val::Px(8.0),
+                margin: UiRect::bottom(Val::Px(16.0)),
+                ..Default::default()
+            },
+            ChildOf(column),
+        ))
+        .id();
+
+    for text in ["", "", "Best"] {
+        commands.spawn((Text::new(text), TextColor(TEXT_COLOR), ChildOf(grid)));
+    }
+    for kind in TallyKind::ALL {
+        commands.spawn((
+            Text::new(kind.label()),
+            TextColor(TEXT_COLOR),
+            ChildOf(grid),
+        ));
+        commands.spawn((
+            Text::new(kind.value(&level_stats, 0.0)),
+            TextColor(TEXT_COLOR),
+            TallyText(kind),
+            ChildOf(grid),
+        ));
+        commands.spawn((
+            Text::new(kind.best(&previous_record.0)),
+            TextColor(TEXT_COLOR),
+            ChildOf(grid),
+        ));
+    }
+    if let Some(par_time) = level_stats.par_time {
+        commands.spawn((Text::new("Par"), TextColor(TEXT_COLOR), ChildOf(grid)));
+        commands.spawn((
+            Text::new(format_time(par_time)),
+            TextColor(TEXT_COLOR),
+            ChildOf(grid),
+        ));
+        commands.spawn((Text::new(""), ChildOf(grid)));
+    }
+
+    spawn_menu_button(
+        &mut commands,
+        column,
+        if level_stats.next_level.is_some() { "Continue" } else { "Main Menu" },
+        300.0,
+        MenuButtonAction::Continue,
+    );
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    states::states::MenuState,
+    ui::menus::{
+        MAIN_MENU_BACKGROUND, TEXT_COLOR,
+        components::MenuButtonAction,
+        functions::{spawn_menu_button, spawn_menu_screen},
+    },
+};
+
+pub fn spawn_level_select_menu(mut commands: Commands) {
+    let column = spawn_menu_screen(
+        &mut commands,
+        MenuState::LevelSelect,
+        "Choose Level",
+        MAIN_MENU_BACKGROUND,
+    );
+
+    let level_ids = Level::list_ids().unwrap_or_else(|e| {
+        warn!("Failed to list levels: {e}");
+        Vec::new()
+    });
+
+    if level_ids.is_empty() {
+        commands.spawn((
+            Text::new("No levels found, build one in the editor first"),
+            TextColor(TEXT_COLOR),
+            ChildOf(column),
+        ));
+    }
+
+    for level_id in level_ids {
+        spawn_menu_button(
+            &mut commands,
+            column,
+            &level_id,
+            300.0,
+            MenuButtonAction::StartLevel(level_id.clone()),
+        );
+    }
+
+    spawn_menu_button(&mut commands, column, "Back", 300.0, MenuButtonAction::Back);
+}
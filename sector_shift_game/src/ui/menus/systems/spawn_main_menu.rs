@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use crate::{
+    states::states::MenuState,
+    ui::menus::{
+        MAIN_MENU_BACKGROUND,
+        components::MenuButtonAction,
+        functions::{spawn_menu_button, spawn_menu_screen},
+    },
+};
+
+pub fn spawn_main_menu(mut commands: Commands) {
+    let column = spawn_menu_screen(
+        &mut commands,
+        MenuState::Main,
+        "SectorShift",
+        MAIN_MENU_BACKGROUND,
+    );
+
+    for (text, action) in [
+        ("New Game", MenuButtonAction::NewGame),
+        ("Choose Level", MenuButtonAction::LevelSelect),
+        ("Load Game", MenuButtonAction::LoadGame),
+        ("Options", MenuButtonAction::Options),
+        ("Quit", MenuButtonAction::Quit),
+    ] {
+        spawn_menu_button(&mut commands, column, text, 300.0, action);
+    }
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+use crate::states::states::GameState;
+
+/// The main menu is shown before any level exists, so it needs its own camera to render to
+pub fn spawn_menu_camera(mut commands: Commands) {
+    commands.spawn((
+        Name::new("MenuCamera"),
+        Camera2d,
+        DespawnOnExit(GameState::MainMenu),
+    ));
+}
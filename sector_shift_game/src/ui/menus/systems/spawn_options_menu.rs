@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+
+use crate::{
+    settings::resources::Settings,
+    states::states::{GameState, MenuState},
+    ui::menus::{
+        MAIN_MENU_BACKGROUND, PAUSE_MENU_BACKGROUND, TEXT_COLOR,
+        components::{MenuButtonAction, SettingValueText},
+        data::SettingKind,
+        functions::{spawn_menu_button, spawn_menu_screen},
+    },
+};
+
+pub fn spawn_options_menu(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    game_state: Res<State<GameState>>,
+) {
+    let background =
+        if *game_state.get() == GameState::Paused { PAUSE_MENU_BACKGROUND } else { MAIN_MENU_BACKGROUND };
+    let column = spawn_menu_screen(&mut commands, MenuState::Options, "Options", background);
+
+    for kind in SettingKind::ALL {
+        let row = commands
+            .spawn((
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    ..Default::default()
+                },
+                ChildOf(column),
+            ))
+            .id();
+
+        commands.spawn((
+            Text::new(kind.label()),
+            TextColor(TEXT_COLOR),
+            Node {
+                width: Val::Px(200.0),
+                ..Default::default()
+            },
+            ChildOf(row),
+        ));
+        spawn_menu_button(
+            &mut commands,
+            row,
+            "<",
+            48.0,
+            MenuButtonAction::DecreaseSetting(kind),
+        );
+        commands.spawn((
+            Text::new(kind.value(&settings)),
+            TextColor(TEXT_COLOR),
+            TextLayout::new_with_justify(Justify::Center),
+            Node {
+                width: Val::Px(120.0),
+                ..Default::default()
+            },
+            SettingValueText(kind),
+            ChildOf(row),
+        ));
+        spawn_menu_button(
+            &mut commands,
+            row,
+            ">",
+            48.0,
+            MenuButtonAction::IncreaseSetting(kind),
+        );
+    }
+
+    spawn_menu_button(
+        &mut commands,
+        column,
+        "Controls",
+        300.0,
+        MenuButtonAction::Controls,
+    );
+    spawn_menu_button(&mut commands, column, "Back", 300.0, MenuButtonAction::Back);
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use crate::{
+    states::states::MenuState,
+    ui::menus::{
+        PAUSE_MENU_BACKGROUND,
+        components::MenuButtonAction,
+        functions::{spawn_menu_button, spawn_menu_screen},
+    },
+};
+
+pub fn spawn_pause_menu(mut commands: Commands) {
+    let column = spawn_menu_screen(
+        &mut commands,
+        MenuState::Pause,
+        "Paused",
+        PAUSE_MENU_BACKGROUND,
+    );
+
+    for (text, action) in [
+        ("Resume", MenuButtonAction::Resume),
+        ("Options", MenuButtonAction::Options),
+        ("Quit to Main Menu", MenuButtonAction::QuitToMainMenu),
+        ("Quit", MenuButtonAction::Quit),
+    ] {
+        spawn_menu_button(&mut commands, column, text, 300.0, action);
+    }
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use sector_shift_core::prelude::*;
+
+use crate::{
+    inputs::data::GameAction,
+    ui::menus::{components::BindingText, functions::binding_label, resources::PendingRebind},
+};
+
+pub fn update_binding_texts(
+    input_map: Res<InputMap<GameAction>>,
+    pending_rebind: Res<PendingRebind>,
+    q_buttons: Query<(&BindingText, &Children)>,
+    mut q_texts: Query<&mut Text>,
+) {
+    if !input_map.is_changed() && !pending_rebind.is_changed() {
+        return;
+    }
+
+    for (BindingText(action, slot), children) in q_buttons {
+        let label = if pending_rebind.action == Some(*action) && pending_rebind.slot == *slot {
+            "Press an input".to_string()
+        } else {
+            binding_label(&input_map, *action, *slot)
+        };
+        let mut texts = q_texts.iter_many_mut(children);
+        while let Some(mut text) = texts.fetch_next() {
+            text.0.clone_from(&label);
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+use crate::ui::menus::{HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+pub fn update_button_colors(
+    q_buttons: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<Button>)>,
+) {
+    for (interaction, mut background_color) in q_buttons {
+        *background_color = match interaction {
+            Interaction::Pressed => PRESSED_BUTTON.into(),
+            Interaction::Hovered => HOVERED_BUTTON.into(),
+            Interaction::None => NORMAL_BUTTON.into(),
+        };
+    }
+}
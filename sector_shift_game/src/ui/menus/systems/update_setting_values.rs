@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+use crate::{settings::resources::Settings, ui::menus::components::SettingValueText};
+
+pub fn update_setting_values(settings: Res<Settings>, q_texts: Query<(&mut Text, &SettingValueText)>) {
+    for (mut text, value_text) in q_texts {
+        text.0 = value_text.0.value(&settings);
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use crate::{
+    stats::resources::LevelStats,
+    ui::menus::{components::TallyText, resources::IntermissionTally},
+};
+
+pub fn update_tally_texts(
+    time: Res<Time>,
+    level_stats: Res<LevelStats>,
+    mut tally: ResMut<IntermissionTally>,
+    q_texts: Query<(&mut Text, &TallyText)>,
+) {
+    tally.elapsed += time.delta_secs();
+
+    for (mut text, tally_text) in q_texts {
+        text.0 = tally_text.0.value(&level_stats, tally.progress(tally_text.0));
+    }
+}
@@ -1,3 +1,5 @@
+pub mod menus;
+
 mod ui_plugin;
 pub use self::ui_plugin::*;
 
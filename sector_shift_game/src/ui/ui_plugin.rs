@@ -3,6 +3,8 @@ use bevy::prelude::*;
 pub struct UiPlugin;
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
+        app.add_plugins(super::menus::MenusPlugin);
+
         #[cfg(feature = "dev")]
         app.add_plugins(super::debug_hud::DebugHudPlugin);
     }